@@ -34,6 +34,10 @@ use crate::{
 
 /// Response from [`AccountBalanceQuery`][crate::AccountBalanceQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+// lets the serde derives use the deprecated fields.
+#[cfg_attr(feature = "serde", allow(deprecated))]
 pub struct AccountBalance {
     /// The account that is being referenced.
     pub account_id: AccountId,
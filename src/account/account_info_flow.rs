@@ -25,6 +25,7 @@ use crate::{
     Client,
     Error,
     Key,
+    KeyRequirement,
     PublicKey,
     Transaction,
 };
@@ -71,3 +72,23 @@ pub async fn verify_transaction_signature<D: TransactionExecute>(
 
     key.verify_transaction(transaction)
 }
+
+/// Checks the given account's key, whatever kind of key it is, against the signatures on the given transaction.
+///
+/// # Errors
+/// - See [`Transaction::check_key_requirements`]
+/// - See [`AccountInfoQuery::execute`]
+///
+/// # Panics
+/// - If `!transaction.is_frozen()`.
+pub async fn check_transaction_key<D: TransactionExecute>(
+    client: &Client,
+    account_id: AccountId,
+    transaction: &Transaction<D>,
+) -> crate::Result<KeyRequirement> {
+    let key = AccountInfoQuery::new().account_id(account_id).execute(client).await?.key;
+
+    let mut requirements = transaction.check_key_requirements(std::slice::from_ref(&key))?;
+
+    Ok(requirements.remove(0))
+}
@@ -0,0 +1,377 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+use parking_lot::Mutex;
+use tokio::sync::{
+    OwnedSemaphorePermit,
+    Semaphore,
+};
+use triomphe::Arc;
+
+use crate::client::ClientBackoff;
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Status,
+    Transaction,
+    TransactionReceipt,
+};
+
+tokio::task_local! {
+    // the throttle of the bulk execution the current request is part of, if any.
+    static THROTTLE: Arc<Throttle>;
+}
+
+/// Returns the throttle of the bulk execution that the current task is executing a request for.
+pub(crate) fn current_throttle() -> Option<Arc<Throttle>> {
+    THROTTLE.try_with(Arc::clone).ok()
+}
+
+/// Executes many transactions concurrently and waits for their receipts, see [`Client::bulk_executor`].
+///
+/// Requests are capped both overall and per node.
+/// When a node answers `BUSY`, a request is rejected with `RESOURCE_EXHAUSTED`,
+/// or a transaction is `THROTTLED_AT_CONSENSUS`, every request of the bulk execution backs off,
+/// starting at the client's [`min_backoff`](Client::min_backoff) and doubling up to its [`max_backoff`](Client::max_backoff).
+///
+/// Otherwise each transaction is executed exactly like [`Transaction::execute`] would,
+/// so it's retried on other nodes, and unhealthy nodes are avoided, in the same way.
+///
+/// Requires the `bulk-executor` feature.
+#[derive(Debug, Clone)]
+pub struct BulkExecutor {
+    client: Client,
+    max_in_flight: usize,
+    max_in_flight_per_node: usize,
+}
+
+impl BulkExecutor {
+    pub(crate) fn new(client: Client) -> Self {
+        Self { client, max_in_flight: 64, max_in_flight_per_node: 8 }
+    }
+
+    /// Returns the maximum number of transactions that are executed, or waited on, at once.
+    #[must_use]
+    pub fn get_max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    /// Sets the maximum number of transactions that are executed, or waited on, at once.
+    ///
+    /// Defaults to `64`, `0` is treated as `1`.
+    pub fn max_in_flight(&mut self, max_in_flight: usize) -> &mut Self {
+        self.max_in_flight = max_in_flight.max(1);
+        self
+    }
+
+    /// Returns the maximum number of requests that are sent to any one node at once.
+    #[must_use]
+    pub fn get_max_in_flight_per_node(&self) -> usize {
+        self.max_in_flight_per_node
+    }
+
+    /// Sets the maximum number of requests that are sent to any one node at once.
+    ///
+    /// This includes the receipt queries made while waiting for transactions to reach consensus.
+    ///
+    /// Defaults to `8`, `0` is treated as `1`.
+    pub fn max_in_flight_per_node(&mut self, max_in_flight_per_node: usize) -> &mut Self {
+        self.max_in_flight_per_node = max_in_flight_per_node.max(1);
+        self
+    }
+
+    /// Executes every transaction in `transactions` and waits for its receipt.
+    ///
+    /// Yields the index of each transaction in `transactions` along with the outcome,
+    /// in the order the receipts arrive rather than the order of `transactions`.
+    ///
+    /// Failing receipts are [`Error::ReceiptStatus`]es, as with [`TransactionResponse::get_receipt`](crate::TransactionResponse::get_receipt).
+    pub fn execute<'a, D, S>(
+        &'a self,
+        transactions: S,
+    ) -> impl Stream<Item = (usize, crate::Result<TransactionReceipt>)> + 'a
+    where
+        D: TransactionExecute + 'a,
+        S: Stream<Item = Transaction<D>> + 'a,
+    {
+        let throttle = Arc::new(Throttle::new(self.max_in_flight_per_node, self.client.backoff()));
+
+        transactions
+            .enumerate()
+            .map(move |(index, mut transaction)| {
+                let throttle = Arc::clone(&throttle);
+                let client = &self.client;
+
+                THROTTLE.scope(Arc::clone(&throttle), async move {
+                    let result = match transaction.execute(client).await {
+                        Ok(response) => response.get_receipt(client).await,
+                        Err(e) => Err(e),
+                    };
+
+                    if let Err(Error::ReceiptStatus {
+                        status: Status::ThrottledAtConsensus, ..
+                    }) = &result
+                    {
+                        throttle.back_off();
+                    }
+
+                    (index, result)
+                })
+            })
+            .buffer_unordered(self.max_in_flight)
+    }
+}
+
+/// State shared by every request of a bulk execution.
+pub(crate) struct Throttle {
+    max_in_flight_per_node: usize,
+    // `Semaphore::acquire_owned` only takes std::sync::Arcs.
+    #[allow(clippy::disallowed_types)]
+    nodes: Mutex<HashMap<AccountId, std::sync::Arc<Semaphore>>>,
+    back_off: Mutex<BackOff>,
+    min_backoff: Duration,
+    max_backoff: Duration,
+}
+
+#[derive(Default)]
+struct BackOff {
+    until: Option<Instant>,
+    // zero when not backing off.
+    delay: Duration,
+}
+
+impl Throttle {
+    fn new(max_in_flight_per_node: usize, backoff: ClientBackoff) -> Self {
+        Self {
+            max_in_flight_per_node,
+            nodes: Mutex::default(),
+            back_off: Mutex::default(),
+            min_backoff: backoff.initial_backoff,
+            max_backoff: backoff.max_backoff,
+        }
+    }
+
+    /// Waits for a free slot on the given node and for any back-off to end.
+    ///
+    /// The slot is freed when the returned permit is dropped.
+    // `Semaphore::acquire_owned` only takes std::sync::Arcs.
+    #[allow(clippy::disallowed_types)]
+    pub(crate) async fn acquire(&self, node_account_id: AccountId) -> OwnedSemaphorePermit {
+        let semaphore =
+            std::sync::Arc::clone(self.nodes.lock().entry(node_account_id).or_insert_with(|| {
+                std::sync::Arc::new(Semaphore::new(self.max_in_flight_per_node))
+            }));
+
+        let permit = semaphore.acquire_owned().await.expect("semaphores are never closed");
+
+        loop {
+            let until = self.back_off.lock().until;
+
+            match until {
+                Some(until) if until > Instant::now() => {
+                    tokio::time::sleep_until(until.into()).await;
+                }
+                _ => break,
+            }
+        }
+
+        permit
+    }
+
+    /// Updates the back-off based on the pre-check status of a response.
+    pub(crate) fn observe_status(&self, status: Status) {
+        match status {
+            Status::Busy | Status::ThrottledAtConsensus => self.back_off(),
+            Status::Ok => {
+                let mut back_off = self.back_off.lock();
+
+                // a response to a request that was sent before backing off started doesn't mean it's over.
+                if back_off.until.is_none_or(|it| it <= Instant::now()) {
+                    *back_off = BackOff::default();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Makes every request wait before being sent, for longer each time this is called in a row.
+    pub(crate) fn back_off(&self) {
+        let mut back_off = self.back_off.lock();
+        let now = Instant::now();
+
+        // requests that were already in flight when we started backing off shouldn't extend it.
+        if back_off.until.is_some_and(|it| it > now) {
+            return;
+        }
+
+        back_off.delay = match back_off.delay {
+            Duration::ZERO => self.min_backoff,
+            delay => (delay * 2).min(self.max_backoff),
+        };
+
+        back_off.until = Some(now + back_off.delay);
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use std::time::{
+        Duration,
+        Instant,
+    };
+
+    use futures_util::{
+        FutureExt,
+        StreamExt,
+    };
+
+    use super::Throttle;
+    use crate::client::ClientBackoff;
+    use crate::testing::{
+        MockNetwork,
+        MockResponse,
+    };
+    use crate::{
+        AccountId,
+        Client,
+        Hbar,
+        PrivateKey,
+        Status,
+        TransferTransaction,
+    };
+
+    async fn client(network: &MockNetwork) -> Client {
+        let client = network.client().unwrap();
+        client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+
+        for node in network.nodes() {
+            client.ping(node.account_id()).await.unwrap();
+        }
+
+        client
+    }
+
+    fn transfers(count: usize) -> impl futures_core::Stream<Item = TransferTransaction> {
+        futures_util::stream::iter((0..count).map(|_| {
+            let mut tx = TransferTransaction::new();
+            tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+                .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1));
+
+            tx
+        }))
+    }
+
+    fn throttle(max_in_flight_per_node: usize) -> Throttle {
+        Throttle::new(
+            max_in_flight_per_node,
+            ClientBackoff {
+                initial_backoff: Duration::from_millis(100),
+                max_backoff: Duration::from_millis(400),
+                ..ClientBackoff::default()
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn yields_every_receipt() {
+        let network = MockNetwork::start(2).await.unwrap();
+        let client = client(&network).await;
+
+        let results: Vec<_> =
+            client.bulk_executor().max_in_flight(4).execute(transfers(10)).collect().await;
+
+        let mut indexes: Vec<_> = results.iter().map(|it| it.0).collect();
+        indexes.sort_unstable();
+
+        assert_eq!(indexes, (0..10).collect::<Vec<_>>());
+        assert!(results.iter().all(|it| it.1.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn busy_backs_off_every_request() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let client = client(&network).await;
+        client.set_min_backoff(Duration::from_millis(200));
+
+        network.nodes()[0].push_response(MockResponse::Precheck(Status::Busy));
+
+        let started_at = Instant::now();
+
+        let results: Vec<_> = client
+            .bulk_executor()
+            .max_in_flight(1)
+            .execute(transfers(3))
+            .map(|it| it.1.map(|_| Instant::now()))
+            .collect()
+            .await;
+
+        // the first transaction had to wait out the back-off, and so did everything after it.
+        for result in results {
+            assert!(result.unwrap() - started_at >= Duration::from_millis(200));
+        }
+    }
+
+    #[tokio::test]
+    async fn caps_requests_per_node() {
+        let throttle = throttle(1);
+        let node = AccountId::new(0, 0, 3);
+
+        let permit = throttle.acquire(node).await;
+
+        assert!(throttle.acquire(node).now_or_never().is_none());
+        assert!(throttle.acquire(AccountId::new(0, 0, 4)).now_or_never().is_some());
+
+        drop(permit);
+
+        assert!(throttle.acquire(node).now_or_never().is_some());
+    }
+
+    #[test]
+    fn back_off_doubles_until_ok() {
+        let throttle = throttle(1);
+
+        throttle.observe_status(Status::Busy);
+        assert_eq!(throttle.back_off.lock().delay, Duration::from_millis(100));
+
+        // still backing off, so this doesn't count.
+        throttle.observe_status(Status::Busy);
+        assert_eq!(throttle.back_off.lock().delay, Duration::from_millis(100));
+
+        for expected in [200, 400, 400] {
+            throttle.back_off.lock().until = None;
+            throttle.back_off();
+            assert_eq!(throttle.back_off.lock().delay, Duration::from_millis(expected));
+        }
+
+        throttle.back_off.lock().until = None;
+        throttle.observe_status(Status::Ok);
+        assert_eq!(throttle.back_off.lock().delay, Duration::ZERO);
+    }
+}
@@ -20,13 +20,19 @@
 
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::time::Duration;
 
-use crate::signer::AnySigner;
 use crate::{
     AccountId,
+    Hbar,
+    LedgerId,
     PrivateKey,
 };
 
+#[cfg(test)]
+mod tests;
+
+#[derive(Clone)]
 struct FromStrProxy<T>(T);
 
 impl<'de, T: FromStr> serde::Deserialize<'de> for FromStrProxy<T>
@@ -44,26 +50,26 @@ where
     }
 }
 
-#[derive(serde_derive::Deserialize)]
+#[derive(Clone, serde_derive::Deserialize)]
 pub(super) struct Operator {
-    account_id: FromStrProxy<AccountId>,
+    account_id: AccountId,
     private_key: FromStrProxy<PrivateKey>,
 }
 
-impl From<Operator> for super::Operator {
-    fn from(value: Operator) -> Self {
-        Self { account_id: value.account_id.0, signer: AnySigner::PrivateKey(value.private_key.0) }
+impl Operator {
+    pub(super) fn into_parts(self) -> (AccountId, PrivateKey) {
+        (self.account_id, self.private_key.0)
     }
 }
 
-#[derive(serde_derive::Deserialize)]
+#[derive(Clone, serde_derive::Serialize, serde_derive::Deserialize)]
 #[serde(untagged)]
 pub(super) enum Either<L, R> {
     Left(L),
     Right(R),
 }
 
-#[derive(serde_derive::Deserialize)]
+#[derive(Clone, Copy, serde_derive::Serialize, serde_derive::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) enum NetworkName {
     Mainnet,
@@ -71,29 +77,78 @@ pub(crate) enum NetworkName {
     Previewnet,
 }
 
-#[derive(serde_derive::Deserialize)]
+/// The configuration of a [`Client`](crate::Client), in a form that can be loaded from, and saved to, config files.
+///
+/// Any format supported by `serde` can be used, for instance JSON or TOML.
+/// Only `network` is required, every other setting is left at its default when it's missing (or `null`).
+///
+/// The operator is given as `"operator": { "account_id": "0.0.1001", "private_key": "302e..." }`,
+/// it's never serialized.
+///
+/// Durations are given as an integer number of milliseconds, and the fields have a `Millis` suffix to match.
+/// A `networkUpdatePeriodMillis` of `0` disables network updates, and a `maxNodeAttempts` of `0` means there's no limit.
+/// `clockSkewMillis` is negative when this machine's clock is behind the network's.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() -> hedera::Result<()> {
+/// use hedera::Client;
+///
+/// let client = Client::from_config(r#"{
+///     "network": { "127.0.0.1:50211": "0.0.3" },
+///     "mirrorNetwork": ["127.0.0.1:5600"],
+///     "maxAttempts": 5,
+///     "requestTimeoutMillis": 30000,
+///     "defaultMaxTransactionFee": 100000000
+/// }"#)?;
+///
+/// assert_eq!(client.max_attempts(), 5);
+///
+/// // and back again, `config` can be serialized with any `serde` serializer.
+/// let config = client.to_config();
+/// # let _ = config;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, serde_derive::Serialize, serde_derive::Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub(super) struct ClientConfigInner {
-    operator: Option<Operator>,
-    network: Either<HashMap<String, FromStrProxy<AccountId>>, NetworkName>,
-    mirror_network: Option<Either<Vec<String>, NetworkName>>,
+pub struct ClientConfig {
+    // private keys are never written back out.
+    #[serde(skip_serializing)]
+    pub(super) operator: Option<Operator>,
+    pub(super) network: Either<HashMap<String, AccountId>, NetworkName>,
+    pub(super) mirror_network: Option<Either<Vec<String>, NetworkName>>,
+    pub(super) ledger_id: Option<LedgerId>,
+    pub(super) max_attempts: Option<usize>,
+    pub(super) min_backoff_millis: Option<u64>,
+    pub(super) max_backoff_millis: Option<u64>,
+    pub(super) request_timeout_millis: Option<u64>,
+    pub(super) grpc_timeout_millis: Option<u64>,
+    pub(super) default_max_transaction_fee: Option<Hbar>,
+    pub(super) default_max_query_payment: Option<Hbar>,
+    pub(super) auto_validate_checksums: Option<bool>,
+    pub(super) default_regenerate_transaction_id: Option<bool>,
+    pub(super) network_update_period_millis: Option<u64>,
+    pub(super) max_node_attempts: Option<usize>,
+    pub(super) min_node_backoff_millis: Option<u64>,
+    pub(super) max_node_backoff_millis: Option<u64>,
+    #[cfg(feature = "tls")]
+    pub(super) transport_security: Option<bool>,
+    #[cfg(feature = "tls")]
+    pub(super) verify_certificates: Option<bool>,
+    pub(super) clock_skew_millis: Option<i64>,
 }
 
-impl From<ClientConfigInner> for ClientConfig {
-    fn from(value: ClientConfigInner) -> Self {
-        Self {
-            operator: value.operator.map(Into::into),
-            network: match value.network {
-                Either::Left(it) => Either::Left(it.into_iter().map(|(k, v)| (k, v.0)).collect()),
-                Either::Right(it) => Either::Right(it),
-            },
-            mirror_network: value.mirror_network,
-        }
-    }
+pub(super) fn to_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
 }
 
-pub(super) struct ClientConfig {
-    pub(super) operator: Option<super::Operator>,
-    pub(super) network: Either<HashMap<String, AccountId>, NetworkName>,
-    pub(super) mirror_network: Option<Either<Vec<String>, NetworkName>>,
+pub(super) fn to_signed_millis(duration: time::Duration) -> i64 {
+    i64::try_from(duration.whole_milliseconds()).unwrap_or(if duration.is_negative() {
+        i64::MIN
+    } else {
+        i64::MAX
+    })
 }
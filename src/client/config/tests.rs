@@ -0,0 +1,153 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::time::Duration;
+
+use assert_matches::assert_matches;
+use serde_json::json;
+
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    LedgerId,
+};
+
+fn to_json(client: &Client) -> serde_json::Value {
+    serde_json::to_value(client.to_config()).unwrap()
+}
+
+#[cfg(feature = "tls")]
+#[tokio::test]
+async fn round_trip() {
+    let client = Client::for_network(HashMap::from([
+        ("127.0.0.1:50211".to_owned(), AccountId::new(0, 0, 3)),
+        ("127.0.0.1:50212".to_owned(), AccountId::new(0, 0, 4)),
+    ]))
+    .unwrap();
+
+    client.set_mirror_network(["127.0.0.1:5600".to_owned()]);
+    client.set_ledger_id(Some(LedgerId::testnet()));
+    client.set_max_attempts(3);
+    client.set_min_backoff(Duration::from_millis(100));
+    client.set_max_backoff(Duration::from_secs(4));
+    client.set_request_timeout(Some(Duration::from_secs(30)));
+    client.set_grpc_timeout(Some(Duration::from_secs(5)));
+    client.set_default_max_transaction_fee(Hbar::new(2));
+    client.set_default_max_query_payment(Hbar::from_tinybars(500));
+    client.set_auto_validate_checksums(true);
+    client.set_default_regenerate_transaction_id(false);
+    client.set_network_update_period(Some(Duration::from_secs(60)));
+    client.set_max_node_attempts(7);
+    client.set_min_node_backoff(Duration::from_millis(250));
+    client.set_max_node_backoff(Duration::from_secs(10));
+    client.set_transport_security(true);
+    client.set_verify_certificates(false);
+    client.set_clock_skew(time::Duration::milliseconds(-1500));
+
+    let json = to_json(&client);
+
+    assert_eq!(
+        json,
+        json!({
+            "network": { "127.0.0.1:50211": "0.0.3", "127.0.0.1:50212": "0.0.4" },
+            "mirrorNetwork": ["127.0.0.1:5600"],
+            "ledgerId": "testnet",
+            "maxAttempts": 3,
+            "minBackoffMillis": 100,
+            "maxBackoffMillis": 4000,
+            "requestTimeoutMillis": 30000,
+            "grpcTimeoutMillis": 5000,
+            "defaultMaxTransactionFee": 200_000_000,
+            "defaultMaxQueryPayment": 500,
+            "autoValidateChecksums": true,
+            "defaultRegenerateTransactionId": false,
+            "networkUpdatePeriodMillis": 60000,
+            "maxNodeAttempts": 7,
+            "minNodeBackoffMillis": 250,
+            "maxNodeBackoffMillis": 10000,
+            "transportSecurity": true,
+            "verifyCertificates": false,
+            "clockSkewMillis": -1500,
+        })
+    );
+
+    let loaded = Client::from_config(&json.to_string()).unwrap();
+
+    assert_eq!(to_json(&loaded), json);
+}
+
+#[tokio::test]
+async fn missing_settings_are_defaults() {
+    let client = Client::from_config(r#"{ "network": "testnet" }"#).unwrap();
+
+    assert_eq!(client.ledger_id_internal().as_deref(), Some(&LedgerId::testnet()));
+    assert_eq!(client.max_attempts(), 10);
+    assert_eq!(client.request_timeout(), None);
+    assert_eq!(client.default_max_transaction_fee(), None);
+    assert!(client.default_regenerate_transaction_id());
+    #[cfg(feature = "tls")]
+    assert!(!client.transport_security());
+    assert_eq!(client.get_operator_account_id(), None);
+}
+
+#[tokio::test]
+async fn zero_disables() {
+    let client = Client::from_config(
+        r#"{
+            "network": { "127.0.0.1:50211": "0.0.3" },
+            "networkUpdatePeriodMillis": 0,
+            "maxNodeAttempts": 0
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(client.network_update_period(), None);
+    assert_eq!(client.max_node_attempts(), None::<NonZeroUsize>);
+}
+
+#[tokio::test]
+async fn operator_is_not_exported() {
+    let client = Client::from_config(
+        r#"{
+            "operator": {
+                "account_id": "0.0.1001",
+                "private_key": "302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e10"
+            },
+            "network": { "127.0.0.1:50211": "0.0.3" }
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(client.get_operator_account_id(), Some(AccountId::new(0, 0, 1001)));
+    assert!(!to_json(&client).as_object().unwrap().contains_key("operator"));
+}
+
+#[tokio::test]
+async fn negative_fee() {
+    let res = Client::from_config(
+        r#"{ "network": { "127.0.0.1:50211": "0.0.3" }, "defaultMaxQueryPayment": -1 }"#,
+    );
+
+    assert_matches!(res, Err(Error::BasicParse(_)));
+}
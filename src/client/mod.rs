@@ -39,30 +39,48 @@ pub(crate) use network::{
 pub(crate) use operator::Operator;
 use parking_lot::RwLock;
 use tokio::sync::watch;
+pub(crate) use transaction_id_generator::TransactionIdGenerator;
 use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
 use self::network::managed::ManagedNetwork;
 use self::network::mirror::MirrorNetwork;
 pub(crate) use self::network::mirror::MirrorNetworkData;
+use crate::interceptor::Interceptors;
 use crate::ping_query::PingQuery;
 use crate::signer::AnySigner;
+use crate::submission_journal::{
+    self,
+    Journal,
+};
 use crate::{
     AccountId,
     ArcSwapOption,
     Error,
     Hbar,
+    Interceptor,
     LedgerId,
     NodeAddressBook,
     NodeAddressBookQuery,
     PrivateKey,
     PublicKey,
+    RecoveredSubmission,
+    Signer,
+    SubmissionJournal,
+    TransactionId,
 };
 
 #[cfg(feature = "serde")]
 mod config;
+#[cfg(feature = "serde")]
+pub use config::ClientConfig;
 
 mod network;
 mod operator;
+mod transaction_id_generator;
 
 #[derive(Copy, Clone)]
 pub(crate) struct ClientBackoff {
@@ -157,6 +175,9 @@ impl ClientBuilder {
             regenerate_transaction_ids: AtomicBool::new(regenerate_transaction_ids),
             network_update_tx,
             backoff: RwLock::new(backoff),
+            interceptors: RwLock::default(),
+            transaction_id_generator: TransactionIdGenerator::default(),
+            submission_journal: RwLock::default(),
         }))
     }
 }
@@ -171,6 +192,9 @@ struct ClientInner {
     regenerate_transaction_ids: AtomicBool,
     network_update_tx: watch::Sender<Option<Duration>>,
     backoff: RwLock<ClientBackoff>,
+    interceptors: RwLock<Interceptors>,
+    transaction_id_generator: TransactionIdGenerator,
+    submission_journal: RwLock<Option<Journal>>,
 }
 
 /// Managed client for use on the Hedera network.
@@ -185,9 +209,39 @@ impl fmt::Debug for Client {
 }
 
 impl Client {
+    /// Create a client from the given config.
+    ///
+    /// Settings missing from `config` are left at their defaults.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if any node address is unparsable,
+    ///   or if the default max transaction fee or query payment is negative.
     #[cfg(feature = "serde")]
-    fn from_config_data(config: config::ClientConfig) -> crate::Result<Self> {
-        let config::ClientConfig { operator, network, mirror_network } = config;
+    pub fn from_config_data(config: ClientConfig) -> crate::Result<Self> {
+        let ClientConfig {
+            operator,
+            network,
+            mirror_network,
+            ledger_id,
+            max_attempts,
+            min_backoff_millis,
+            max_backoff_millis,
+            request_timeout_millis,
+            grpc_timeout_millis,
+            default_max_transaction_fee,
+            default_max_query_payment,
+            auto_validate_checksums,
+            default_regenerate_transaction_id,
+            network_update_period_millis,
+            max_node_attempts,
+            min_node_backoff_millis,
+            max_node_backoff_millis,
+            #[cfg(feature = "tls")]
+            transport_security,
+            #[cfg(feature = "tls")]
+            verify_certificates,
+            clock_skew_millis,
+        } = config;
 
         // fixme: check to ensure net and mirror net are the same when they're a network name (no other SDK actually checks this though)
         let client = match network {
@@ -211,29 +265,153 @@ impl Client {
         });
 
         if let Some(operator) = operator {
-            client.0.operator.store(Some(Arc::new(operator)));
+            let (account_id, private_key) = operator.into_parts();
+            client.set_operator(account_id, private_key);
         }
 
         if let Some(mirror_network) = mirror_network {
             client.set_mirror_network(mirror_network.load().addresses());
         }
 
+        if let Some(ledger_id) = ledger_id {
+            client.set_ledger_id(Some(ledger_id));
+        }
+
+        for (name, fee) in [
+            ("default max transaction fee", default_max_transaction_fee),
+            ("default max query payment", default_max_query_payment),
+        ] {
+            if fee.is_some_and(|it| it < Hbar::ZERO) {
+                return Err(Error::basic_parse(format!("{name} must not be negative")));
+            }
+        }
+
+        if let Some(it) = default_max_transaction_fee {
+            client.set_default_max_transaction_fee(it);
+        }
+
+        if let Some(it) = default_max_query_payment {
+            client.set_default_max_query_payment(it);
+        }
+
+        {
+            let mut backoff = client.0.backoff.write();
+
+            if let Some(it) = max_attempts {
+                backoff.max_attempts = it;
+            }
+
+            if let Some(it) = min_backoff_millis {
+                backoff.initial_backoff = Duration::from_millis(it);
+            }
+
+            if let Some(it) = max_backoff_millis {
+                backoff.max_backoff = Duration::from_millis(it);
+            }
+
+            if let Some(it) = request_timeout_millis {
+                backoff.request_timeout = Some(Duration::from_millis(it));
+            }
+
+            if let Some(it) = grpc_timeout_millis {
+                backoff.grpc_timeout = Some(Duration::from_millis(it));
+            }
+        }
+
+        if let Some(it) = auto_validate_checksums {
+            client.set_auto_validate_checksums(it);
+        }
+
+        if let Some(it) = default_regenerate_transaction_id {
+            client.set_default_regenerate_transaction_id(it);
+        }
+
+        if let Some(it) = network_update_period_millis {
+            client.set_network_update_period((it != 0).then(|| Duration::from_millis(it)));
+        }
+
+        if let Some(it) = max_node_attempts {
+            client.set_max_node_attempts(it);
+        }
+
+        if let Some(it) = min_node_backoff_millis {
+            client.set_min_node_backoff(Duration::from_millis(it));
+        }
+
+        if let Some(it) = max_node_backoff_millis {
+            client.set_max_node_backoff(Duration::from_millis(it));
+        }
+
+        #[cfg(feature = "tls")]
+        {
+            if let Some(it) = transport_security {
+                client.set_transport_security(it);
+            }
+
+            if let Some(it) = verify_certificates {
+                client.set_verify_certificates(it);
+            }
+        }
+
+        if let Some(it) = clock_skew_millis {
+            client.set_clock_skew(time::Duration::milliseconds(it));
+        }
+
         Ok(client)
     }
 
-    /// Create a client from the given json config.
+    /// Create a client from the given json config, see [`ClientConfig`] for the format.
     ///
     /// # Errors
     /// - [`Error::BasicParse`] if an error occurs parsing the configuration.
     #[cfg(feature = "serde")]
     pub fn from_config(json: &str) -> crate::Result<Self> {
-        let config = serde_json::from_str::<config::ClientConfigInner>(json)
-            .map_err(crate::Error::basic_parse)?
-            .into();
+        let config =
+            serde_json::from_str::<ClientConfig>(json).map_err(crate::Error::basic_parse)?;
 
         Self::from_config_data(config)
     }
 
+    /// Returns this client's configuration, which [`from_config_data`](Self::from_config_data) turns back into an equivalent client.
+    ///
+    /// Every setting is included, so that configs can be diffed.
+    /// The network is always given as a map of node addresses (never as a network name),
+    /// and the operator isn't included at all, to keep private keys out of exported configs.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_config(&self) -> ClientConfig {
+        let backoff = self.backoff();
+
+        ClientConfig {
+            operator: None,
+            network: config::Either::Left(self.network()),
+            mirror_network: Some(config::Either::Left(self.mirror_network())),
+            ledger_id: self.ledger_id_internal().as_deref().cloned(),
+            max_attempts: Some(backoff.max_attempts),
+            min_backoff_millis: Some(config::to_millis(backoff.initial_backoff)),
+            max_backoff_millis: Some(config::to_millis(backoff.max_backoff)),
+            request_timeout_millis: backoff.request_timeout.map(config::to_millis),
+            grpc_timeout_millis: backoff.grpc_timeout.map(config::to_millis),
+            default_max_transaction_fee: Some(
+                self.default_max_transaction_fee().unwrap_or(Hbar::ZERO),
+            ),
+            default_max_query_payment: Some(self.default_max_query_payment().unwrap_or(Hbar::ZERO)),
+            auto_validate_checksums: Some(self.auto_validate_checksums()),
+            default_regenerate_transaction_id: Some(self.default_regenerate_transaction_id()),
+            network_update_period_millis: Some(
+                self.network_update_period().map_or(0, config::to_millis),
+            ),
+            max_node_attempts: Some(self.max_node_attempts().map_or(0, NonZeroUsize::get)),
+            min_node_backoff_millis: Some(config::to_millis(self.min_node_backoff())),
+            max_node_backoff_millis: Some(config::to_millis(self.max_node_backoff())),
+            #[cfg(feature = "tls")]
+            transport_security: Some(self.transport_security()),
+            #[cfg(feature = "tls")]
+            verify_certificates: Some(self.verify_certificates()),
+            clock_skew_millis: Some(config::to_signed_millis(self.clock_skew())),
+        }
+    }
+
     /// Returns the addresses for the configured mirror network.
     ///
     /// Unless _explicitly_ set, the return value isn't guaranteed to be anything in particular in order to allow future changes without breaking semver.
@@ -391,6 +569,51 @@ impl Client {
         self.net().0.load().set_min_backoff(min_node_backoff)
     }
 
+    /// Returns true if the client connects to consensus nodes over TLS.
+    ///
+    /// This is `false` by default.
+    #[must_use]
+    #[cfg(feature = "tls")]
+    pub fn transport_security(&self) -> bool {
+        self.net().0.load().transport_security().enabled
+    }
+
+    /// Enable or disable TLS for connections to consensus nodes.
+    ///
+    /// When enabled, nodes are contacted on their TLS port (`50212`) instead of the plaintext port (`50211`).
+    /// Node certificates are self-signed, so they're pinned to the certificate hash in the address book
+    /// instead of being validated against a root of trust (see [`set_verify_certificates`](Self::set_verify_certificates)).
+    ///
+    /// A node that fails the TLS handshake is treated the same as a node that can't be reached,
+    /// it's marked unhealthy and the request is retried on another node.
+    ///
+    /// Changing this drops any open connections to consensus nodes.
+    #[cfg(feature = "tls")]
+    pub fn set_transport_security(&self, value: bool) {
+        self.net().update_transport_security(|it| it.enabled = value);
+    }
+
+    /// Returns true if node certificates are checked against the address book when using TLS.
+    ///
+    /// This is `true` by default.
+    #[must_use]
+    #[cfg(feature = "tls")]
+    pub fn verify_certificates(&self) -> bool {
+        self.net().0.load().transport_security().verify_certificates
+    }
+
+    /// Enable or disable checking node certificates against the address book when using TLS.
+    ///
+    /// Only nodes set with [`set_network_from_address_book`](Self::set_network_from_address_book)
+    /// (or updated from the network's address book) have a known certificate hash,
+    /// with verification enabled, TLS connections to any other node fail.
+    ///
+    /// Changing this drops any open connections to consensus nodes.
+    #[cfg(feature = "tls")]
+    pub fn set_verify_certificates(&self, value: bool) {
+        self.net().update_transport_security(|it| it.verify_certificates = value);
+    }
+
     /// Construct a hedera client pre-configured for access to the given network.
     ///
     /// Currently supported network names are `"mainnet"`, `"testnet"`, and `"previewnet"`.
@@ -457,9 +680,7 @@ impl Client {
     ///
     /// The operator private key is used to sign all transactions executed by this client.
     pub fn set_operator(&self, id: AccountId, key: PrivateKey) {
-        self.0
-            .operator
-            .store(Some(Arc::new(Operator { account_id: id, signer: AnySigner::PrivateKey(key) })));
+        self.store_operator(id, AnySigner::PrivateKey(key));
     }
 
     /// Sets the account that will, by default, be paying for transactions and queries built with
@@ -475,12 +696,63 @@ impl Client {
         public_key: PublicKey,
         f: F,
     ) {
+        self.store_operator(id, AnySigner::arbitrary(Box::new(public_key), f));
+    }
+
+    /// Sets the account that will, by default, be paying for transactions and queries built with
+    /// this client.
+    ///
+    /// The operator account ID is used to generate the default transaction ID for all transactions
+    /// executed with this client.
+    ///
+    /// The operator [`Signer`] is used to sign all transactions executed by this client,
+    /// signing happens asynchronously, as part of execution.
+    pub fn set_operator_with_signer<S: Signer + 'static>(&self, id: AccountId, signer: S) {
+        self.store_operator(id, AnySigner::signer(signer));
+    }
+
+    fn store_operator(&self, account_id: AccountId, signer: AnySigner) {
         self.0.operator.store(Some(Arc::new(Operator {
-            account_id: id,
-            signer: AnySigner::arbitrary(Box::new(public_key), f),
+            account_id,
+            signer,
+            transaction_id_generator: self.0.transaction_id_generator.clone(),
         })));
     }
 
+    /// Generates a new transaction ID for the given payer account ID.
+    ///
+    /// Unlike [`TransactionId::generate`], the valid starts of the IDs this client
+    /// (and its clones) generates for a payer are unique and increasing,
+    /// even when generated from many tasks at once or when the system clock goes backwards.
+    /// Transaction IDs that are generated automatically, such as when executing transactions, come from here.
+    ///
+    /// The valid start is backdated by a few seconds, and by the [`clock_skew`](Self::clock_skew).
+    #[must_use]
+    pub fn generate_transaction_id(&self, account_id: AccountId) -> TransactionId {
+        self.0.transaction_id_generator.generate(account_id)
+    }
+
+    /// Returns how far this machine's clock is assumed to be ahead of the network's.
+    ///
+    /// Defaults to zero.
+    #[must_use]
+    pub fn clock_skew(&self) -> time::Duration {
+        self.0.transaction_id_generator.clock_skew()
+    }
+
+    /// Sets how far this machine's clock is assumed to be ahead of the network's, negative if it's behind.
+    ///
+    /// The skew is subtracted from the valid start of generated transaction IDs,
+    /// which avoids `INVALID_TRANSACTION_START` when the clock is ahead,
+    /// and `TRANSACTION_EXPIRED` when it's far behind.
+    pub fn set_clock_skew(&self, clock_skew: time::Duration) {
+        self.0.transaction_id_generator.set_clock_skew(clock_skew);
+    }
+
+    pub(crate) fn transaction_id_generator(&self) -> TransactionIdGenerator {
+        self.0.transaction_id_generator.clone()
+    }
+
     /// Gets a reference to the configured network.
     pub(crate) fn net(&self) -> &Network {
         &self.0.network.primary
@@ -538,6 +810,19 @@ impl Client {
         self.0.backoff.write().request_timeout = timeout;
     }
 
+    /// Returns the maximum amount of time a single gRPC request to a node can take.
+    #[must_use]
+    pub fn grpc_timeout(&self) -> Option<Duration> {
+        self.backoff().grpc_timeout
+    }
+
+    /// Sets the maximum amount of time a single gRPC request to a node can take.
+    ///
+    /// A request that takes longer than this is retried on another node.
+    pub fn set_grpc_timeout(&self, timeout: Option<Duration>) {
+        self.0.backoff.write().grpc_timeout = timeout;
+    }
+
     /// Returns the maximum number of attempts for a request.
     #[must_use]
     pub fn max_attempts(&self) -> usize {
@@ -558,8 +843,8 @@ impl Client {
 
     /// Sets the initial backoff for a request being executed.
     #[doc(alias = "set_initial_backoff")]
-    pub fn set_min_backoff(&self, max_backoff: Duration) {
-        self.0.backoff.write().max_backoff = max_backoff;
+    pub fn set_min_backoff(&self, min_backoff: Duration) {
+        self.0.backoff.write().initial_backoff = min_backoff;
     }
 
     /// Returns the maximum amount of time a request will wait between attempts.
@@ -578,6 +863,57 @@ impl Client {
         *self.0.backoff.read()
     }
 
+    /// Adds an interceptor that's called for every request this client sends to a node.
+    ///
+    /// Interceptors are called in the order they were added, see [`Interceptor`] for details.
+    pub fn add_interceptor<I: Interceptor + 'static>(&self, interceptor: I) {
+        self.0.interceptors.write().push(interceptor);
+    }
+
+    /// Removes every interceptor added with [`add_interceptor`](Self::add_interceptor).
+    pub fn clear_interceptors(&self) {
+        *self.0.interceptors.write() = Interceptors::default();
+    }
+
+    pub(crate) fn interceptors(&self) -> Interceptors {
+        self.0.interceptors.read().clone()
+    }
+
+    /// Sets the journal that every transaction this client submits is recorded in, see [`SubmissionJournal`].
+    ///
+    /// This replaces any previously set journal.
+    pub fn set_submission_journal<J: SubmissionJournal + 'static>(&self, journal: J) {
+        *self.0.submission_journal.write() =
+            Some(Arc::new(journal).unsize(Coercion!(to dyn SubmissionJournal)));
+    }
+
+    /// Stops recording submitted transactions in the journal set with [`set_submission_journal`](Self::set_submission_journal).
+    pub fn clear_submission_journal(&self) {
+        *self.0.submission_journal.write() = None;
+    }
+
+    pub(crate) fn submission_journal(&self) -> Option<Journal> {
+        self.0.submission_journal.read().clone()
+    }
+
+    /// Finds out what happened to every transaction left in the submission journal.
+    ///
+    /// Transactions that are still within their valid duration are sent to their node again first,
+    /// which is harmless if the node already had them.
+    /// Then the receipt of each transaction is queried; entries that get a receipt (of any status)
+    /// are removed from the journal.
+    ///
+    /// Returns nothing if no journal is set.
+    ///
+    /// # Errors
+    /// - [`Error::Journal`] if the journal's entries couldn't be read.
+    pub async fn recover_submissions(&self) -> crate::Result<Vec<RecoveredSubmission>> {
+        match self.submission_journal() {
+            Some(journal) => submission_journal::recover(self, &journal).await,
+            None => Ok(Vec::new()),
+        }
+    }
+
     // keep this internal (repr)
     pub(crate) fn load_operator(&self) -> arc_swap::Guard<Option<Arc<Operator>>> {
         self.0.operator.load()
@@ -588,6 +924,15 @@ impl Client {
         self.0.operator.load_full()
     }
 
+    /// Returns a [`BulkExecutor`](crate::BulkExecutor) for executing many transactions with this client at once.
+    ///
+    /// Requires the `bulk-executor` feature.
+    #[cfg(feature = "bulk-executor")]
+    #[must_use]
+    pub fn bulk_executor(&self) -> crate::BulkExecutor {
+        crate::BulkExecutor::new(self.clone())
+    }
+
     /// Send a ping to the given node.
     pub async fn ping(&self, node_account_id: AccountId) -> crate::Result<()> {
         PingQuery::new(node_account_id).execute(self, None).await
@@ -633,7 +978,7 @@ impl Client {
     /// Note that network updates will not affect any in-flight requests.
     pub fn set_network_update_period(&self, period: Option<Duration>) {
         self.0.network_update_tx.send_if_modified(|place| {
-            let changed = *place == period;
+            let changed = *place != period;
             if changed {
                 *place = period;
             }
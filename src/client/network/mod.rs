@@ -20,6 +20,8 @@
 
 pub(super) mod managed;
 pub(super) mod mirror;
+#[cfg(feature = "tls")]
+mod tls;
 
 use std::borrow::Cow;
 use std::collections::{
@@ -27,7 +29,7 @@ use std::collections::{
     HashMap,
 };
 use std::fmt;
-use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 use std::time::{
@@ -165,6 +167,15 @@ impl Network {
         // todo: skip the updating whem `map` is the same and `connections` is the same.
         self.rcu(|old| NetworkData::with_address_book(old, address_book));
     }
+
+    #[cfg(feature = "tls")]
+    pub(crate) fn update_transport_security<F: Fn(&mut TransportSecurity)>(&self, f: F) {
+        self.rcu(|old| {
+            let mut transport_security = old.transport_security;
+            f(&mut transport_security);
+            old.with_transport_security(transport_security)
+        });
+    }
 }
 
 impl From<NetworkData> for Network {
@@ -182,6 +193,7 @@ pub(crate) struct NetworkData {
     // Health stuff has to be in an Arc because it needs to stick around even if the map changes.
     health: Box<[Arc<parking_lot::RwLock<NodeHealth>>]>,
     connections: Box<[NodeConnection]>,
+    transport_security: TransportSecurity,
 }
 
 impl NetworkData {
@@ -210,6 +222,7 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            transport_security: TransportSecurity::default(),
         }
     }
 
@@ -222,31 +235,41 @@ impl NetworkData {
         let mut health = Vec::with_capacity(address_book.len());
 
         for (i, address) in address_book.iter().enumerate() {
+            // TLS endpoints are folded into their plaintext counterparts,
+            // the port actually used gets picked when connecting.
+            // endpoints without an IPv4 address are IPv6 addresses or domain names, both just hosts by now.
             let new: BTreeSet<_> = address
                 .service_endpoints
                 .iter()
-                .filter(|it| it.port() == NodeConnection::PLAINTEXT_PORT)
-                .map(|it| (*it.ip()).into())
+                .map(|it| (it.ip().to_string(), it.port()))
+                .chain(
+                    address.endpoints.iter().filter(|it| it.ip_address_v4.is_none()).filter_map(
+                        |it| Some((it.domain_name.clone(), u16::try_from(it.port).ok()?)),
+                    ),
+                )
+                .filter_map(|(host, port)| HostAndPort::from_address_book(host, port))
                 .collect();
 
+            let tls_certificate_hash = &address.tls_certificate_hash;
+
             // if the node is the exact same we want to reuse everything (namely the connections and `healthy`).
             // if the node has different routes then we still want to reuse `healthy` but replace the channel with a new channel.
             // if the node just flat out doesn't exist in `old`, we want to add the new node.
             // and, last but not least, if the node doesn't exist in `new` we want to get rid of it.
             let upsert = match old.map.get(&address.node_account_id) {
                 Some(&account) => {
+                    let old_connection = &old.connections[account];
                     let connection =
-                        match old.connections[account].addresses.symmetric_difference(&new).count()
-                        {
-                            0 => old.connections[account].clone(),
-                            _ => NodeConnection { addresses: new, channel: OnceCell::new() },
+                        match old_connection.addresses.symmetric_difference(&new).count() {
+                            0 if old_connection.tls_certificate_hash == *tls_certificate_hash => {
+                                old_connection.clone()
+                            }
+                            _ => NodeConnection::new(new, tls_certificate_hash.clone()),
                         };
 
                     (old.health[account].clone(), connection)
                 }
-                None => {
-                    (Arc::default(), NodeConnection { addresses: new, channel: OnceCell::new() })
-                }
+                None => (Arc::default(), NodeConnection::new(new, tls_certificate_hash.clone())),
             };
 
             map.insert(address.node_account_id, i);
@@ -261,6 +284,7 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            transport_security: old.transport_security,
         }
     }
 
@@ -284,10 +308,14 @@ impl NetworkData {
                     entry.insert(next_index);
                     node_ids.push(*node);
                     // fixme: keep the channel around more.
-                    connections.push(NodeConnection {
-                        addresses: BTreeSet::from([address]),
-                        channel: OnceCell::new(),
-                    });
+                    // the address book is the only source of certificate hashes, so keep the one we already know.
+                    let tls_certificate_hash = match self.map.get(node) {
+                        Some(it) => self.connections[*it].tls_certificate_hash.clone(),
+                        None => Vec::new(),
+                    };
+
+                    connections
+                        .push(NodeConnection::new(BTreeSet::from([address]), tls_certificate_hash));
 
                     health.push(match self.map.get(node) {
                         Some(it) => self.health[*it].clone(),
@@ -303,9 +331,34 @@ impl NetworkData {
             health: health.into_boxed_slice(),
             connections: connections.into_boxed_slice(),
             backoff: NodeBackoff::default().into(),
+            transport_security: self.transport_security,
         })
     }
 
+    #[cfg(feature = "tls")]
+    fn with_transport_security(&self, transport_security: TransportSecurity) -> Self {
+        // existing channels were made for the old settings, so every connection gets a fresh channel.
+        let connections = self
+            .connections
+            .iter()
+            .map(|it| NodeConnection::new(it.addresses.clone(), it.tls_certificate_hash.clone()))
+            .collect();
+
+        Self {
+            map: self.map.clone(),
+            node_ids: self.node_ids.clone(),
+            backoff: RwLock::new(*self.backoff.read()),
+            health: self.health.clone(),
+            connections,
+            transport_security,
+        }
+    }
+
+    #[cfg(feature = "tls")]
+    pub(crate) fn transport_security(&self) -> TransportSecurity {
+        self.transport_security
+    }
+
     pub(crate) fn node_ids(&self) -> &[AccountId] {
         &self.node_ids
     }
@@ -404,7 +457,7 @@ impl NetworkData {
     pub(crate) fn channel(&self, index: usize) -> (AccountId, Channel) {
         let id = self.node_ids[index];
 
-        let channel = self.connections[index].channel();
+        let channel = self.connections[index].channel(self.transport_security);
 
         (id, channel)
     }
@@ -419,6 +472,25 @@ impl NetworkData {
     }
 }
 
+/// How connections to consensus nodes are secured.
+///
+/// Without the `tls` feature this is always the default, plaintext connections.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(not(feature = "tls"), allow(dead_code))]
+pub(crate) struct TransportSecurity {
+    /// Connect to nodes over TLS instead of plaintext.
+    pub(crate) enabled: bool,
+
+    /// Check node certificates against the hashes in the address book (only applies when `enabled`).
+    pub(crate) verify_certificates: bool,
+}
+
+impl Default for TransportSecurity {
+    fn default() -> Self {
+        Self { enabled: false, verify_certificates: true }
+    }
+}
+
 #[derive(Default)]
 enum NodeHealth {
     /// The node has never been used, so we don't know anything about it.
@@ -542,53 +614,94 @@ impl HostAndPort {
     const fn from_static(host: &'static str) -> Self {
         Self { host: Cow::Borrowed(host), port: NodeConnection::PLAINTEXT_PORT }
     }
+
+    /// Returns the address a node can be reached at through an address book endpoint,
+    /// or `None` if `port` isn't one that nodes serve the API on.
+    ///
+    /// Address books may leave the port unset (`0`) or list the legacy `50111`, both mean `50211`.
+    /// Like the static networks this always uses the plaintext port, see [`to_tls`](Self::to_tls).
+    fn from_address_book(host: String, port: u16) -> Option<Self> {
+        match port {
+            0 | 50111 | NodeConnection::PLAINTEXT_PORT | NodeConnection::TLS_PORT => {
+                Some(Self { host: Cow::Owned(host), port: NodeConnection::PLAINTEXT_PORT })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the TLS equivalent of this address.
+    ///
+    /// Nodes serve TLS on `50212` rather than `50211`, any other port is assumed to already be correct.
+    #[cfg(feature = "tls")]
+    fn to_tls(&self) -> Self {
+        let port = match self.port {
+            NodeConnection::PLAINTEXT_PORT => NodeConnection::TLS_PORT,
+            port => port,
+        };
+
+        Self { host: self.host.clone(), port }
+    }
 }
 
 impl FromStr for HostAndPort {
     type Err = crate::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (host, port) = s.split_once(':').ok_or_else(|| Error::basic_parse("Invalid uri"))?;
+        let (host, port) = s.rsplit_once(':').ok_or_else(|| Error::basic_parse("Invalid uri"))?;
 
-        Ok(Self {
-            host: Cow::Owned(host.to_owned()),
-            port: port.parse().map_err(Error::basic_parse)?,
-        })
+        // IPv6 addresses are bracketed so that their colons aren't confused with the port's.
+        let host = match host.strip_prefix('[').and_then(|it| it.strip_suffix(']')) {
+            Some(host) => host.parse::<Ipv6Addr>().map_err(Error::basic_parse)?.to_string(),
+            None => host.to_owned(),
+        };
+
+        Ok(Self { host: Cow::Owned(host), port: port.parse().map_err(Error::basic_parse)? })
     }
 }
 
 impl fmt::Display for HostAndPort {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}:{}", self.host, self.port)
-    }
-}
-
-impl From<Ipv4Addr> for HostAndPort {
-    fn from(value: Ipv4Addr) -> Self {
-        Self { host: Cow::Owned(value.to_string()), port: NodeConnection::PLAINTEXT_PORT }
+        if self.host.contains(':') {
+            write!(f, "[{}]:{}", self.host, self.port)
+        } else {
+            write!(f, "{}:{}", self.host, self.port)
+        }
     }
 }
 
 #[derive(Clone)]
 struct NodeConnection {
     addresses: BTreeSet<HostAndPort>,
+    /// The node's certificate hash from the address book, empty if the node didn't come from an address book.
+    tls_certificate_hash: Vec<u8>,
     channel: OnceCell<Channel>,
 }
 
 impl NodeConnection {
     const PLAINTEXT_PORT: u16 = 50211;
+    const TLS_PORT: u16 = 50212;
+
+    fn new(addresses: BTreeSet<HostAndPort>, tls_certificate_hash: Vec<u8>) -> Self {
+        Self { addresses, tls_certificate_hash, channel: OnceCell::new() }
+    }
 
     fn new_static(addresses: &[&'static str]) -> NodeConnection {
-        Self {
-            addresses: addresses.iter().copied().map(HostAndPort::from_static).collect(),
-            channel: OnceCell::default(),
-        }
+        Self::new(addresses.iter().copied().map(HostAndPort::from_static).collect(), Vec::new())
     }
 
-    pub(crate) fn channel(&self) -> Channel {
+    #[cfg_attr(not(feature = "tls"), allow(unused_variables))]
+    pub(crate) fn channel(&self, transport_security: TransportSecurity) -> Channel {
         let channel = self
             .channel
             .get_or_init(|| {
+                #[cfg(feature = "tls")]
+                if transport_security.enabled {
+                    if let Some(channel) = self.tls_channel(transport_security.verify_certificates)
+                    {
+                        return channel;
+                    }
+                }
+
                 let addresses = self.addresses.iter().map(|it| {
                     Endpoint::from_shared(format!("tcp://{it}"))
                         .unwrap()
@@ -604,4 +717,117 @@ impl NodeConnection {
 
         channel
     }
+
+    /// Creates a channel that connects to the node over TLS, returns `None` if the node has no addresses.
+    #[cfg(feature = "tls")]
+    fn tls_channel(&self, verify_certificates: bool) -> Option<Channel> {
+        let addresses: Vec<_> = self.addresses.iter().map(HostAndPort::to_tls).collect();
+
+        // the connector picks the actual address, this is only used for the `:authority` of requests,
+        // and `http` keeps tonic from trying to layer its own TLS on top of ours.
+        let endpoint = Endpoint::from_shared(format!("http://{}", addresses.first()?))
+            .unwrap()
+            .keep_alive_timeout(Duration::from_secs(10))
+            .keep_alive_while_idle(true)
+            .connect_timeout(Duration::from_secs(10));
+
+        let connector = tls::NodeTlsConnector::new(
+            addresses,
+            self.tls_certificate_hash.clone(),
+            verify_certificates,
+        );
+
+        Some(endpoint.connect_with_connector_lazy(connector))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::net::{
+        Ipv4Addr,
+        Ipv6Addr,
+        SocketAddrV4,
+    };
+
+    use super::{
+        HostAndPort,
+        NetworkData,
+    };
+    use crate::{
+        AccountId,
+        NodeAddress,
+        NodeAddressBook,
+        ServiceEndpoint,
+    };
+
+    fn domain_endpoint(domain_name: &str, port: i32) -> ServiceEndpoint {
+        ServiceEndpoint { ip_address_v4: None, port, domain_name: domain_name.to_owned() }
+    }
+
+    fn node(num: u64) -> NodeAddress {
+        NodeAddress {
+            node_id: num - 3,
+            rsa_public_key: Vec::new(),
+            node_account_id: AccountId::from(num),
+            tls_certificate_hash: Vec::new(),
+            service_endpoints: Vec::new(),
+            description: String::new(),
+            endpoints: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn address_book_endpoints() {
+        let address_book = NodeAddressBook {
+            node_addresses: vec![
+                NodeAddress {
+                    service_endpoints: vec![SocketAddrV4::new(Ipv4Addr::LOCALHOST, 50211)],
+                    endpoints: vec![domain_endpoint(&Ipv6Addr::LOCALHOST.to_string(), 0)],
+                    ..node(3)
+                },
+                NodeAddress {
+                    endpoints: vec![
+                        domain_endpoint("node1.solo.local", 50211),
+                        domain_endpoint("node1.solo.local", 50212),
+                        // not a consensus node port, so it gets skipped.
+                        domain_endpoint("mirror.solo.local", 5600),
+                    ],
+                    ..node(4)
+                },
+                NodeAddress {
+                    service_endpoints: vec![SocketAddrV4::new([10, 0, 0, 5].into(), 50111)],
+                    ..node(5)
+                },
+            ],
+        };
+
+        let network = NetworkData::with_address_book(&NetworkData::default(), &address_book);
+
+        assert_eq!(
+            network.addresses(),
+            HashMap::from([
+                ("127.0.0.1:50211".to_owned(), AccountId::from(3)),
+                ("[::1]:50211".to_owned(), AccountId::from(3)),
+                ("node1.solo.local:50211".to_owned(), AccountId::from(4)),
+                ("10.0.0.5:50211".to_owned(), AccountId::from(5)),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_host_and_port() {
+        let address: HostAndPort = "[::1]:50211".parse().unwrap();
+
+        assert_eq!(address.host, "::1");
+        assert_eq!(address.port, 50211);
+        assert_eq!(address.to_string(), "[::1]:50211");
+
+        let address: HostAndPort = "node1.solo.local:50212".parse().unwrap();
+
+        assert_eq!(address.host, "node1.solo.local");
+        assert_eq!(address.to_string(), "node1.solo.local:50212");
+
+        "[node1.solo.local]:50211".parse::<HostAndPort>().unwrap_err();
+    }
 }
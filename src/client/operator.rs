@@ -1,3 +1,4 @@
+use super::TransactionIdGenerator;
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
@@ -9,16 +10,21 @@ use crate::{
 pub(crate) struct Operator {
     pub(crate) account_id: AccountId,
     pub(crate) signer: AnySigner,
+    pub(crate) transaction_id_generator: TransactionIdGenerator,
 }
 
 impl Operator {
-    #[must_use]
-    pub(crate) fn sign(&self, body_bytes: &[u8]) -> (PublicKey, Vec<u8>) {
-        self.signer.sign(body_bytes)
+    pub(crate) async fn sign(&self, body_bytes: &[u8]) -> crate::Result<(PublicKey, Vec<u8>)> {
+        self.signer.sign(body_bytes).await
     }
 
     #[must_use]
     pub(crate) fn generate_transaction_id(&self) -> TransactionId {
-        TransactionId::generate(self.account_id)
+        self.transaction_id_generator.generate(self.account_id)
+    }
+
+    #[must_use]
+    pub(crate) fn reserve_transaction_ids(&self, count: usize) -> TransactionId {
+        self.transaction_id_generator.reserve(self.account_id, count)
     }
 }
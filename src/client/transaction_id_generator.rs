@@ -0,0 +1,220 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+use triomphe::Arc;
+
+use crate::transaction::DEFAULT_TRANSACTION_VALID_DURATION;
+use crate::{
+    AccountId,
+    TransactionId,
+};
+
+/// Generates transaction IDs whose valid starts never repeat, or go backwards, for a payer.
+///
+/// Clones share their state, so every clone of a [`Client`](crate::Client) generates unique IDs together.
+///
+/// If the clock goes backwards past the last ID, IDs start over from the current time,
+/// rather than staying in the future (where nodes would reject them) until the clock catches up.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TransactionIdGenerator(Arc<Mutex<GeneratorState>>);
+
+#[derive(Debug, Default)]
+struct GeneratorState {
+    clock_skew: Duration,
+    last_valid_starts: HashMap<AccountId, OffsetDateTime>,
+    pruned_at: Option<OffsetDateTime>,
+}
+
+impl TransactionIdGenerator {
+    pub(crate) fn clock_skew(&self) -> Duration {
+        self.0.lock().clock_skew
+    }
+
+    pub(crate) fn set_clock_skew(&self, clock_skew: Duration) {
+        self.0.lock().clock_skew = clock_skew;
+    }
+
+    pub(crate) fn generate(&self, account_id: AccountId) -> TransactionId {
+        self.reserve(account_id, 1)
+    }
+
+    /// Generates the first of `count` transaction IDs a nanosecond apart, none of which will be generated again.
+    pub(crate) fn reserve(&self, account_id: AccountId, count: usize) -> TransactionId {
+        let mut id = TransactionId::generate(account_id);
+
+        let mut state = self.0.lock();
+
+        id.valid_start -= state.clock_skew;
+
+        let now = OffsetDateTime::now_utc() - state.clock_skew;
+
+        // a payer's next ID is always newer than an ID from longer ago than this, no need to remember it.
+        if state.pruned_at.is_none_or(|it| (now - it).abs() >= DEFAULT_TRANSACTION_VALID_DURATION) {
+            let cutoff = now - DEFAULT_TRANSACTION_VALID_DURATION;
+            state.last_valid_starts.retain(|_, last| *last >= cutoff);
+            state.pruned_at = Some(now);
+        }
+
+        // bump by a nanosecond when the clock hasn't moved past the last ID,
+        // unless that would put the ID in the future (the clock went backwards).
+        if let Some(last) = state.last_valid_starts.get(&account_id) {
+            let next = *last + Duration::NANOSECOND;
+            if next <= now {
+                id.valid_start = id.valid_start.max(next);
+            }
+        }
+
+        let last = id.valid_start + Duration::nanoseconds(count.saturating_sub(1) as i64);
+        state.last_valid_starts.insert(account_id, last);
+
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::TransactionIdGenerator;
+    use crate::AccountId;
+
+    #[test]
+    fn unique_and_monotonic() {
+        let generator = TransactionIdGenerator::default();
+        let payer = AccountId::new(0, 0, 1001);
+
+        let ids: Vec<_> = (0..10_000).map(|_| generator.generate(payer)).collect();
+
+        assert!(ids.windows(2).all(|it| it[0].valid_start < it[1].valid_start));
+        assert!(ids.iter().all(|it| it.account_id == payer));
+    }
+
+    #[test]
+    fn unique_across_threads() {
+        let generator = TransactionIdGenerator::default();
+        let payer = AccountId::new(0, 0, 1001);
+
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let generator = generator.clone();
+                std::thread::spawn(move || {
+                    (0..1000).map(|_| generator.generate(payer).valid_start).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let valid_starts: HashSet<_> =
+            threads.into_iter().flat_map(|it| it.join().unwrap()).collect();
+
+        assert_eq!(valid_starts.len(), 8 * 1000);
+    }
+
+    #[test]
+    fn payers_are_independent() {
+        let generator = TransactionIdGenerator::default();
+
+        let payer1 = AccountId::new(0, 0, 1001);
+        let payer2 = AccountId::new(0, 0, 1002);
+
+        generator
+            .0
+            .lock()
+            .last_valid_starts
+            .insert(payer1, OffsetDateTime::now_utc() - Duration::SECOND);
+
+        let cutoff = OffsetDateTime::now_utc() - Duration::seconds(2);
+
+        assert!(generator.generate(payer1).valid_start > cutoff);
+        assert!(generator.generate(payer2).valid_start < cutoff);
+    }
+
+    #[test]
+    fn clock_goes_backwards() {
+        let generator = TransactionIdGenerator::default();
+        let payer = AccountId::new(0, 0, 1001);
+
+        // as if the last ID was generated before the clock went back an hour.
+        generator
+            .0
+            .lock()
+            .last_valid_starts
+            .insert(payer, OffsetDateTime::now_utc() + Duration::HOUR);
+
+        let first = generator.generate(payer);
+        let second = generator.generate(payer);
+
+        assert!(first.valid_start < OffsetDateTime::now_utc());
+        assert!(first.valid_start < second.valid_start);
+    }
+
+    #[test]
+    fn forgets_old_payers() {
+        let generator = TransactionIdGenerator::default();
+
+        let payer1 = AccountId::new(0, 0, 1001);
+        let payer2 = AccountId::new(0, 0, 1002);
+
+        generator
+            .0
+            .lock()
+            .last_valid_starts
+            .insert(payer1, OffsetDateTime::now_utc() - Duration::HOUR);
+
+        generator.generate(payer2);
+
+        let state = generator.0.lock();
+        assert!(!state.last_valid_starts.contains_key(&payer1));
+        assert!(state.last_valid_starts.contains_key(&payer2));
+    }
+
+    #[test]
+    fn reserve() {
+        let generator = TransactionIdGenerator::default();
+        let payer = AccountId::new(0, 0, 1001);
+
+        let first = generator.reserve(payer, 5);
+        let next = generator.generate(payer);
+
+        assert!(next.valid_start > first.valid_start + Duration::nanoseconds(4));
+    }
+
+    #[test]
+    fn clock_skew() {
+        let generator = TransactionIdGenerator::default();
+        generator.set_clock_skew(Duration::minutes(1));
+
+        let id = generator.generate(AccountId::new(0, 0, 1001));
+
+        assert!(id.valid_start < OffsetDateTime::now_utc() - Duration::minutes(1));
+        assert!(id.valid_start > OffsetDateTime::now_utc() - Duration::minutes(2));
+    }
+}
@@ -0,0 +1,295 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// A Solidity ABI type, such as `uint256`, `bytes32[2]` or `(address,string)[]`.
+///
+/// `AbiType` parses from, and displays as, the canonical type string used in function selectors.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AbiType {
+    /// `address`
+    Address,
+
+    /// `bool`
+    Bool,
+
+    /// `intN`, where `N` is the size in bits: a multiple of 8 from 8 to 256.
+    Int(usize),
+
+    /// `uintN`, where `N` is the size in bits: a multiple of 8 from 8 to 256.
+    Uint(usize),
+
+    /// `bytesN`, where `N` is the size in bytes: from 1 to 32.
+    FixedBytes(usize),
+
+    /// `bytes`
+    Bytes,
+
+    /// `string`
+    String,
+
+    /// `function`, an address followed by a function selector.
+    Function,
+
+    /// `T[]`
+    Array(Box<AbiType>),
+
+    /// `T[k]`
+    FixedArray(Box<AbiType>, usize),
+
+    /// `(T1,T2,...)`, which is how a Solidity `struct` is encoded.
+    Tuple(Vec<AbiType>),
+}
+
+impl AbiType {
+    /// Returns `true` if values of this type are encoded out of line, after the head.
+    #[must_use]
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(ty, _) => ty.is_dynamic(),
+            Self::Tuple(tys) => tys.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// Returns the number of bytes this type takes up in the head of an encoding.
+    ///
+    /// Returns `None` if this type, or any type nested in it, is too large to ever be encoded.
+    pub(crate) fn head_len(&self) -> Option<usize> {
+        let len = match self {
+            Self::Array(ty) => ty.head_len().map(|_| 32)?,
+            Self::FixedArray(ty, len) => ty.head_len()?.checked_mul(*len)?,
+            Self::Tuple(tys) => {
+                tys.iter().try_fold(0_usize, |acc, ty| acc.checked_add(ty.head_len()?))?
+            }
+            _ => 32,
+        };
+
+        Some(if self.is_dynamic() { 32 } else { len })
+    }
+}
+
+impl fmt::Display for AbiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address => f.write_str("address"),
+            Self::Bool => f.write_str("bool"),
+            Self::Int(bits) => write!(f, "int{bits}"),
+            Self::Uint(bits) => write!(f, "uint{bits}"),
+            Self::FixedBytes(len) => write!(f, "bytes{len}"),
+            Self::Bytes => f.write_str("bytes"),
+            Self::String => f.write_str("string"),
+            Self::Function => f.write_str("function"),
+            Self::Array(ty) => write!(f, "{ty}[]"),
+            Self::FixedArray(ty, len) => write!(f, "{ty}[{len}]"),
+            Self::Tuple(tys) => {
+                f.write_str("(")?;
+                for (i, ty) in tys.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{ty}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl FromStr for AbiType {
+    type Err = Error;
+
+    /// Parses a Solidity type string.
+    ///
+    /// Besides the canonical form this accepts the `uint` and `int` aliases,
+    /// and tuples written as `tuple(...)`.
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let (ty, rest) = parse_type(s.trim())?;
+
+        if !rest.is_empty() {
+            return Err(Error::basic_parse(format!("unexpected `{rest}` after ABI type `{ty}`")));
+        }
+
+        if ty.head_len().is_none() {
+            return Err(Error::basic_parse(format!("ABI type `{ty}` is too large")));
+        }
+
+        Ok(ty)
+    }
+}
+
+/// Parses one type from the start of `s`, returning it and whatever follows it.
+fn parse_type(s: &str) -> crate::Result<(AbiType, &str)> {
+    let (mut ty, mut rest) = if let Some(tuple) = s.strip_prefix("tuple(") {
+        parse_tuple(tuple)?
+    } else if let Some(tuple) = s.strip_prefix('(') {
+        parse_tuple(tuple)?
+    } else {
+        let end = s.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(s.len());
+        (parse_elementary(&s[..end])?, &s[end..])
+    };
+
+    while let Some(suffix) = rest.strip_prefix('[') {
+        let end = suffix
+            .find(']')
+            .ok_or_else(|| Error::basic_parse(format!("unclosed `[` in ABI type `{s}`")))?;
+
+        ty = match &suffix[..end] {
+            "" => AbiType::Array(Box::new(ty)),
+            len => {
+                let len = len.parse().map_err(Error::basic_parse)?;
+                AbiType::FixedArray(Box::new(ty), len)
+            }
+        };
+
+        rest = &suffix[end + 1..];
+    }
+
+    Ok((ty, rest))
+}
+
+/// Parses the components of a tuple, `s` starts just after the opening parenthesis.
+fn parse_tuple(mut s: &str) -> crate::Result<(AbiType, &str)> {
+    let mut tys = Vec::new();
+
+    if let Some(rest) = s.strip_prefix(')') {
+        return Ok((AbiType::Tuple(tys), rest));
+    }
+
+    loop {
+        let (ty, rest) = parse_type(s.trim_start())?;
+        tys.push(ty);
+
+        let rest = rest.trim_start();
+        if let Some(rest) = rest.strip_prefix(',') {
+            s = rest;
+        } else if let Some(rest) = rest.strip_prefix(')') {
+            return Ok((AbiType::Tuple(tys), rest));
+        } else {
+            return Err(Error::basic_parse(format!(
+                "expected `,` or `)` in ABI tuple, found `{rest}`"
+            )));
+        }
+    }
+}
+
+fn parse_elementary(s: &str) -> crate::Result<AbiType> {
+    let ty = match s {
+        "address" => AbiType::Address,
+        "bool" => AbiType::Bool,
+        "bytes" => AbiType::Bytes,
+        "string" => AbiType::String,
+        "function" => AbiType::Function,
+        "int" => AbiType::Int(256),
+        "uint" => AbiType::Uint(256),
+        _ => {
+            if let Some(bits) = s.strip_prefix("uint").and_then(|it| it.parse().ok()) {
+                AbiType::Uint(check_bits(s, bits)?)
+            } else if let Some(bits) = s.strip_prefix("int").and_then(|it| it.parse().ok()) {
+                AbiType::Int(check_bits(s, bits)?)
+            } else if let Some(len) = s.strip_prefix("bytes").and_then(|it| it.parse().ok()) {
+                if !(1..=32).contains(&len) {
+                    return Err(Error::basic_parse(format!("invalid ABI type `{s}`")));
+                }
+
+                AbiType::FixedBytes(len)
+            } else {
+                return Err(Error::basic_parse(format!("unknown ABI type `{s}`")));
+            }
+        }
+    };
+
+    Ok(ty)
+}
+
+fn check_bits(s: &str, bits: usize) -> crate::Result<usize> {
+    if bits == 0 || bits > 256 || bits % 8 != 0 {
+        return Err(Error::basic_parse(format!("invalid ABI type `{s}`")));
+    }
+
+    Ok(bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::AbiType;
+
+    #[test]
+    fn parse_elementary() {
+        assert_eq!(AbiType::from_str("uint").unwrap(), AbiType::Uint(256));
+        assert_eq!(AbiType::from_str("int64").unwrap(), AbiType::Int(64));
+        assert_eq!(AbiType::from_str("bytes32").unwrap(), AbiType::FixedBytes(32));
+        assert_eq!(AbiType::from_str("address").unwrap(), AbiType::Address);
+    }
+
+    #[test]
+    fn parse_nested() {
+        let ty = AbiType::from_str("tuple(uint256, (address,string)[2])[]").unwrap();
+
+        assert_eq!(
+            ty,
+            AbiType::Array(Box::new(AbiType::Tuple(vec![
+                AbiType::Uint(256),
+                AbiType::FixedArray(
+                    Box::new(AbiType::Tuple(vec![AbiType::Address, AbiType::String])),
+                    2
+                ),
+            ])))
+        );
+
+        assert_eq!(ty.to_string(), "(uint256,(address,string)[2])[]");
+        assert!(ty.is_dynamic());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in ["uint7", "uint264", "bytes0", "bytes33", "foo", "(uint8", "uint8[", "uint8 x"] {
+            assert!(AbiType::from_str(s).is_err(), "`{s}` should fail to parse");
+        }
+    }
+
+    #[test]
+    fn parse_too_large() {
+        for s in [
+            "uint256[99999999999999999999]",
+            "uint256[9999999999999999999]",
+            "uint256[9999999999999999999][]",
+            "(uint256[288230376151711744],uint256[288230376151711744],string)",
+        ] {
+            assert!(AbiType::from_str(s).is_err(), "`{s}` should fail to parse");
+        }
+    }
+
+    #[test]
+    fn head_len() {
+        let ty = AbiType::from_str("(uint8,bytes32[3])").unwrap();
+        assert!(!ty.is_dynamic());
+        assert_eq!(ty.head_len(), Some(4 * 32));
+
+        assert_eq!(AbiType::from_str("(uint8,string)").unwrap().head_len(), Some(32));
+    }
+}
@@ -0,0 +1,968 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::{
+    fmt,
+    iter,
+};
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+    Sign,
+};
+
+use crate::contract::AbiType;
+use crate::{
+    Error,
+    EvmAddress,
+};
+
+const WORD_LEN: usize = 32;
+
+/// A value that can be encoded with, or decoded from, the Solidity ABI.
+///
+/// Every value knows its own [`AbiType`], so arrays carry their element type,
+/// which is what lets empty arrays be encoded and named in a function selector.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiValue {
+    /// An `address`.
+    Address(EvmAddress),
+
+    /// A `bool`.
+    Bool(bool),
+
+    /// An `intN`, with the size in bits followed by the value.
+    Int(usize, BigInt),
+
+    /// A `uintN`, with the size in bits followed by the value.
+    Uint(usize, BigUint),
+
+    /// A `bytesN`, where `N` is the length of the bytes.
+    FixedBytes(Vec<u8>),
+
+    /// A `bytes`.
+    Bytes(Vec<u8>),
+
+    /// A `string`.
+    String(String),
+
+    /// A `function`, which is the address of a contract and a function selector.
+    Function(EvmAddress, [u8; 4]),
+
+    /// A `T[]`, with the element type followed by the elements.
+    Array(AbiType, Vec<AbiValue>),
+
+    /// A `T[k]`, with the element type followed by the elements.
+    FixedArray(AbiType, Vec<AbiValue>),
+
+    /// A tuple, which is how a Solidity `struct` is encoded.
+    Tuple(Vec<AbiValue>),
+}
+
+impl AbiValue {
+    /// Returns the type of this value.
+    #[must_use]
+    pub fn abi_type(&self) -> AbiType {
+        match self {
+            Self::Address(_) => AbiType::Address,
+            Self::Bool(_) => AbiType::Bool,
+            Self::Int(bits, _) => AbiType::Int(*bits),
+            Self::Uint(bits, _) => AbiType::Uint(*bits),
+            Self::FixedBytes(bytes) => AbiType::FixedBytes(bytes.len()),
+            Self::Bytes(_) => AbiType::Bytes,
+            Self::String(_) => AbiType::String,
+            Self::Function(..) => AbiType::Function,
+            Self::Array(ty, _) => AbiType::Array(Box::new(ty.clone())),
+            Self::FixedArray(ty, values) => AbiType::FixedArray(Box::new(ty.clone()), values.len()),
+            Self::Tuple(values) => AbiType::Tuple(values.iter().map(Self::abi_type).collect()),
+        }
+    }
+
+    /// Returns the value as an `address`, if it is one.
+    #[must_use]
+    pub fn as_address(&self) -> Option<EvmAddress> {
+        match self {
+            Self::Address(address) => Some(*address),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `bool`, if it is one.
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a signed integer, if it is an `intN`.
+    #[must_use]
+    pub fn as_int(&self) -> Option<&BigInt> {
+        match self {
+            Self::Int(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as an unsigned integer, if it is a `uintN`.
+    #[must_use]
+    pub fn as_uint(&self) -> Option<&BigUint> {
+        match self {
+            Self::Uint(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as bytes, if it is a `bytes` or a `bytesN`.
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(bytes) | Self::FixedBytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a `string`, if it is one.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of an array, or the components of a tuple.
+    #[must_use]
+    pub fn as_slice(&self) -> Option<&[AbiValue]> {
+        match self {
+            Self::Array(_, values) | Self::FixedArray(_, values) | Self::Tuple(values) => {
+                Some(values)
+            }
+            _ => None,
+        }
+    }
+
+    /// Encodes `values` as the parameters of a function call, without a function selector.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if a value doesn't fit its type, for instance an array element of the wrong type.
+    pub fn encode_params(values: &[Self]) -> crate::Result<Vec<u8>> {
+        let mut out = Vec::new();
+        encode_sequence(values, &mut out)?;
+        Ok(out)
+    }
+
+    /// Decodes `bytes` as a sequence of values of `types`, such as the return values of a function.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `bytes` isn't a valid encoding of `types`.
+    pub fn decode_params(types: &[AbiType], bytes: &[u8]) -> crate::Result<Vec<Self>> {
+        decode_sequence(types.iter(), bytes).map(|(values, _)| values)
+    }
+
+    /// Converts this value to a value of type `ty`, for instance a `uint256` to a `uint8`,
+    /// or a `0x` prefixed hex `string` to an `address`.
+    ///
+    /// This lets loosely typed values, such as ones built with the `From` impls, be used for parameters of a specific type.
+    /// Whether an integer is in range for its new size is checked when it's encoded.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if this value can't be converted to `ty`.
+    /// - [`Error::BasicParse`] if a `string` converted to an `address` isn't a valid EVM address.
+    pub fn coerce_to(&self, ty: &AbiType) -> crate::Result<Self> {
+        let value = match (self, ty) {
+            (Self::Int(_, value), AbiType::Int(bits)) => Self::Int(*bits, value.clone()),
+            (Self::Uint(_, value), AbiType::Int(bits)) => {
+                Self::Int(*bits, BigInt::from(value.clone()))
+            }
+            (Self::Uint(_, value), AbiType::Uint(bits)) => Self::Uint(*bits, value.clone()),
+            (Self::Int(_, value), AbiType::Uint(bits)) => Self::Uint(
+                *bits,
+                value
+                    .to_biguint()
+                    .ok_or_else(|| Error::abi(format!("{value} doesn't fit in a `{ty}`")))?,
+            ),
+            (Self::String(address), AbiType::Address) => Self::Address(address.parse()?),
+            (Self::Bytes(bytes) | Self::FixedBytes(bytes), AbiType::FixedBytes(len))
+                if bytes.len() == *len =>
+            {
+                Self::FixedBytes(bytes.clone())
+            }
+            (Self::Bytes(bytes) | Self::FixedBytes(bytes), AbiType::Bytes) => {
+                Self::Bytes(bytes.clone())
+            }
+            (Self::Array(_, values) | Self::FixedArray(_, values), AbiType::Array(element_ty)) => {
+                Self::Array(
+                    (**element_ty).clone(),
+                    values
+                        .iter()
+                        .map(|value| value.coerce_to(element_ty))
+                        .collect::<crate::Result<_>>()?,
+                )
+            }
+            (
+                Self::Array(_, values) | Self::FixedArray(_, values),
+                AbiType::FixedArray(element_ty, len),
+            ) if values.len() == *len => Self::FixedArray(
+                (**element_ty).clone(),
+                values
+                    .iter()
+                    .map(|value| value.coerce_to(element_ty))
+                    .collect::<crate::Result<_>>()?,
+            ),
+            (Self::Tuple(values), AbiType::Tuple(tys)) if values.len() == tys.len() => Self::Tuple(
+                values
+                    .iter()
+                    .zip(tys)
+                    .map(|(value, ty)| value.coerce_to(ty))
+                    .collect::<crate::Result<_>>()?,
+            ),
+            (value, _) if value.abi_type() == *ty => value.clone(),
+            (value, _) => {
+                return Err(Error::abi(format!(
+                    "a `{}` can't be used as a `{ty}`",
+                    value.abi_type()
+                )))
+            }
+        };
+
+        Ok(value)
+    }
+
+    /// Encodes this value on its own.
+    ///
+    /// For a dynamic value this is what goes in the tail, for a static value it's what goes in the head.
+    pub(crate) fn encode(&self) -> crate::Result<Vec<u8>> {
+        let mut out = Vec::new();
+        self.encode_into(&mut out)?;
+        Ok(out)
+    }
+
+    /// Decodes a value of type `ty` whose head starts at `offset` into `bytes`.
+    pub(crate) fn decode_head(ty: &AbiType, bytes: &[u8], offset: usize) -> crate::Result<Self> {
+        if ty.is_dynamic() {
+            let tail = read_usize(bytes, offset)?;
+            decode_value(ty, bytes.get(tail..).ok_or_else(|| out_of_bounds(ty))?)
+        } else {
+            decode_value(ty, bytes.get(offset..).ok_or_else(|| out_of_bounds(ty))?)
+        }
+        .map(|(value, _)| value)
+    }
+
+    fn encode_into(&self, out: &mut Vec<u8>) -> crate::Result<()> {
+        match self {
+            Self::Address(address) => {
+                out.extend_from_slice(&[0; 12]);
+                out.extend_from_slice(&address.to_bytes());
+            }
+
+            Self::Bool(value) => out.extend_from_slice(&encode_usize(usize::from(*value))),
+
+            Self::Int(bits, value) => {
+                check_bits(&self.abi_type(), *bits)?;
+
+                let half = BigInt::from(1) << (bits - 1);
+                if *value >= half || *value < -half {
+                    return Err(Error::abi(format!("{value} doesn't fit in an `int{bits}`")));
+                }
+
+                let pad = if value.sign() == Sign::Minus { 0xff } else { 0x00 };
+                let bytes = value.to_signed_bytes_be();
+                out.extend(iter::repeat_n(pad, WORD_LEN - bytes.len()));
+                out.extend_from_slice(&bytes);
+            }
+
+            Self::Uint(bits, value) => {
+                check_bits(&self.abi_type(), *bits)?;
+
+                if value.bits() > *bits as u64 {
+                    return Err(Error::abi(format!("{value} doesn't fit in a `uint{bits}`")));
+                }
+
+                let bytes = value.to_bytes_be();
+                out.extend(iter::repeat_n(0, WORD_LEN - bytes.len()));
+                out.extend_from_slice(&bytes);
+            }
+
+            Self::FixedBytes(bytes) => {
+                if !(1..=WORD_LEN).contains(&bytes.len()) {
+                    return Err(Error::abi(format!(
+                        "a `bytesN` must be 1 to 32 bytes long, found {} bytes",
+                        bytes.len()
+                    )));
+                }
+
+                encode_padded(bytes, out);
+            }
+
+            Self::Bytes(bytes) => {
+                out.extend_from_slice(&encode_usize(bytes.len()));
+                encode_padded(bytes, out);
+            }
+
+            Self::String(value) => {
+                out.extend_from_slice(&encode_usize(value.len()));
+                encode_padded(value.as_bytes(), out);
+            }
+
+            Self::Function(address, selector) => {
+                out.extend_from_slice(&address.to_bytes());
+                encode_padded(selector, out);
+            }
+
+            Self::Array(ty, values) => {
+                check_elements(ty, values)?;
+                out.extend_from_slice(&encode_usize(values.len()));
+                encode_sequence(values, out)?;
+            }
+
+            Self::FixedArray(ty, values) => {
+                check_elements(ty, values)?;
+                encode_sequence(values, out)?;
+            }
+
+            Self::Tuple(values) => encode_sequence(values, out)?,
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for AbiValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address(address) => write!(f, "{address}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(_, value) => write!(f, "{value}"),
+            Self::Uint(_, value) => write!(f, "{value}"),
+            Self::FixedBytes(bytes) | Self::Bytes(bytes) => write!(f, "0x{}", hex::encode(bytes)),
+            Self::String(value) => write!(f, "{value:?}"),
+            Self::Function(address, selector) => write!(f, "{address}:0x{}", hex::encode(selector)),
+            Self::Array(_, values) | Self::FixedArray(_, values) => write_list(f, values, "[", "]"),
+            Self::Tuple(values) => write_list(f, values, "(", ")"),
+        }
+    }
+}
+
+fn write_list(
+    f: &mut fmt::Formatter<'_>,
+    values: &[AbiValue],
+    open: &str,
+    close: &str,
+) -> fmt::Result {
+    f.write_str(open)?;
+    for (i, value) in values.iter().enumerate() {
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+
+        write!(f, "{value}")?;
+    }
+    f.write_str(close)
+}
+
+impl From<bool> for AbiValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<EvmAddress> for AbiValue {
+    fn from(value: EvmAddress) -> Self {
+        Self::Address(value)
+    }
+}
+
+impl From<String> for AbiValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for AbiValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<Vec<u8>> for AbiValue {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<&[u8]> for AbiValue {
+    fn from(value: &[u8]) -> Self {
+        Self::Bytes(value.to_vec())
+    }
+}
+
+impl From<BigUint> for AbiValue {
+    fn from(value: BigUint) -> Self {
+        Self::Uint(256, value)
+    }
+}
+
+impl From<BigInt> for AbiValue {
+    fn from(value: BigInt) -> Self {
+        Self::Int(256, value)
+    }
+}
+
+// integers become their 256 bit versions, `coerce_to` takes care of narrowing them.
+macro_rules! impl_from_int {
+    ($variant:ident, $big:ident, $($ty:ty),*) => {
+        $(
+            impl From<$ty> for AbiValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(256, $big::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Uint, BigUint, u8, u16, u32, u64, u128);
+impl_from_int!(Int, BigInt, i8, i16, i32, i64, i128);
+
+fn check_bits(ty: &AbiType, bits: usize) -> crate::Result<()> {
+    if bits == 0 || bits > 256 || bits % 8 != 0 {
+        return Err(Error::abi(format!("`{ty}` is not a valid ABI type")));
+    }
+
+    Ok(())
+}
+
+fn head_len(ty: &AbiType) -> crate::Result<usize> {
+    ty.head_len().ok_or_else(|| Error::abi(format!("`{ty}` is too large to encode")))
+}
+
+fn check_elements(ty: &AbiType, values: &[AbiValue]) -> crate::Result<()> {
+    for value in values {
+        let value_ty = value.abi_type();
+        if value_ty != *ty {
+            return Err(Error::abi(format!(
+                "array of `{ty}` can't contain an element of type `{value_ty}`"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn encode_usize(value: usize) -> [u8; WORD_LEN] {
+    let mut word = [0; WORD_LEN];
+    word[(WORD_LEN - 8)..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+/// Appends `bytes`, followed by zeros up to the next multiple of 32 bytes.
+fn encode_padded(bytes: &[u8], out: &mut Vec<u8>) {
+    out.extend_from_slice(bytes);
+    out.extend(iter::repeat_n(0, (WORD_LEN - bytes.len() % WORD_LEN) % WORD_LEN));
+}
+
+/// Encodes `values` as a tuple: every head, followed by the tails of the dynamic values.
+fn encode_sequence(values: &[AbiValue], out: &mut Vec<u8>) -> crate::Result<()> {
+    let types: Vec<_> = values.iter().map(AbiValue::abi_type).collect();
+    let head_len = types.iter().try_fold(0_usize, |acc, ty| {
+        acc.checked_add(head_len(ty)?).ok_or_else(|| Error::abi("values are too large to encode"))
+    })?;
+
+    let mut head = Vec::with_capacity(head_len);
+    let mut tail = Vec::new();
+
+    for (value, ty) in values.iter().zip(&types) {
+        if ty.is_dynamic() {
+            head.extend_from_slice(&encode_usize(head_len + tail.len()));
+            value.encode_into(&mut tail)?;
+        } else {
+            value.encode_into(&mut head)?;
+        }
+    }
+
+    out.append(&mut head);
+    out.append(&mut tail);
+
+    Ok(())
+}
+
+fn out_of_bounds(ty: &AbiType) -> Error {
+    Error::abi(format!("ran out of bytes decoding a `{ty}`"))
+}
+
+fn word_is_zero(bytes: &[u8]) -> bool {
+    bytes.iter().all(|it| *it == 0)
+}
+
+fn read_word(bytes: &[u8], offset: usize) -> crate::Result<&[u8; WORD_LEN]> {
+    bytes
+        .get(offset..)
+        .and_then(|it| it.get(..WORD_LEN))
+        .map(|it| it.try_into().unwrap())
+        .ok_or_else(|| Error::abi("ran out of bytes reading a 32 byte word"))
+}
+
+fn read_usize(bytes: &[u8], offset: usize) -> crate::Result<usize> {
+    let word = read_word(bytes, offset)?;
+
+    if !word_is_zero(&word[..(WORD_LEN - 8)]) {
+        return Err(Error::abi("offset or length doesn't fit in a `u64`"));
+    }
+
+    usize::try_from(u64::from_be_bytes(word[(WORD_LEN - 8)..].try_into().unwrap()))
+        .map_err(Error::abi)
+}
+
+/// Decodes a sequence of values of `types`, returning them and how many bytes of `bytes` they took up.
+fn decode_sequence<'a, I>(types: I, bytes: &[u8]) -> crate::Result<(Vec<AbiValue>, usize)>
+where
+    I: IntoIterator<Item = &'a AbiType>,
+    I::IntoIter: Clone,
+{
+    let types = types.into_iter();
+
+    // every tail has to come after the heads, and after the tail before it.
+    // otherwise many values could share one large tail, and a small input would decode to many times its size.
+    let mut end = 0_usize;
+    for ty in types.clone() {
+        end = end.checked_add(head_len(ty)?).ok_or_else(|| out_of_bounds(ty))?;
+    }
+
+    let mut offset = 0;
+    let mut values = Vec::new();
+
+    for ty in types {
+        let value = if ty.is_dynamic() {
+            let tail = read_usize(bytes, offset)?;
+            if tail < end {
+                return Err(Error::abi(format!("`{ty}` overlaps the value before it")));
+            }
+
+            let (value, len) =
+                decode_value(ty, bytes.get(tail..).ok_or_else(|| out_of_bounds(ty))?)?;
+            end = tail + len;
+            value
+        } else {
+            AbiValue::decode_head(ty, bytes, offset)?
+        };
+
+        values.push(value);
+        offset = offset.checked_add(head_len(ty)?).ok_or_else(|| out_of_bounds(ty))?;
+    }
+
+    Ok((values, end))
+}
+
+/// Decodes a value of type `ty` from the start of `bytes`, returning it and how many bytes it took up.
+fn decode_value(ty: &AbiType, bytes: &[u8]) -> crate::Result<(AbiValue, usize)> {
+    let value = match ty {
+        AbiType::Address => {
+            let word = read_word(bytes, 0)?;
+            if !word_is_zero(&word[..12]) {
+                return Err(Error::abi("`address` has non-zero padding"));
+            }
+
+            AbiValue::Address(EvmAddress(word[12..].try_into().unwrap()))
+        }
+
+        AbiType::Bool => {
+            let word = read_word(bytes, 0)?;
+            if !word_is_zero(&word[..31]) || word[31] > 1 {
+                return Err(Error::abi("`bool` is neither 0 nor 1"));
+            }
+
+            AbiValue::Bool(word[31] == 1)
+        }
+
+        AbiType::Int(bits) => {
+            check_bits(ty, *bits)?;
+
+            let value = BigInt::from_signed_bytes_be(read_word(bytes, 0)?);
+            let half = BigInt::from(1) << (bits - 1);
+            if value >= half || value < -half {
+                return Err(Error::abi(format!("{value} doesn't fit in an `int{bits}`")));
+            }
+
+            AbiValue::Int(*bits, value)
+        }
+
+        AbiType::Uint(bits) => {
+            check_bits(ty, *bits)?;
+
+            let value = BigUint::from_bytes_be(read_word(bytes, 0)?);
+            if value.bits() > *bits as u64 {
+                return Err(Error::abi(format!("{value} doesn't fit in a `uint{bits}`")));
+            }
+
+            AbiValue::Uint(*bits, value)
+        }
+
+        AbiType::FixedBytes(len) => {
+            if !(1..=WORD_LEN).contains(len) {
+                return Err(Error::abi(format!("`{ty}` is not a valid ABI type")));
+            }
+
+            AbiValue::FixedBytes(read_word(bytes, 0)?[..*len].to_vec())
+        }
+
+        AbiType::Bytes => {
+            let data = read_dynamic_bytes(ty, bytes)?;
+            return Ok((AbiValue::Bytes(data.to_vec()), WORD_LEN + data.len()));
+        }
+
+        // consistent with `ContractFunctionResult::get_str`, this is a lossy conversion.
+        AbiType::String => {
+            let data = read_dynamic_bytes(ty, bytes)?;
+            return Ok((
+                AbiValue::String(String::from_utf8_lossy(data).into_owned()),
+                WORD_LEN + data.len(),
+            ));
+        }
+
+        AbiType::Function => {
+            let word = read_word(bytes, 0)?;
+            AbiValue::Function(
+                EvmAddress(word[..20].try_into().unwrap()),
+                word[20..24].try_into().unwrap(),
+            )
+        }
+
+        AbiType::Array(element_ty) => {
+            let len = read_usize(bytes, 0)?;
+            let elements = &bytes[WORD_LEN..];
+
+            // don't let a bogus length make us allocate (or loop) more than the input could hold.
+            if len.checked_mul(head_len(element_ty)?.max(1)).is_none_or(|it| it > elements.len()) {
+                return Err(out_of_bounds(ty));
+            }
+
+            let (values, len) = decode_sequence(iter::repeat_n(&**element_ty, len), elements)?;
+            return Ok((AbiValue::Array((**element_ty).clone(), values), WORD_LEN + len));
+        }
+
+        AbiType::FixedArray(element_ty, len) => {
+            // same as for arrays, the type might have been built by hand rather than parsed.
+            if len.checked_mul(head_len(element_ty)?.max(1)).is_none_or(|it| it > bytes.len()) {
+                return Err(out_of_bounds(ty));
+            }
+
+            let (values, len) = decode_sequence(iter::repeat_n(&**element_ty, *len), bytes)?;
+            return Ok((AbiValue::FixedArray((**element_ty).clone(), values), len));
+        }
+
+        AbiType::Tuple(tys) => {
+            let (values, len) = decode_sequence(tys, bytes)?;
+            return Ok((AbiValue::Tuple(values), len));
+        }
+    };
+
+    // everything else is a single word.
+    Ok((value, WORD_LEN))
+}
+
+fn read_dynamic_bytes<'a>(ty: &AbiType, bytes: &'a [u8]) -> crate::Result<&'a [u8]> {
+    let len = read_usize(bytes, 0)?;
+
+    bytes.get(WORD_LEN..).and_then(|it| it.get(..len)).ok_or_else(|| out_of_bounds(ty))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use hex_literal::hex;
+    use num_bigint::{
+        BigInt,
+        BigUint,
+    };
+
+    use super::AbiValue;
+    use crate::contract::AbiType;
+    use crate::EvmAddress;
+
+    fn uint(value: u64) -> AbiValue {
+        AbiValue::Uint(256, BigUint::from(value))
+    }
+
+    #[test]
+    fn encode_static_tuple() {
+        let address = EvmAddress::from(hex!("00000000000000000000000000000000000004d2"));
+        let values = [AbiValue::Tuple(vec![uint(1), AbiValue::Address(address)])];
+
+        let bytes = AbiValue::encode_params(&values).unwrap();
+
+        assert_eq!(
+            hex::encode(&bytes),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "00000000000000000000000000000000000000000000000000000000000004d2",
+            )
+        );
+
+        let ty = AbiType::from_str("(uint256,address)").unwrap();
+        assert_eq!(AbiValue::decode_params(&[ty], &bytes).unwrap(), values);
+    }
+
+    #[test]
+    fn encode_dynamic_tuple() {
+        // the canonical example from the Solidity ABI spec: `f(uint256,uint32[],bytes10,bytes)`
+        let values = [
+            uint(0x123),
+            AbiValue::Array(
+                AbiType::Uint(32),
+                vec![
+                    AbiValue::Uint(32, BigUint::from(0x456_u32)),
+                    AbiValue::Uint(32, BigUint::from(0x789_u32)),
+                ],
+            ),
+            AbiValue::FixedBytes(b"1234567890".to_vec()),
+            AbiValue::Bytes(b"Hello, world!".to_vec()),
+        ];
+
+        let bytes = AbiValue::encode_params(&values).unwrap();
+
+        assert_eq!(
+            hex::encode(&bytes),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000123",
+                "0000000000000000000000000000000000000000000000000000000000000080",
+                "3132333435363738393000000000000000000000000000000000000000000000",
+                "00000000000000000000000000000000000000000000000000000000000000e0",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000456",
+                "0000000000000000000000000000000000000000000000000000000000000789",
+                "000000000000000000000000000000000000000000000000000000000000000d",
+                "48656c6c6f2c20776f726c642100000000000000000000000000000000000000",
+            )
+        );
+
+        let types: Vec<_> = values.iter().map(AbiValue::abi_type).collect();
+        assert_eq!(AbiValue::decode_params(&types, &bytes).unwrap(), values);
+    }
+
+    #[test]
+    fn encode_nested_arrays() {
+        // also from the Solidity ABI spec: `g(uint256[][],string[])`
+        let values = [
+            AbiValue::Array(
+                AbiType::Array(Box::new(AbiType::Uint(256))),
+                vec![
+                    AbiValue::Array(AbiType::Uint(256), vec![uint(1), uint(2)]),
+                    AbiValue::Array(AbiType::Uint(256), vec![uint(3)]),
+                ],
+            ),
+            AbiValue::Array(
+                AbiType::String,
+                vec![
+                    AbiValue::String("one".to_owned()),
+                    AbiValue::String("two".to_owned()),
+                    AbiValue::String("three".to_owned()),
+                ],
+            ),
+        ];
+
+        let bytes = AbiValue::encode_params(&values).unwrap();
+
+        assert_eq!(
+            hex::encode(&bytes),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000040",
+                "0000000000000000000000000000000000000000000000000000000000000140",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000040",
+                "00000000000000000000000000000000000000000000000000000000000000a0",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "0000000000000000000000000000000000000000000000000000000000000060",
+                "00000000000000000000000000000000000000000000000000000000000000a0",
+                "00000000000000000000000000000000000000000000000000000000000000e0",
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "6f6e650000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "74776f0000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000005",
+                "7468726565000000000000000000000000000000000000000000000000000000",
+            )
+        );
+
+        let types: Vec<_> = values.iter().map(AbiValue::abi_type).collect();
+        assert_eq!(AbiValue::decode_params(&types, &bytes).unwrap(), values);
+    }
+
+    #[test]
+    fn encode_array_of_tuples() {
+        let tuple = |amount: u64, memo: &str| {
+            AbiValue::Tuple(vec![uint(amount), AbiValue::String(memo.to_owned())])
+        };
+
+        let ty = AbiType::from_str("(uint256,string)").unwrap();
+        let values = [
+            AbiValue::Array(ty.clone(), vec![tuple(1, "a"), tuple(2, "b")]),
+            AbiValue::FixedArray(AbiType::Int(8), vec![AbiValue::Int(8, BigInt::from(-1))]),
+        ];
+
+        let bytes = AbiValue::encode_params(&values).unwrap();
+
+        assert_eq!(
+            hex::encode(&bytes),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000040",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000040",
+                "00000000000000000000000000000000000000000000000000000000000000c0",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "0000000000000000000000000000000000000000000000000000000000000040",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "6100000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "0000000000000000000000000000000000000000000000000000000000000040",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "6200000000000000000000000000000000000000000000000000000000000000",
+            )
+        );
+
+        let types: Vec<_> = values.iter().map(AbiValue::abi_type).collect();
+        assert_eq!(AbiValue::decode_params(&types, &bytes).unwrap(), values);
+        assert_eq!(types[0].to_string(), "(uint256,string)[]");
+    }
+
+    #[test]
+    fn encode_empty_array() {
+        let values = [AbiValue::Array(AbiType::Address, Vec::new())];
+
+        assert_eq!(values[0].abi_type().to_string(), "address[]");
+        assert_eq!(
+            hex::encode(AbiValue::encode_params(&values).unwrap()),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000020",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            )
+        );
+    }
+
+    #[test]
+    fn encode_mismatched_element_fails() {
+        let values = [AbiValue::Array(AbiType::Uint(8), vec![uint(1)])];
+        assert!(AbiValue::encode_params(&values).is_err());
+
+        assert!(AbiValue::encode_params(&[AbiValue::Uint(8, BigUint::from(256_u32))]).is_err());
+        assert!(AbiValue::encode_params(&[AbiValue::Int(8, BigInt::from(-129))]).is_err());
+    }
+
+    #[test]
+    fn display() {
+        let value = AbiValue::Tuple(vec![
+            AbiValue::from(-1_i8),
+            AbiValue::from("hi"),
+            AbiValue::Array(AbiType::Bool, vec![AbiValue::from(true), AbiValue::from(false)]),
+            AbiValue::FixedBytes(vec![0xab, 0xcd]),
+        ]);
+
+        assert_eq!(value.to_string(), r#"(-1, "hi", [true, false], 0xabcd)"#);
+    }
+
+    #[test]
+    fn coerce() {
+        let ty = AbiType::from_str("(uint8,int64,address,bytes4[])").unwrap();
+        let value = AbiValue::Tuple(vec![
+            AbiValue::from(200_u64),
+            AbiValue::from(-5_i32),
+            AbiValue::from("0x00000000000000000000000000000000000004d2"),
+            AbiValue::Array(AbiType::Bytes, vec![AbiValue::from(b"abcd".as_slice())]),
+        ]);
+
+        let coerced = value.coerce_to(&ty).unwrap();
+
+        assert_eq!(coerced.abi_type(), ty);
+        assert_eq!(
+            coerced.as_slice().unwrap()[2].as_address().unwrap(),
+            EvmAddress::from(hex!("00000000000000000000000000000000000004d2"))
+        );
+
+        assert!(AbiValue::from(-1_i8).coerce_to(&AbiType::Uint(8)).is_err());
+        assert!(AbiValue::from(true).coerce_to(&AbiType::String).is_err());
+        assert!(AbiValue::from(b"abc".as_slice()).coerce_to(&AbiType::FixedBytes(4)).is_err());
+
+        // range is only checked once the value is encoded.
+        let too_big = AbiValue::from(256_u32).coerce_to(&AbiType::Uint(8)).unwrap();
+        assert!(AbiValue::encode_params(&[too_big]).is_err());
+    }
+
+    #[test]
+    fn decode_truncated_fails() {
+        let ty = AbiType::from_str("uint256[]").unwrap();
+
+        // claims to hold `u64::MAX` elements.
+        let bytes = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "000000000000000000000000000000000000000000000000ffffffffffffffff"
+        );
+
+        assert!(AbiValue::decode_params(&[ty], &bytes).is_err());
+        assert!(AbiValue::decode_params(&[AbiType::String], &bytes[..32]).is_err());
+    }
+
+    #[test]
+    fn decode_too_large_fails() {
+        // can't be parsed, but can still be built by hand.
+        let ty = AbiType::FixedArray(Box::new(AbiType::Uint(256)), usize::MAX);
+        assert!(AbiValue::decode_params(&[ty], &[0; 64]).is_err());
+
+        let ty = AbiType::FixedArray(Box::new(AbiType::String), usize::MAX / 32);
+        assert!(AbiValue::decode_params(&[ty], &[0; 64]).is_err());
+    }
+
+    #[test]
+    fn decode_overlapping_tails_fails() {
+        let ty = AbiType::from_str("string[]").unwrap();
+
+        let mut bytes = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000020"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "0000000000000000000000000000000000000000000000000000000000000040"
+            "0000000000000000000000000000000000000000000000000000000000000080"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "6100000000000000000000000000000000000000000000000000000000000000"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "6200000000000000000000000000000000000000000000000000000000000000"
+        );
+
+        assert_eq!(
+            AbiValue::decode_params(&[ty.clone()], &bytes).unwrap(),
+            [AbiValue::Array(AbiType::String, vec!["a".into(), "b".into()])]
+        );
+
+        // both elements share the first tail.
+        bytes[127] = 0x40;
+        assert!(AbiValue::decode_params(&[ty.clone()], &bytes).is_err());
+
+        // the first element points back into the heads.
+        bytes[95] = 0x20;
+        assert!(AbiValue::decode_params(&[ty], &bytes).is_err());
+    }
+}
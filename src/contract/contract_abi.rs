@@ -0,0 +1,908 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use crate::contract::contract_function_selector::ContractFunctionSelector;
+use crate::contract::{
+    AbiType,
+    AbiValue,
+};
+use crate::{
+    ContractFunctionParameters,
+    ContractFunctionResult,
+    ContractLogInfo,
+    Error,
+};
+
+/// The JSON ABI of a solidity contract, as output by `solc`, Hardhat or Foundry.
+///
+/// Lets functions be called by name with dynamically typed [`AbiValue`]s,
+/// instead of building [`ContractFunctionParameters`] by hand.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContractAbi {
+    /// The contract's constructor, if it declares one.
+    ///
+    /// The constructor's `name` is empty and it has no outputs.
+    pub constructor: Option<AbiFunction>,
+
+    /// The contract's functions, in the order they appear in the ABI.
+    pub functions: Vec<AbiFunction>,
+
+    /// The contract's events, in the order they appear in the ABI.
+    pub events: Vec<AbiEvent>,
+
+    /// The contract's custom errors, in the order they appear in the ABI.
+    pub errors: Vec<AbiError>,
+}
+
+/// A function declared in a [`ContractAbi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiFunction {
+    /// The name of the function.
+    pub name: String,
+
+    /// The parameters of the function.
+    pub inputs: Vec<AbiParam>,
+
+    /// The return values of the function.
+    pub outputs: Vec<AbiParam>,
+}
+
+/// An event declared in a [`ContractAbi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiEvent {
+    /// The name of the event.
+    pub name: String,
+
+    /// The parameters of the event, both indexed and not, in the order they're declared.
+    pub inputs: Vec<AbiParam>,
+
+    /// Whether the event is `anonymous`, in which case the first topic of its logs isn't the event's [`topic`](Self::topic).
+    pub anonymous: bool,
+}
+
+/// A custom error declared in a [`ContractAbi`], such as `error InsufficientBalance(uint256 available)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiError {
+    /// The name of the error.
+    pub name: String,
+
+    /// The parameters of the error.
+    pub inputs: Vec<AbiParam>,
+}
+
+/// A parameter or return value of an [`AbiFunction`], or a parameter of an [`AbiEvent`] or [`AbiError`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiParam {
+    /// The name of the parameter, which is empty for unnamed parameters.
+    pub name: String,
+
+    /// The type of the parameter.
+    pub ty: AbiType,
+
+    /// Whether the parameter is logged as a topic, rather than in the data, this is only ever `true` for events.
+    pub indexed: bool,
+}
+
+/// Values decoded with a [`ContractAbi`], which can be looked up by the name of their parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamedAbiValues {
+    names: Vec<String>,
+    values: Vec<AbiValue>,
+}
+
+impl ContractAbi {
+    /// Parses a JSON ABI.
+    ///
+    /// `json` is either the ABI itself (a JSON array, as output by `solc --abi`),
+    /// or a Hardhat or Foundry build artifact, which has the ABI in its `abi` field.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't a valid ABI or build artifact.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        Self::from_json_value(serde_json::from_str(json).map_err(Error::basic_parse)?)
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_json_value(json: serde_json::Value) -> crate::Result<Self> {
+        let entries = match json {
+            serde_json::Value::Array(_) => json,
+            serde_json::Value::Object(mut artifact) => artifact.remove("abi").ok_or_else(|| {
+                Error::basic_parse("expected a JSON ABI or an artifact with an `abi` field")
+            })?,
+            _ => {
+                return Err(Error::basic_parse(
+                    "expected a JSON ABI or an artifact with an `abi` field",
+                ))
+            }
+        };
+
+        let entries: Vec<json::EntryJson> =
+            serde_json::from_value(entries).map_err(Error::basic_parse)?;
+
+        let mut abi = Self::default();
+
+        for entry in entries {
+            match entry.kind.as_str() {
+                "function" => abi.functions.push(entry.try_into()?),
+                "constructor" => abi.constructor = Some(entry.try_into()?),
+                "event" => abi.events.push(entry.try_into()?),
+                "error" => abi.errors.push(entry.try_into()?),
+                // `fallback` and `receive` have nothing to call by name.
+                _ => {}
+            }
+        }
+
+        Ok(abi)
+    }
+
+    /// Encodes `values` as the parameters of the constructor, to deploy the contract with.
+    ///
+    /// `values` are converted to the types of the parameters with [`AbiValue::coerce_to`].
+    /// The result can be passed to [`ContractCreateTransaction::constructor_parameters`](crate::ContractCreateTransaction::constructor_parameters).
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `values` don't fit the parameters of the constructor.
+    pub fn encode_constructor(&self, values: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        match &self.constructor {
+            Some(constructor) => Ok(constructor.parameters(values)?.to_bytes(None)),
+            // a contract without a constructor has an implicit one without parameters.
+            None if values.is_empty() => Ok(Vec::new()),
+            None => Err(Error::abi(format!(
+                "the constructor takes no parameters, but {} values were given",
+                values.len()
+            ))),
+        }
+    }
+
+    /// Returns the function called `name`.
+    ///
+    /// `name` is either the name of the function, or its full signature, such as `transfer(address,uint256)`,
+    /// which picks one of several overloads of the same name.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if there is no such function, or if `name` is the name of several overloaded functions.
+    pub fn function(&self, name: &str) -> crate::Result<&AbiFunction> {
+        self.find_function(name, None)
+    }
+
+    /// Encodes `values` as the parameters of the function called `name`.
+    ///
+    /// `values` are converted to the types of the parameters with [`AbiValue::coerce_to`].
+    /// If `name` is overloaded, the overload with as many parameters as there are `values` is used.
+    /// The result can be passed to [`ContractExecuteTransaction::function_with_parameters`](crate::ContractExecuteTransaction::function_with_parameters)
+    /// or [`ContractCallQuery::function_with_parameters`](crate::ContractCallQuery::function_with_parameters)
+    /// along with the function's [`name`](AbiFunction::name).
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if there is no such function, or if `values` don't fit its parameters.
+    pub fn function_parameters(
+        &self,
+        name: &str,
+        values: &[AbiValue],
+    ) -> crate::Result<ContractFunctionParameters> {
+        self.find_function(name, Some(values.len()))?.parameters(values)
+    }
+
+    /// Encodes a call of the function called `name` with `values`, starting with the function selector.
+    ///
+    /// The result can be passed to [`ContractExecuteTransaction::function_parameters`](crate::ContractExecuteTransaction::function_parameters)
+    /// or [`ContractCallQuery::function_parameters`](crate::ContractCallQuery::function_parameters).
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if there is no such function, or if `values` don't fit its parameters.
+    pub fn encode_call(&self, name: &str, values: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        self.find_function(name, Some(values.len()))?.encode_call(values)
+    }
+
+    /// Decodes `result` as the return values of the function called `name`.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if there is no such function, or if `result` isn't a valid encoding of its return values.
+    pub fn decode_output(
+        &self,
+        name: &str,
+        result: &ContractFunctionResult,
+    ) -> crate::Result<NamedAbiValues> {
+        self.function(name)?.decode_output(result.as_bytes())
+    }
+
+    /// Returns the event called `name`.
+    ///
+    /// `name` is either the name of the event, or its full signature, such as `Transfer(address,address,uint256)`.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if there is no such event, or if `name` matches more than one event.
+    pub fn event(&self, name: &str) -> crate::Result<&AbiEvent> {
+        let candidates: Vec<_> = match name.split_once('(') {
+            Some((name, inputs)) => {
+                let inputs: AbiType = format!("({inputs}").parse()?;
+                self.events
+                    .iter()
+                    .filter(|event| event.name == name && event.input_type() == inputs)
+                    .collect()
+            }
+
+            None => self.events.iter().filter(|event| event.name == name).collect(),
+        };
+
+        match candidates.as_slice() {
+            [event] => Ok(event),
+            [] => Err(Error::abi(format!("no event matching `{name}` in the ABI"))),
+            // unlike functions, events can share a signature, such as ERC-20 and ERC-721 `Transfer`s.
+            _ => Err(Error::abi(format!("more than one event matches `{name}` in the ABI"))),
+        }
+    }
+
+    /// Decodes `log` as an instance of whichever event in this ABI it matches, returning the event and its parameters.
+    ///
+    /// Events are matched by the first topic of the log.
+    /// Anonymous events have no such topic, so they're tried last, in order, and the first one that decodes is used.
+    ///
+    /// This works for the [`logs`](ContractFunctionResult::logs) of any `ContractFunctionResult`,
+    /// including the ones in transaction records.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if no event in this ABI matches `log`.
+    pub fn decode_log(&self, log: &ContractLogInfo) -> crate::Result<(&AbiEvent, NamedAbiValues)> {
+        let first_topic = log.topics.first();
+
+        // an ERC-20 and an ERC-721 `Transfer` share a signature, but not which parameters are indexed,
+        // so every event with a matching topic gets a try.
+        let named = self
+            .events
+            .iter()
+            .filter(|event| !event.anonymous && first_topic.is_some_and(|it| *it == event.topic()));
+        let anonymous = self.events.iter().filter(|event| event.anonymous);
+
+        named
+            .chain(anonymous)
+            .find_map(|event| event.decode_log(log).ok().map(|values| (event, values)))
+            .ok_or_else(|| Error::abi("no event in the ABI matches the log"))
+    }
+
+    /// Decodes revert `data` as one of the custom errors in this ABI, returning the error and its parameters.
+    ///
+    /// `data` is the whole revert data, starting with the error's selector.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if no error in this ABI matches `data`.
+    pub fn decode_error(&self, data: &[u8]) -> crate::Result<(&AbiError, NamedAbiValues)> {
+        self.errors
+            .iter()
+            .filter(|error| data.starts_with(&error.selector()))
+            .find_map(|error| error.decode(data).ok().map(|values| (error, values)))
+            .ok_or_else(|| Error::abi("no error in the ABI matches the revert data"))
+    }
+
+    fn find_function(&self, name: &str, input_count: Option<usize>) -> crate::Result<&AbiFunction> {
+        let candidates: Vec<_> = match name.split_once('(') {
+            Some((name, inputs)) => {
+                let inputs: AbiType = format!("({inputs}").parse()?;
+                self.functions
+                    .iter()
+                    .filter(|function| function.name == name && function.input_type() == inputs)
+                    .collect()
+            }
+
+            None => self
+                .functions
+                .iter()
+                .filter(|function| function.name == name)
+                .filter(|function| input_count.is_none_or(|count| function.inputs.len() == count))
+                .collect(),
+        };
+
+        match candidates.as_slice() {
+            [function] => Ok(function),
+            [] => Err(Error::abi(format!("no function matching `{name}` in the ABI"))),
+            [first, ..] => Err(Error::abi(format!(
+                "function `{name}` is overloaded, use its full signature, such as `{}`",
+                first.signature()
+            ))),
+        }
+    }
+}
+
+impl AbiFunction {
+    /// Returns the signature of this function, such as `transfer(address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        format!("{}{}", self.name, self.input_type())
+    }
+
+    /// Returns the selector of this function: the first 4 bytes of the hash of its [`signature`](Self::signature).
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        let mut selector = ContractFunctionSelector::new(&self.name);
+        for input in &self.inputs {
+            selector.add_param_type(&input.ty.to_string());
+        }
+
+        selector.finish()
+    }
+
+    /// Encodes `values` as the parameters of this function.
+    ///
+    /// `values` are converted to the types of the parameters with [`AbiValue::coerce_to`].
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `values` don't fit the parameters of this function.
+    pub fn parameters(&self, values: &[AbiValue]) -> crate::Result<ContractFunctionParameters> {
+        if values.len() != self.inputs.len() {
+            return Err(Error::abi(format!(
+                "`{}` takes {} parameters, but {} values were given",
+                self.signature(),
+                self.inputs.len(),
+                values.len()
+            )));
+        }
+
+        let mut parameters = ContractFunctionParameters::new();
+        for (value, input) in values.iter().zip(&self.inputs) {
+            parameters.try_add_value(&value.coerce_to(&input.ty)?)?;
+        }
+
+        Ok(parameters)
+    }
+
+    /// Encodes a call of this function with `values`, starting with the function selector.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `values` don't fit the parameters of this function.
+    pub fn encode_call(&self, values: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        Ok(self.parameters(values)?.to_bytes(Some(&self.name)))
+    }
+
+    /// Decodes `bytes` as the return values of this function.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `bytes` isn't a valid encoding of the return values of this function.
+    pub fn decode_output(&self, bytes: &[u8]) -> crate::Result<NamedAbiValues> {
+        NamedAbiValues::decode(&self.outputs, bytes)
+    }
+
+    fn input_type(&self) -> AbiType {
+        AbiType::Tuple(self.inputs.iter().map(|input| input.ty.clone()).collect())
+    }
+}
+
+impl AbiEvent {
+    /// Returns the signature of this event, such as `Transfer(address,address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        format!("{}{}", self.name, self.input_type())
+    }
+
+    /// Returns the topic logs of this event start with (unless it's anonymous): the hash of its [`signature`](Self::signature).
+    #[must_use]
+    pub fn topic(&self) -> [u8; 32] {
+        Keccak256::digest(self.signature().as_bytes()).into()
+    }
+
+    /// Decodes `log` as an instance of this event.
+    ///
+    /// Indexed parameters that aren't of a value type (such as `string`, `bytes`, arrays and tuples)
+    /// are only logged as the hash of their encoding, so they're decoded as the `bytes32` hash.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `log` isn't an instance of this event.
+    pub fn decode_log(&self, log: &ContractLogInfo) -> crate::Result<NamedAbiValues> {
+        let topics = match log.topics.split_first() {
+            _ if self.anonymous => log.topics.as_slice(),
+            Some((first, rest)) if *first == self.topic() => rest,
+            _ => {
+                return Err(Error::abi(format!("log isn't an instance of `{}`", self.signature())))
+            }
+        };
+
+        let indexed_count = self.inputs.iter().filter(|input| input.indexed).count();
+        if topics.len() != indexed_count {
+            return Err(Error::abi(format!(
+                "log has {} indexed values, but `{}` has {indexed_count}",
+                topics.len(),
+                self.signature()
+            )));
+        }
+
+        let data_types: Vec<_> = self
+            .inputs
+            .iter()
+            .filter(|input| !input.indexed)
+            .map(|input| input.ty.clone())
+            .collect();
+
+        let mut topics = topics.iter();
+        let mut data = AbiValue::decode_params(&data_types, &log.data)?.into_iter();
+
+        let mut values = Vec::with_capacity(self.inputs.len());
+        for input in &self.inputs {
+            let value = if input.indexed {
+                topics.next().map(|topic| decode_topic(&input.ty, topic)).transpose()?
+            } else {
+                data.next()
+            };
+
+            values.push(value.ok_or_else(|| Error::abi("ran out of values decoding a log"))?);
+        }
+
+        Ok(NamedAbiValues {
+            names: self.inputs.iter().map(|input| input.name.clone()).collect(),
+            values,
+        })
+    }
+
+    fn input_type(&self) -> AbiType {
+        AbiType::Tuple(self.inputs.iter().map(|input| input.ty.clone()).collect())
+    }
+}
+
+impl AbiError {
+    /// Returns the signature of this error, such as `InsufficientBalance(uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        format!("{}{}", self.name, self.input_type())
+    }
+
+    /// Returns the selector revert data for this error starts with, which is computed like a function's selector.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        let mut selector = ContractFunctionSelector::new(&self.name);
+        for input in &self.inputs {
+            selector.add_param_type(&input.ty.to_string());
+        }
+
+        selector.finish()
+    }
+
+    /// Decodes revert `data`, starting with the selector, as an instance of this error.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `data` isn't an instance of this error.
+    pub fn decode(&self, data: &[u8]) -> crate::Result<NamedAbiValues> {
+        let params = data.strip_prefix(&self.selector()).ok_or_else(|| {
+            Error::abi(format!("revert data isn't an instance of `{}`", self.signature()))
+        })?;
+
+        NamedAbiValues::decode(&self.inputs, params)
+    }
+
+    fn input_type(&self) -> AbiType {
+        AbiType::Tuple(self.inputs.iter().map(|input| input.ty.clone()).collect())
+    }
+}
+
+fn decode_topic(ty: &AbiType, topic: &[u8]) -> crate::Result<AbiValue> {
+    if topic.len() != 32 {
+        return Err(Error::abi(format!("log topic is {} bytes long, not 32", topic.len())));
+    }
+
+    match ty {
+        AbiType::Bytes
+        | AbiType::String
+        | AbiType::Array(_)
+        | AbiType::FixedArray(..)
+        | AbiType::Tuple(_) => Ok(AbiValue::FixedBytes(topic.to_vec())),
+        _ => AbiValue::decode_head(ty, topic, 0),
+    }
+}
+
+impl NamedAbiValues {
+    pub(crate) fn decode(params: &[AbiParam], bytes: &[u8]) -> crate::Result<Self> {
+        let types: Vec<_> = params.iter().map(|param| param.ty.clone()).collect();
+
+        Ok(Self {
+            names: params.iter().map(|param| param.name.clone()).collect(),
+            values: AbiValue::decode_params(&types, bytes)?,
+        })
+    }
+
+    /// Returns the value of the parameter called `name`.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&AbiValue> {
+        let index = self.names.iter().position(|it| it == name)?;
+        self.values.get(index)
+    }
+
+    /// Returns the names of the parameters, in order, unnamed parameters have an empty name.
+    #[must_use]
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns the values, in order.
+    #[must_use]
+    pub fn values(&self) -> &[AbiValue] {
+        &self.values
+    }
+
+    /// Returns the values, in order.
+    #[must_use]
+    pub fn into_values(self) -> Vec<AbiValue> {
+        self.values
+    }
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use super::{
+        AbiError,
+        AbiEvent,
+        AbiFunction,
+        AbiParam,
+    };
+    use crate::contract::AbiType;
+    use crate::Error;
+
+    #[derive(serde_derive::Deserialize)]
+    pub(super) struct EntryJson {
+        // `type` is allowed to be left out for functions.
+        #[serde(rename = "type", default = "function_kind")]
+        pub(super) kind: String,
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        inputs: Vec<ParamJson>,
+        #[serde(default)]
+        outputs: Vec<ParamJson>,
+        #[serde(default)]
+        anonymous: bool,
+    }
+
+    fn function_kind() -> String {
+        "function".to_owned()
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    struct ParamJson {
+        #[serde(default)]
+        name: String,
+        #[serde(rename = "type")]
+        ty: String,
+        #[serde(default)]
+        components: Vec<ParamJson>,
+        #[serde(default)]
+        indexed: bool,
+    }
+
+    impl TryFrom<EntryJson> for AbiFunction {
+        type Error = Error;
+
+        fn try_from(entry: EntryJson) -> crate::Result<Self> {
+            Ok(Self {
+                name: entry.name,
+                inputs: params(entry.inputs)?,
+                outputs: params(entry.outputs)?,
+            })
+        }
+    }
+
+    impl TryFrom<EntryJson> for AbiEvent {
+        type Error = Error;
+
+        fn try_from(entry: EntryJson) -> crate::Result<Self> {
+            Ok(Self { name: entry.name, inputs: params(entry.inputs)?, anonymous: entry.anonymous })
+        }
+    }
+
+    impl TryFrom<EntryJson> for AbiError {
+        type Error = Error;
+
+        fn try_from(entry: EntryJson) -> crate::Result<Self> {
+            Ok(Self { name: entry.name, inputs: params(entry.inputs)? })
+        }
+    }
+
+    fn params(params: Vec<ParamJson>) -> crate::Result<Vec<AbiParam>> {
+        params.into_iter().map(AbiParam::try_from).collect()
+    }
+
+    impl TryFrom<ParamJson> for AbiParam {
+        type Error = Error;
+
+        fn try_from(param: ParamJson) -> crate::Result<Self> {
+            // tuples are spelled `tuple`, `tuple[]`, `tuple[2]`... with their types in `components`.
+            let ty = match param.ty.strip_prefix("tuple") {
+                Some(suffix) => {
+                    let components = params(param.components)?;
+                    let tuple = AbiType::Tuple(components.into_iter().map(|it| it.ty).collect());
+                    format!("{tuple}{suffix}").parse()?
+                }
+
+                None => param.ty.parse()?,
+            };
+
+            Ok(Self { name: param.name, ty, indexed: param.indexed })
+        }
+    }
+}
+
+// every test starts from a JSON ABI.
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use hex_literal::hex;
+    use num_bigint::BigUint;
+
+    use super::ContractAbi;
+    use crate::contract::{
+        AbiType,
+        AbiValue,
+    };
+    use crate::{
+        ContractId,
+        ContractLogInfo,
+        EvmAddress,
+    };
+
+    // a trimmed down Hardhat artifact.
+    const ARTIFACT: &str = r#"{
+        "_format": "hh-sol-artifact-1",
+        "contractName": "Orders",
+        "abi": [
+            {
+                "type": "constructor",
+                "inputs": [{ "name": "owner", "type": "address", "internalType": "address" }],
+                "stateMutability": "nonpayable"
+            },
+            {
+                "type": "function",
+                "name": "place",
+                "inputs": [
+                    {
+                        "name": "order",
+                        "type": "tuple",
+                        "internalType": "struct Orders.Order",
+                        "components": [
+                            { "name": "amount", "type": "uint64" },
+                            { "name": "memo", "type": "string" }
+                        ]
+                    }
+                ],
+                "outputs": [
+                    { "name": "id", "type": "uint256" },
+                    { "name": "accepted", "type": "bool" }
+                ],
+                "stateMutability": "nonpayable"
+            },
+            {
+                "name": "balanceOf",
+                "inputs": [{ "name": "account", "type": "address" }],
+                "outputs": [{ "name": "", "type": "uint256" }]
+            },
+            {
+                "type": "function",
+                "name": "balanceOf",
+                "inputs": [
+                    { "name": "account", "type": "address" },
+                    { "name": "tokens", "type": "tuple[]", "components": [{ "name": "id", "type": "uint256" }] }
+                ],
+                "outputs": []
+            },
+            {
+                "type": "event",
+                "name": "Placed",
+                "inputs": [{ "name": "id", "type": "uint256", "indexed": true }],
+                "anonymous": false
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn from_json() {
+        let abi = ContractAbi::from_json(ARTIFACT).unwrap();
+
+        assert_eq!(abi.constructor.as_ref().unwrap().inputs[0].ty, AbiType::Address);
+        assert_eq!(abi.functions.len(), 3);
+        assert_eq!(abi.function("place").unwrap().signature(), "place((uint64,string))");
+        assert_eq!(
+            abi.function("balanceOf(address,(uint256)[])").unwrap().signature(),
+            "balanceOf(address,(uint256)[])"
+        );
+
+        // overloaded, so the name alone isn't enough.
+        assert!(abi.function("balanceOf").is_err());
+        assert!(abi.function("missing").is_err());
+    }
+
+    #[test]
+    fn from_bare_abi() {
+        let abi = ContractAbi::from_json(
+            r#"[{ "type": "function", "name": "get", "inputs": [], "outputs": [] }]"#,
+        )
+        .unwrap();
+
+        assert_eq!(abi.function("get").unwrap().selector(), hex!("6d4ce63c"));
+        assert!(ContractAbi::from_json(r#"{ "bytecode": "0x" }"#).is_err());
+    }
+
+    #[test]
+    fn encode_call() {
+        let abi = ContractAbi::from_json(ARTIFACT).unwrap();
+
+        let order = AbiValue::Tuple(vec![AbiValue::from(5_u64), AbiValue::from("hi")]);
+        let call = abi.encode_call("place", &[order]).unwrap();
+
+        assert_eq!(call[..4], abi.function("place").unwrap().selector());
+        assert_eq!(
+            hex::encode(&call[4..]),
+            concat!(
+                "0000000000000000000000000000000000000000000000000000000000000020",
+                "0000000000000000000000000000000000000000000000000000000000000005",
+                "0000000000000000000000000000000000000000000000000000000000000040",
+                "0000000000000000000000000000000000000000000000000000000000000002",
+                "6869000000000000000000000000000000000000000000000000000000000000",
+            )
+        );
+
+        // the overload is picked by the number of values.
+        let address = EvmAddress::from(hex!("00000000000000000000000000000000000004d2"));
+        let call = abi.encode_call("balanceOf", &[AbiValue::from(address)]).unwrap();
+        assert_eq!(call[..4], abi.function("balanceOf(address)").unwrap().selector());
+
+        assert!(abi.encode_call("place", &[AbiValue::from(true)]).is_err());
+        assert!(abi.encode_call("place", &[]).is_err());
+    }
+
+    #[test]
+    fn decode_output() {
+        let abi = ContractAbi::from_json(ARTIFACT).unwrap();
+
+        let output = abi
+            .function("place")
+            .unwrap()
+            .decode_output(&hex!(
+                "000000000000000000000000000000000000000000000000000000000000002a"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+            ))
+            .unwrap();
+
+        assert_eq!(output.get("id").and_then(AbiValue::as_uint), Some(&BigUint::from(42_u8)));
+        assert_eq!(output.get("accepted").and_then(AbiValue::as_bool), Some(true));
+        assert_eq!(output.get("missing"), None);
+        assert_eq!(output.names(), ["id", "accepted"]);
+    }
+
+    const TOKEN_EVENTS: &str = r#"[
+        {
+            "type": "event",
+            "name": "Transfer",
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "value", "type": "uint256", "indexed": false }
+            ],
+            "anonymous": false
+        },
+        {
+            "type": "event",
+            "name": "Transfer",
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "tokenId", "type": "uint256", "indexed": true }
+            ],
+            "anonymous": false
+        },
+        {
+            "type": "event",
+            "name": "Memo",
+            "inputs": [
+                { "name": "memo", "type": "string", "indexed": true },
+                { "name": "sender", "type": "address", "indexed": false }
+            ],
+            "anonymous": true
+        }
+    ]"#;
+
+    const TRANSFER_TOPIC: [u8; 32] =
+        hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+    const FROM: [u8; 32] = hex!("00000000000000000000000000000000000000000000000000000000000004d2");
+    const TO: [u8; 32] = hex!("00000000000000000000000000000000000000000000000000000000000004d3");
+
+    fn log(topics: &[&[u8]], data: &[u8]) -> ContractLogInfo {
+        ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: topics.iter().map(|it| it.to_vec()).collect(),
+            data: data.to_vec(),
+        }
+    }
+
+    #[test]
+    fn event_topic() {
+        let abi = ContractAbi::from_json(TOKEN_EVENTS).unwrap();
+
+        // the ERC-20 and ERC-721 `Transfer`s only differ in which parameters are indexed.
+        assert!(abi.event("Transfer(address, address, uint256)").is_err());
+        assert_eq!(abi.events[0].topic(), TRANSFER_TOPIC);
+        assert_eq!(abi.events[1].topic(), TRANSFER_TOPIC);
+
+        let event = abi.event("Memo").unwrap();
+        assert_eq!(event.signature(), "Memo(string,address)");
+        assert!(event.anonymous);
+    }
+
+    #[test]
+    fn decode_fungible_transfer() {
+        let abi = ContractAbi::from_json(TOKEN_EVENTS).unwrap();
+
+        let log = log(
+            &[&TRANSFER_TOPIC, &FROM, &TO],
+            &hex!("0000000000000000000000000000000000000000000000000000000000000064"),
+        );
+
+        let (event, values) = abi.decode_log(&log).unwrap();
+
+        assert_eq!(event.inputs[2].name, "value");
+        assert_eq!(
+            values.get("from").and_then(AbiValue::as_address),
+            Some(EvmAddress::from(hex!("00000000000000000000000000000000000004d2")))
+        );
+        assert_eq!(
+            values.get("to").and_then(AbiValue::as_address),
+            Some(EvmAddress::from(hex!("00000000000000000000000000000000000004d3")))
+        );
+        assert_eq!(values.get("value").and_then(AbiValue::as_uint), Some(&BigUint::from(100_u8)));
+    }
+
+    #[test]
+    fn decode_nft_transfer() {
+        let abi = ContractAbi::from_json(TOKEN_EVENTS).unwrap();
+
+        let serial = hex!("0000000000000000000000000000000000000000000000000000000000000007");
+        let log = log(&[&TRANSFER_TOPIC, &FROM, &TO, &serial], &[]);
+
+        let (event, values) = abi.decode_log(&log).unwrap();
+
+        assert_eq!(event.inputs[2].name, "tokenId");
+        assert_eq!(values.get("tokenId").and_then(AbiValue::as_uint), Some(&BigUint::from(7_u8)));
+    }
+
+    #[test]
+    fn decode_anonymous() {
+        let abi = ContractAbi::from_json(TOKEN_EVENTS).unwrap();
+
+        // indexed strings are only logged as their hash.
+        let memo_hash = hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8");
+        let log = log(&[&memo_hash], &FROM);
+
+        let (event, values) = abi.decode_log(&log).unwrap();
+
+        assert_eq!(event.name, "Memo");
+        assert_eq!(values.get("memo").and_then(AbiValue::as_bytes), Some(memo_hash.as_slice()));
+        assert_eq!(values.values()[1].abi_type(), AbiType::Address);
+    }
+
+    #[test]
+    fn decode_unknown_log() {
+        let abi = ContractAbi::from_json(TOKEN_EVENTS).unwrap();
+
+        let log = log(&[&[0xab; 32], &FROM], &[]);
+
+        assert!(abi.decode_log(&log).is_err());
+        assert!(abi.events[0].decode_log(&log).is_err());
+    }
+}
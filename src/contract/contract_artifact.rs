@@ -0,0 +1,437 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::ops::Range;
+
+use crate::contract::ContractAbi;
+use crate::{
+    ContractId,
+    Error,
+};
+
+/// A compiled contract, as output by Hardhat or Foundry.
+///
+/// Libraries the contract uses are left as placeholders in its bytecode,
+/// which have to be [linked](Self::link_library) to deployed libraries before the contract can be deployed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractArtifact {
+    /// The ABI of the contract.
+    pub abi: ContractAbi,
+
+    // hex, without `0x`, with library placeholders in it until they're linked.
+    bytecode: String,
+    deployed_bytecode: String,
+    link_references: Vec<LinkReference>,
+    deployed_link_references: Vec<LinkReference>,
+    immutable_references: Vec<Range<usize>>,
+}
+
+/// A spot in the bytecode where the address of a library goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LinkReference {
+    file: String,
+    name: String,
+    offsets: Range<usize>,
+}
+
+impl ContractArtifact {
+    /// Parses a Hardhat (`artifacts/**/Contract.json`) or Foundry (`out/**/Contract.json`) build artifact.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't a valid build artifact.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let artifact: json::ArtifactJson =
+            serde_json::from_str(json).map_err(Error::basic_parse)?;
+
+        artifact.try_into()
+    }
+
+    /// Links every placeholder for the library `name` to the deployed library `contract_id`.
+    ///
+    /// `name` is either the name of the library, or its fully qualified name, such as `contracts/Math.sol:Math`.
+    ///
+    /// # Errors
+    /// - [`Error::LibraryLink`] if the contract doesn't use a library called `name`.
+    /// - [`Error::BasicParse`] if `contract_id` can't be converted to a solidity address.
+    pub fn link_library(
+        &mut self,
+        name: &str,
+        contract_id: ContractId,
+    ) -> crate::Result<&mut Self> {
+        let address = contract_id.to_solidity_address()?;
+
+        let linked = link(&mut self.bytecode, &mut self.link_references, name, &address)
+            | link(&mut self.deployed_bytecode, &mut self.deployed_link_references, name, &address);
+
+        if !linked {
+            return Err(Error::library_link(format!(
+                "the contract doesn't use a library `{name}`"
+            )));
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the names of the libraries which are yet to be linked, in the form `file:Library`.
+    #[must_use]
+    pub fn unlinked_libraries(&self) -> Vec<String> {
+        let mut names: Vec<_> = self
+            .link_references
+            .iter()
+            .chain(&self.deployed_link_references)
+            .map(|it| format!("{}:{}", it.file, it.name))
+            .collect();
+
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    /// Returns the creation bytecode of the contract, which is what gets deployed.
+    ///
+    /// # Errors
+    /// - [`Error::LibraryLink`] if any library is yet to be linked.
+    pub fn bytecode(&self) -> crate::Result<Vec<u8>> {
+        self.check_linked()?;
+
+        hex::decode(&self.bytecode).map_err(Error::basic_parse)
+    }
+
+    /// Returns the runtime bytecode the contract is expected to have once it's deployed.
+    ///
+    /// # Errors
+    /// - [`Error::LibraryLink`] if any library is yet to be linked.
+    pub fn deployed_bytecode(&self) -> crate::Result<Vec<u8>> {
+        self.check_linked()?;
+
+        hex::decode(&self.deployed_bytecode).map_err(Error::basic_parse)
+    }
+
+    /// Returns `true` if `runtime_bytecode`, as returned by a [`ContractBytecodeQuery`](crate::ContractBytecodeQuery),
+    /// is the [`deployed_bytecode`](Self::deployed_bytecode) of this contract.
+    ///
+    /// Immutable variables are set by the constructor, so the bytes they take up are ignored when the artifact
+    /// says where they are, as Foundry artifacts do. Hardhat artifacts don't, so a contract with immutable
+    /// variables won't match its Hardhat artifact.
+    ///
+    /// # Errors
+    /// - [`Error::LibraryLink`] if any library is yet to be linked.
+    pub fn matches_deployed_bytecode(&self, runtime_bytecode: &[u8]) -> crate::Result<bool> {
+        let expected = self.deployed_bytecode()?;
+
+        if expected.len() != runtime_bytecode.len() {
+            return Ok(false);
+        }
+
+        Ok(expected.iter().zip(runtime_bytecode).enumerate().all(|(i, (expected, actual))| {
+            expected == actual || self.immutable_references.iter().any(|it| it.contains(&i))
+        }))
+    }
+
+    fn check_linked(&self) -> crate::Result<()> {
+        let unlinked = self.unlinked_libraries();
+
+        if !unlinked.is_empty() {
+            return Err(Error::library_link(format!(
+                "libraries `{}` are yet to be linked",
+                unlinked.join("`, `")
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes `address` over every placeholder for `name` in `bytecode`, returns `true` if there were any.
+fn link(
+    bytecode: &mut String,
+    references: &mut Vec<LinkReference>,
+    name: &str,
+    address: &str,
+) -> bool {
+    let len_before = references.len();
+
+    references.retain(|reference| {
+        let matches = reference.name == name
+            || name.strip_prefix(&*reference.file).and_then(|it| it.strip_prefix(':'))
+                == Some(&reference.name);
+
+        if matches {
+            // offsets are in bytes, and `bytecode` is hex.
+            bytecode.replace_range(reference.offsets.start * 2..reference.offsets.end * 2, address);
+        }
+
+        !matches
+    });
+
+    references.len() != len_before
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use std::collections::BTreeMap;
+
+    use super::{
+        ContractArtifact,
+        LinkReference,
+    };
+    use crate::contract::ContractAbi;
+    use crate::Error;
+
+    // file -> library -> offsets
+    type LinkReferencesJson = BTreeMap<String, BTreeMap<String, Vec<OffsetJson>>>;
+
+    #[derive(serde_derive::Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub(super) struct ArtifactJson {
+        abi: serde_json::Value,
+        bytecode: BytecodeJson,
+        deployed_bytecode: BytecodeJson,
+        // Hardhat puts link references next to the bytecode, Foundry puts them in it.
+        #[serde(default)]
+        link_references: LinkReferencesJson,
+        #[serde(default)]
+        deployed_link_references: LinkReferencesJson,
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    #[serde(untagged)]
+    enum BytecodeJson {
+        // Hardhat
+        Hex(String),
+        // Foundry
+        #[serde(rename_all = "camelCase")]
+        Object {
+            object: String,
+            #[serde(default)]
+            link_references: LinkReferencesJson,
+            #[serde(default)]
+            immutable_references: BTreeMap<String, Vec<OffsetJson>>,
+        },
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    struct OffsetJson {
+        start: usize,
+        length: usize,
+    }
+
+    impl TryFrom<ArtifactJson> for ContractArtifact {
+        type Error = Error;
+
+        fn try_from(artifact: ArtifactJson) -> crate::Result<Self> {
+            let (bytecode, link_references, _) =
+                parse_bytecode(artifact.bytecode, artifact.link_references)?;
+            let (deployed_bytecode, deployed_link_references, immutable_references) =
+                parse_bytecode(artifact.deployed_bytecode, artifact.deployed_link_references)?;
+
+            Ok(Self {
+                abi: ContractAbi::from_json_value(artifact.abi)?,
+                bytecode,
+                deployed_bytecode,
+                link_references,
+                deployed_link_references,
+                immutable_references,
+            })
+        }
+    }
+
+    fn parse_bytecode(
+        bytecode: BytecodeJson,
+        link_references: LinkReferencesJson,
+    ) -> crate::Result<(String, Vec<LinkReference>, Vec<std::ops::Range<usize>>)> {
+        let (hex, link_references, immutable_references) = match bytecode {
+            BytecodeJson::Hex(hex) => (hex, link_references, BTreeMap::new()),
+            BytecodeJson::Object { object, link_references, immutable_references } => {
+                (object, link_references, immutable_references)
+            }
+        };
+
+        let hex = hex.strip_prefix("0x").map(str::to_owned).unwrap_or(hex);
+
+        let mut references = Vec::new();
+        for (file, libraries) in link_references {
+            for (name, offsets) in libraries {
+                for OffsetJson { start, length } in offsets {
+                    let end = start
+                        .checked_add(length)
+                        .filter(|end| end.checked_mul(2).is_some_and(|it| it <= hex.len()));
+
+                    let Some(end) = end.filter(|_| length == 20) else {
+                        return Err(Error::basic_parse(format!(
+                            "invalid link reference for library `{file}:{name}`"
+                        )));
+                    };
+
+                    references.push(LinkReference {
+                        file: file.clone(),
+                        name: name.clone(),
+                        offsets: start..end,
+                    });
+                }
+            }
+        }
+
+        // placeholders get overwritten by addresses, anything else has to be hex already.
+        // (this also means that every offset is on a char boundary)
+        let is_valid = |(i, byte): (usize, u8)| {
+            byte.is_ascii_hexdigit()
+                || (byte.is_ascii() && references.iter().any(|it| it.offsets.contains(&(i / 2))))
+        };
+
+        if !hex.bytes().enumerate().all(is_valid) {
+            return Err(Error::basic_parse("bytecode isn't hex"));
+        }
+
+        let immutable_references = immutable_references
+            .into_values()
+            .flatten()
+            .map(|OffsetJson { start, length }| {
+                let end = start
+                    .checked_add(length)
+                    .ok_or_else(|| Error::basic_parse("invalid immutable reference"))?;
+
+                Ok(start..end)
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok((hex, references, immutable_references))
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use hex_literal::hex;
+
+    use super::ContractArtifact;
+    use crate::contract::AbiValue;
+    use crate::ContractId;
+
+    // a trimmed down Hardhat artifact for a contract using the library `Math`, the bytecode is made up.
+    const HARDHAT_ARTIFACT: &str = r#"{
+        "_format": "hh-sol-artifact-1",
+        "contractName": "Calculator",
+        "sourceName": "contracts/Calculator.sol",
+        "abi": [
+            {
+                "inputs": [{ "internalType": "uint256", "name": "seed", "type": "uint256" }],
+                "stateMutability": "nonpayable",
+                "type": "constructor"
+            }
+        ],
+        "bytecode": "0x6080__$f2c1f4d4d9e3b13c86bdb5c4b3b0bfe0c4$__6000",
+        "deployedBytecode": "0x60aa__$f2c1f4d4d9e3b13c86bdb5c4b3b0bfe0c4$__",
+        "linkReferences": {
+            "contracts/Math.sol": { "Math": [{ "length": 20, "start": 2 }] }
+        },
+        "deployedLinkReferences": {
+            "contracts/Math.sol": { "Math": [{ "length": 20, "start": 2 }] }
+        }
+    }"#;
+
+    // a trimmed down Foundry artifact for a contract with an immutable variable, the bytecode is made up.
+    const FOUNDRY_ARTIFACT: &str = r#"{
+        "abi": [],
+        "bytecode": { "object": "0x60806040", "linkReferences": {} },
+        "deployedBytecode": {
+            "object": "0x60806040520000007f",
+            "linkReferences": {},
+            "immutableReferences": { "7": [{ "start": 5, "length": 3 }] }
+        }
+    }"#;
+
+    #[test]
+    fn link_library() {
+        let mut artifact = ContractArtifact::from_json(HARDHAT_ARTIFACT).unwrap();
+
+        assert_eq!(artifact.unlinked_libraries(), ["contracts/Math.sol:Math"]);
+        assert!(artifact.bytecode().is_err());
+
+        artifact.link_library("contracts/Math.sol:Math", ContractId::new(0, 0, 0x1234)).unwrap();
+
+        assert!(artifact.unlinked_libraries().is_empty());
+        assert_eq!(
+            artifact.bytecode().unwrap(),
+            hex!("6080 0000000000000000000000000000000000001234 6000")
+        );
+        assert_eq!(
+            artifact.deployed_bytecode().unwrap(),
+            hex!("60aa 0000000000000000000000000000000000001234")
+        );
+    }
+
+    #[test]
+    fn invalid_bytecode() {
+        let artifact = |bytecode: &str, start: usize| {
+            ContractArtifact::from_json(&format!(
+                r#"{{
+                    "abi": [],
+                    "bytecode": "{bytecode}",
+                    "deployedBytecode": "",
+                    "linkReferences": {{ "Math.sol": {{ "Math": [{{ "length": 20, "start": {start} }}] }} }}
+                }}"#
+            ))
+        };
+
+        let placeholder = "__$f2c1f4d4d9e3b13c86bdb5c4b3b0bfe0c4$__";
+
+        assert!(artifact(&format!("6080{placeholder}"), 2).is_ok());
+        // the offset overflows.
+        assert!(artifact(&format!("6080{placeholder}"), usize::MAX - 10).is_err());
+        // `é` is two bytes, so the placeholder doesn't start on a char boundary.
+        assert!(artifact(&format!("é80{placeholder}"), 2).is_err());
+        // not hex outside of the placeholder.
+        assert!(artifact(&format!("60zz{placeholder}"), 2).is_err());
+    }
+
+    #[test]
+    fn link_unknown_library() {
+        let mut artifact = ContractArtifact::from_json(HARDHAT_ARTIFACT).unwrap();
+
+        assert!(artifact.link_library("Strings", ContractId::new(0, 0, 1)).is_err());
+        assert!(artifact.link_library("other/Math.sol:Math", ContractId::new(0, 0, 1)).is_err());
+
+        artifact.link_library("Math", ContractId::new(0, 0, 1)).unwrap();
+    }
+
+    #[test]
+    fn constructor_parameters() {
+        let artifact = ContractArtifact::from_json(HARDHAT_ARTIFACT).unwrap();
+
+        assert_eq!(
+            artifact.abi.encode_constructor(&[AbiValue::from(5_u8)]).unwrap(),
+            hex!("0000000000000000000000000000000000000000000000000000000000000005")
+        );
+        assert!(artifact.abi.encode_constructor(&[]).is_err());
+    }
+
+    #[test]
+    fn matches_deployed_bytecode() {
+        let artifact = ContractArtifact::from_json(FOUNDRY_ARTIFACT).unwrap();
+
+        assert_eq!(artifact.bytecode().unwrap(), hex!("60806040"));
+
+        // the immutable variable is set to `0x123456` by the constructor.
+        assert!(artifact.matches_deployed_bytecode(&hex!("6080604052 123456 7f")).unwrap());
+        assert!(!artifact.matches_deployed_bytecode(&hex!("6080604053 123456 7f")).unwrap());
+        assert!(!artifact.matches_deployed_bytecode(&hex!("6080604052 123456")).unwrap());
+    }
+}
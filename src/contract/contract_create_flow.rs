@@ -20,12 +20,16 @@
 
 use time::Duration;
 
+use crate::contract::AbiValue;
 use crate::signer::AnySigner;
 use crate::staked_id::StakedId;
 use crate::{
     AccountId,
     Client,
+    ContractArtifact,
+    ContractBytecodeQuery,
     ContractCreateTransaction,
+    ContractId,
     Error,
     FileAppendTransaction,
     FileCreateTransaction,
@@ -35,6 +39,7 @@ use crate::{
     Key,
     PrivateKey,
     PublicKey,
+    Signer,
     TransactionResponse,
 };
 
@@ -44,9 +49,13 @@ use crate::{
 /// 1. Create a file for the contract's bytecode (via a [`FileCreateTransaction`] and zero or more [`FileAppendTransaction`]s)
 /// 2. Execute a [`ContractCreateTransaction`] using the provided information and the newly created file.
 /// 3. Delete the file created in step 1.
+/// 4. If the bytecode came from an [`artifact`](Self::artifact), check the runtime bytecode of the new contract
+///    against the artifact's (via a [`ContractBytecodeQuery`]).
 #[derive(Default, Debug)]
 pub struct ContractCreateFlow {
     bytecode: Vec<u8>,
+    artifact: Option<ContractArtifact>,
+    verify_deployed_bytecode: bool,
     file_append_max_chunks: Option<usize>,
     node_account_ids: Option<Vec<AccountId>>,
     contract_data: ContractData,
@@ -82,6 +91,46 @@ impl ContractCreateFlow {
         Ok(self)
     }
 
+    /// Returns the artifact the bytecode of the smart contract came from.
+    #[must_use]
+    pub fn get_artifact(&self) -> Option<&ContractArtifact> {
+        self.artifact.as_ref()
+    }
+
+    /// Sets the bytecode of the smart contract to the creation bytecode of a Hardhat or Foundry build artifact.
+    ///
+    /// Use [`verify_deployed_bytecode`](Self::verify_deployed_bytecode) to also check the runtime bytecode
+    /// of the contract against the artifact's once it's deployed.
+    ///
+    /// # Errors
+    /// - [`Error::LibraryLink`] if the artifact has libraries which are yet to be [linked](ContractArtifact::link_library).
+    pub fn artifact(&mut self, artifact: ContractArtifact) -> crate::Result<&mut Self> {
+        self.bytecode = artifact.bytecode()?;
+        self.artifact = Some(artifact);
+
+        Ok(self)
+    }
+
+    /// Returns whether the runtime bytecode of the new contract is checked against the artifact's.
+    ///
+    /// The default value is `false`, this has no effect unless the bytecode came from an [`artifact`](Self::artifact).
+    #[must_use]
+    pub fn get_verify_deployed_bytecode(&self) -> bool {
+        self.verify_deployed_bytecode
+    }
+
+    /// Sets whether the runtime bytecode of the new contract is checked against the artifact's.
+    ///
+    /// When the check fails, `execute` returns [`Error::DeployedBytecodeMismatch`] with the ID of the contract,
+    /// which was still created.
+    /// The check fails for contracts with immutable variables deployed from a Hardhat artifact,
+    /// see [`ContractArtifact::matches_deployed_bytecode`].
+    pub fn verify_deployed_bytecode(&mut self, verify: bool) -> &mut Self {
+        self.verify_deployed_bytecode = verify;
+
+        self
+    }
+
     /// Returns the account IDs of the nodes the transactions may be submitted to.
     #[must_use]
     pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
@@ -131,6 +180,22 @@ impl ContractCreateFlow {
         self
     }
 
+    /// Sets the parameters to pass to the constructor by encoding `values` with the ABI of the [`artifact`](Self::artifact).
+    ///
+    /// `values` are converted to the types of the parameters with [`AbiValue::coerce_to`].
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if there is no artifact, or `values` don't fit the parameters of its constructor.
+    pub fn constructor_arguments(&mut self, values: &[AbiValue]) -> crate::Result<&mut Self> {
+        let artifact = self.artifact.as_ref().ok_or_else(|| {
+            Error::abi("encoding constructor arguments needs the ABI of an artifact, set one first")
+        })?;
+
+        self.contract_data.constructor_parameters = artifact.abi.encode_constructor(values)?;
+
+        Ok(self)
+    }
+
     /// Returns the gas limit to deploy the smart contract.
     #[must_use]
     pub fn get_gas(&self) -> u64 {
@@ -299,6 +364,15 @@ impl ContractCreateFlow {
         self
     }
 
+    /// Sets the [`Signer`] for use in the ``ContractCreateTransaction``
+    ///
+    /// Important: Only *one* signer is allowed.
+    pub fn sign_with_signer<S: Signer + 'static>(&mut self, signer: S) -> &mut Self {
+        self.contract_data.signer = Some(AnySigner::signer(signer));
+
+        self
+    }
+
     /// Generates the required transactions and executes them all.
     pub async fn execute(&self, client: &Client) -> crate::Result<TransactionResponse> {
         self.execute_with_optional_timeout(client, None).await
@@ -359,10 +433,11 @@ impl ContractCreateFlow {
         .execute_with_optional_timeout(client, timeout_per_transaction)
         .await?;
 
-        response
+        let contract_id = response
             .get_receipt_query()
             .execute_with_optional_timeout(client, timeout_per_transaction)
-            .await?;
+            .await?
+            .contract_id;
 
         // todo: Should this return `response` even if this fails?
         make_file_delete_transaction(file_id, self.node_account_ids.clone())
@@ -372,8 +447,39 @@ impl ContractCreateFlow {
             .execute_with_optional_timeout(client, timeout_per_transaction)
             .await?;
 
+        // a successful contract create always has a contract ID.
+        if let (Some(artifact), Some(contract_id), true) =
+            (&self.artifact, contract_id, self.verify_deployed_bytecode)
+        {
+            self.verify_bytecode(artifact, contract_id, client, timeout_per_transaction).await?;
+        }
+
         Ok(response)
     }
+
+    async fn verify_bytecode(
+        &self,
+        artifact: &ContractArtifact,
+        contract_id: ContractId,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<()> {
+        let mut query = ContractBytecodeQuery::new();
+
+        query.contract_id(contract_id);
+
+        if let Some(node_account_ids) = self.node_account_ids.clone() {
+            query.node_account_ids(node_account_ids);
+        }
+
+        let runtime_bytecode = query.execute_with_optional_timeout(client, timeout).await?;
+
+        if !artifact.matches_deployed_bytecode(&runtime_bytecode)? {
+            return Err(Error::DeployedBytecodeMismatch(Box::new(contract_id)));
+        }
+
+        Ok(())
+    }
 }
 
 // Not to be confused with ContractCreateTrasnactionData which is missing a couple fields.
@@ -528,7 +634,9 @@ mod tests {
     use time::Duration;
 
     use crate::{
+        AbiValue,
         AccountId,
+        ContractArtifact,
         ContractCreateFlow,
         Hbar,
         PrivateKey,
@@ -543,6 +651,38 @@ mod tests {
         assert_eq!(flow.get_bytecode(), &BYTECODE)
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn get_set_artifact() {
+        let artifact = ContractArtifact::from_json(
+            r#"{ "abi": [], "bytecode": { "object": "0x6080" }, "deployedBytecode": { "object": "0x60aa" } }"#,
+        )
+        .unwrap();
+
+        let mut flow = ContractCreateFlow::new();
+        flow.artifact(artifact.clone()).unwrap();
+
+        assert_eq!(flow.get_bytecode(), [0x60, 0x80]);
+        assert_eq!(flow.get_artifact(), Some(&artifact));
+    }
+
+    #[test]
+    fn get_set_verify_deployed_bytecode() {
+        let mut flow = ContractCreateFlow::new();
+        assert_eq!(flow.get_verify_deployed_bytecode(), false);
+
+        flow.verify_deployed_bytecode(true);
+
+        assert_eq!(flow.get_verify_deployed_bytecode(), true);
+    }
+
+    #[test]
+    fn constructor_arguments_without_artifact() {
+        let mut flow = ContractCreateFlow::new();
+
+        assert!(flow.constructor_arguments(&[AbiValue::from(1_u8)]).is_err());
+    }
+
     #[test]
     fn get_set_max_chunks() {
         let mut flow = ContractCreateFlow::new();
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::cmp::max;
 use std::str::FromStr;
 
@@ -9,6 +10,7 @@ use num_bigint::{
 
 use self::private::Sealed;
 use crate::contract::contract_function_selector::ContractFunctionSelector;
+use crate::contract::AbiValue;
 use crate::ethereum::SolidityAddress;
 
 /// Builder for encoding parameters for a Solidity contract constructor/function call.
@@ -19,11 +21,21 @@ pub struct ContractFunctionParameters {
 
 #[derive(Debug, Clone)]
 struct Argument {
-    type_name: &'static str,
+    type_name: Cow<'static, str>,
     value_bytes: Vec<u8>,
     is_dynamic: bool,
 }
 
+impl Argument {
+    fn head_len(&self) -> usize {
+        if self.is_dynamic {
+            32
+        } else {
+            self.value_bytes.len()
+        }
+    }
+}
+
 mod private {
     pub trait Sealed {}
     impl Sealed for String {}
@@ -133,13 +145,14 @@ impl ContractFunctionParameters {
     // since downstream code can just...
     // Call this with `Option<&A>` anyway if they want to keep ownership of it.
     pub fn to_bytes(&self, func_name: Option<&str>) -> Vec<u8> {
-        let mut current_dynamic_offset = self.args.len() * 32;
+        // static tuples and fixed arrays can take up more than one word of the head.
+        let mut current_dynamic_offset: usize = self.args.iter().map(Argument::head_len).sum();
         let mut arg_bytes = Vec::new();
         let mut dynamic_arg_bytes = Vec::new();
         let mut function_selector = func_name.map(ContractFunctionSelector::new);
         for arg in &self.args {
             if let Some(selector) = &mut function_selector {
-                selector.add_param_type(arg.type_name);
+                selector.add_param_type(&arg.type_name);
             }
             if arg.is_dynamic {
                 arg_bytes.extend_from_slice(
@@ -167,7 +180,7 @@ impl ContractFunctionParameters {
     /// Add a `string` argument to the `ContractFunctionParameters`
     pub fn add_string<T: AsRef<str>>(&mut self, val: T) -> &mut Self {
         self.args.push(Argument {
-            type_name: "string",
+            type_name: "string".into(),
             value_bytes: encode_dynamic_bytes(val.as_ref().as_bytes()),
             is_dynamic: true,
         });
@@ -177,7 +190,7 @@ impl ContractFunctionParameters {
     /// Add a `string[]` argument to the `ContractFunctionParameters`
     pub fn add_string_array<T: AsRef<str>>(&mut self, val: &[T]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "string[]",
+            type_name: "string[]".into(),
             value_bytes: encode_array_of_dynamic_byte_arrays(
                 val.iter().map(|s| s.as_ref().as_bytes()),
                 val.len(),
@@ -190,7 +203,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes` argument to the `ContractFunctionParameters`
     pub fn add_bytes(&mut self, val: &[u8]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes",
+            type_name: "bytes".into(),
             value_bytes: encode_dynamic_bytes(val),
             is_dynamic: true,
         });
@@ -200,7 +213,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes[]` argument to the `ContractFunctionParameters`
     pub fn add_bytes_array(&mut self, val: &[&[u8]]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes[]",
+            type_name: "bytes[]".into(),
             value_bytes: encode_array_of_dynamic_byte_arrays(val, val.len()),
             is_dynamic: true,
         });
@@ -210,7 +223,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes32` argument to the `ContractFunctionParameters`
     pub fn add_bytes32<T: AsBytes32 + ?Sized>(&mut self, val: &T) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes32",
+            type_name: "bytes32".into(),
             value_bytes: encode_array_of_32_byte(val),
             is_dynamic: false,
         });
@@ -220,7 +233,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes32[]` argument to the `ContractFunctionParameters`
     pub fn add_bytes32_array(&mut self, val: &[[u8; 32]]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes32",
+            type_name: "bytes32".into(),
             value_bytes: encode_array_of_32_byte_elements(val.iter().copied(), val.len()),
             is_dynamic: true,
         });
@@ -230,7 +243,7 @@ impl ContractFunctionParameters {
     /// Add a `bool` argument to the `ContractFunctionParameters`
     pub fn add_bool(&mut self, val: bool) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bool",
+            type_name: "bool".into(),
             value_bytes: left_pad_32_bytes(
                 // a bool in rust is guaranteed to be of value 0 or 1
                 u32::from(val).to_be_bytes().as_slice(),
@@ -247,7 +260,7 @@ impl ContractFunctionParameters {
         T: IntEncode,
     {
         self.args.push(Argument {
-            type_name,
+            type_name: type_name.into(),
             value_bytes: truncate_and_left_pad_32_bytes(val, byte_count).to_vec(),
             is_dynamic: false,
         });
@@ -264,7 +277,7 @@ impl ContractFunctionParameters {
         T: IntEncode,
     {
         self.args.push(Argument {
-            type_name,
+            type_name: type_name.into(),
             value_bytes: encode_array_of_32_byte_elements(
                 values.iter().map(|val| truncate_and_left_pad_32_bytes(val, byte_count)),
                 values.len(),
@@ -917,7 +930,7 @@ impl ContractFunctionParameters {
     /// Add an `address` argument to the `ContractFunctionParameters`
     pub fn add_address(&mut self, address: &str) -> &mut Self {
         self.args.push(Argument {
-            type_name: "address",
+            type_name: "address".into(),
             value_bytes: encode_address(address).to_vec(),
             is_dynamic: false,
         });
@@ -927,7 +940,7 @@ impl ContractFunctionParameters {
     /// Add an `address[]` argument to the `ContractFunctionParameters`
     pub fn add_address_array(&mut self, addresses: &[&str]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "address[]",
+            type_name: "address[]".into(),
             value_bytes: encode_array_of_32_byte_elements(
                 addresses.iter().map(|addr| encode_address(addr)),
                 addresses.len(),
@@ -951,12 +964,89 @@ impl ContractFunctionParameters {
         right_pad_32_bytes(&mut value_bytes);
 
         self.args.push(Argument {
-            type_name: "function",
+            type_name: "function".into(),
             value_bytes: value_bytes,
             is_dynamic: false,
         });
         self
     }
+
+    /// Add a tuple argument, such as a solidity `struct`, to the `ContractFunctionParameters`
+    ///
+    /// The components of the tuple are the arguments of `tuple`, in the order they were added.
+    pub fn add_tuple(&mut self, tuple: &ContractFunctionParameters) -> &mut Self {
+        self.args.push(Argument {
+            type_name: tuple.tuple_type_name().into(),
+            value_bytes: tuple.to_bytes(None),
+            is_dynamic: tuple.args.iter().any(|arg| arg.is_dynamic),
+        });
+        self
+    }
+
+    /// Add an array of tuples argument, such as a solidity `struct[]`, to the `ContractFunctionParameters`
+    ///
+    /// # Panics
+    /// - If `tuples` is empty, since there's no way to know the type of its elements,
+    ///   use [`add_value`](Self::add_value) for that instead.
+    /// - If the tuples don't all have the same component types.
+    pub fn add_tuple_array(&mut self, tuples: &[ContractFunctionParameters]) -> &mut Self {
+        let Some(first) = tuples.first() else {
+            panic!("cannot add an empty tuple array to ContractFunctionParameters")
+        };
+
+        let type_name = first.tuple_type_name();
+        let mut elements = ContractFunctionParameters::new();
+        for tuple in tuples {
+            let tuple_type_name = tuple.tuple_type_name();
+            assert!(
+                tuple_type_name == type_name,
+                "cannot add a `{tuple_type_name}` to an array of `{type_name}`"
+            );
+
+            elements.add_tuple(tuple);
+        }
+
+        let mut value_bytes =
+            left_pad_32_bytes(tuples.len().to_be_bytes().as_slice(), false).to_vec();
+        value_bytes.extend(elements.to_bytes(None));
+
+        self.args.push(Argument {
+            type_name: format!("{type_name}[]").into(),
+            value_bytes,
+            is_dynamic: true,
+        });
+        self
+    }
+
+    /// Add an argument of any ABI type to the `ContractFunctionParameters`
+    ///
+    /// This covers what the other functions can't, such as fixed size arrays (`uint8[4]`),
+    /// nested arrays (`string[][]`) and empty arrays of tuples.
+    ///
+    /// # Panics
+    /// If `value` doesn't fit its own type, for instance an array element of the wrong type or an integer that's out of range.
+    pub fn add_value(&mut self, value: &AbiValue) -> &mut Self {
+        self.try_add_value(value).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    pub(crate) fn try_add_value(&mut self, value: &AbiValue) -> crate::Result<&mut Self> {
+        let ty = value.abi_type();
+        let value_bytes = value.encode()?;
+
+        self.args.push(Argument {
+            type_name: ty.to_string().into(),
+            value_bytes,
+            is_dynamic: ty.is_dynamic(),
+        });
+
+        Ok(self)
+    }
+
+    /// Returns the type of a tuple made of the current arguments, for instance `(uint256,string)`.
+    fn tuple_type_name(&self) -> String {
+        let type_names: Vec<_> = self.args.iter().map(|arg| &*arg.type_name).collect();
+        format!("({})", type_names.join(","))
+    }
 }
 
 fn left_pad_32_bytes(bytes: &[u8], is_negative: bool) -> [u8; 32] {
@@ -1051,6 +1141,10 @@ mod tests {
 
     use crate::contract::contract_function_parameters::ContractFunctionParameters;
     use crate::contract::contract_function_selector::ContractFunctionSelector;
+    use crate::contract::{
+        AbiType,
+        AbiValue,
+    };
 
     #[test]
     fn misc_params() {
@@ -1271,6 +1365,72 @@ mod tests {
             .assert_eq(&buf);
     }
 
+    #[test]
+    fn tuple_params() {
+        let param_bytes = ContractFunctionParameters::new()
+            .add_tuple(ContractFunctionParameters::new().add_uint8(1).add_uint8(2))
+            .add_string("a")
+            .to_bytes(Some("foo"));
+
+        // the static tuple takes up two words of the head, so the string's offset is `0x60`.
+        assert_eq!(
+            hex::encode(param_bytes),
+            "37c27a29\
+                0000000000000000000000000000000000000000000000000000000000000001\
+                0000000000000000000000000000000000000000000000000000000000000002\
+                0000000000000000000000000000000000000000000000000000000000000060\
+                0000000000000000000000000000000000000000000000000000000000000001\
+                6100000000000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn tuple_array_params() {
+        let param_bytes = ContractFunctionParameters::new()
+            .add_tuple_array(&[
+                ContractFunctionParameters::new()
+                    .add_uint256(BigUint::from(1_u8))
+                    .add_string("a")
+                    .clone(),
+                ContractFunctionParameters::new()
+                    .add_uint256(BigUint::from(2_u8))
+                    .add_string("b")
+                    .clone(),
+            ])
+            .add_value(&AbiValue::FixedArray(
+                AbiType::Int(8),
+                vec![AbiValue::Int(8, BigInt::from(-1))],
+            ))
+            .to_bytes(Some("bar"));
+
+        assert_eq!(
+            hex::encode(param_bytes),
+            "23558fa0\
+                0000000000000000000000000000000000000000000000000000000000000040\
+                ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+                0000000000000000000000000000000000000000000000000000000000000002\
+                0000000000000000000000000000000000000000000000000000000000000040\
+                00000000000000000000000000000000000000000000000000000000000000c0\
+                0000000000000000000000000000000000000000000000000000000000000001\
+                0000000000000000000000000000000000000000000000000000000000000040\
+                0000000000000000000000000000000000000000000000000000000000000001\
+                6100000000000000000000000000000000000000000000000000000000000000\
+                0000000000000000000000000000000000000000000000000000000000000002\
+                0000000000000000000000000000000000000000000000000000000000000040\
+                0000000000000000000000000000000000000000000000000000000000000001\
+                6200000000000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn tuple_array_mismatched_panic() {
+        ContractFunctionParameters::new().add_tuple_array(&[
+            ContractFunctionParameters::new().add_uint8(1).clone(),
+            ContractFunctionParameters::new().add_string("a").clone(),
+        ]);
+    }
+
     #[test]
     #[should_panic]
     fn bytes32_panic() {
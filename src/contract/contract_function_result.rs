@@ -27,6 +27,11 @@ use num_bigint::{
     BigUint,
 };
 
+use crate::contract::{
+    AbiType,
+    AbiValue,
+    ContractRevert,
+};
 use crate::protobuf::ToProtobuf;
 use crate::{
     AccountId,
@@ -38,6 +43,8 @@ use crate::{
 
 /// The result returned by a call to a smart contract function.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractFunctionResult {
     /// The smart contract instance whose function was called.
     pub contract_id: ContractId,
@@ -46,12 +53,14 @@ pub struct ContractFunctionResult {
     pub evm_address: Option<ContractId>,
 
     /// The raw bytes returned by the function.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::bytes"))]
     pub bytes: Vec<u8>,
 
     /// Message if there was an error during smart contract execution.
     pub error_message: Option<String>,
 
     /// Bloom filter for record.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::bytes"))]
     pub bloom: Vec<u8>,
 
     /// Units of gas used to execute contract.
@@ -64,6 +73,7 @@ pub struct ContractFunctionResult {
     pub hbar_amount: u64,
 
     /// The parameters passed into the contract call.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::bytes"))]
     pub contract_function_parameters_bytes: Vec<u8>,
 
     /// The account that is the "sender." If not present it is the accountId from the transactionId.
@@ -223,6 +233,39 @@ impl ContractFunctionResult {
     pub fn get_i256(&self, index: usize) -> Option<BigInt> {
         self.get_bytes32(index).map(|it| BigInt::from_signed_bytes_be(it))
     }
+
+    /// Get the value at `index` as a value of any solidity type, such as a fixed size array or an array of tuples.
+    ///
+    /// Like the other getters, `index` counts 32 byte slots, so a static tuple or fixed size array
+    /// before `index` takes up one slot per word of its encoding.
+    #[must_use]
+    pub fn get_value(&self, index: usize, ty: &AbiType) -> Option<AbiValue> {
+        AbiValue::decode_head(ty, &self.bytes, index * Self::SLOT_SIZE).ok()
+    }
+
+    /// Get the value at `index` as a solidity tuple (`struct`) made of `components`.
+    #[must_use]
+    pub fn get_tuple(&self, index: usize, components: &[AbiType]) -> Option<Vec<AbiValue>> {
+        match self.get_value(index, &AbiType::Tuple(components.to_vec()))? {
+            AbiValue::Tuple(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Get the whole function result as a sequence of values of `types`, such as the return types of the function.
+    #[must_use]
+    pub fn get_values(&self, types: &[AbiType]) -> Option<Vec<AbiValue>> {
+        AbiValue::decode_params(types, &self.bytes).ok()
+    }
+
+    /// Get what the call reverted with, or `None` if it didn't fail.
+    ///
+    /// [`ContractRevert::reason`] decodes `Error(string)` and `Panic(uint256)` reverts,
+    /// and [`ContractRevert::decode`] decodes custom errors given the contract's ABI.
+    #[must_use]
+    pub fn revert(&self) -> Option<ContractRevert> {
+        ContractRevert::from_result(self)
+    }
 }
 
 impl FromProtobuf<services::ContractFunctionResult> for ContractFunctionResult {
@@ -323,6 +366,10 @@ mod tests {
         BigUint,
     };
 
+    use crate::contract::{
+        AbiType,
+        AbiValue,
+    };
     use crate::protobuf::{
         FromProtobuf,
         ToProtobuf,
@@ -332,6 +379,7 @@ mod tests {
         ContractFunctionResult,
         ContractId,
         ContractNonceInfo,
+        EvmAddress,
     };
 
     const CALL_RESULT: [u8; 320] = hex!(
@@ -384,6 +432,20 @@ mod tests {
         "72616E646F6D2062797465732032000000000000decaff000000000000000000"
     );
 
+    // `((uint8,address),(uint256,string))`
+    const TUPLE_RESULT: [u8; 224] = hex!(
+        // static tuple, inline at slots 0 and 1
+        "0000000000000000000000000000000000000000000000000000000000000001"
+        "00000000000000000000000000000000000000000000000000000000000004d2"
+        // reference to the dynamic tuple at slot 3
+        "0000000000000000000000000000000000000000000000000000000000000060"
+        // the dynamic tuple, its string's offset is relative to the start of the tuple
+        "0000000000000000000000000000000000000000000000000000000000000002"
+        "0000000000000000000000000000000000000000000000000000000000000040"
+        "0000000000000000000000000000000000000000000000000000000000000005"
+        "68656c6c6f000000000000000000000000000000000000000000000000000000"
+    );
+
     #[test]
     fn evm_address() {
         const EVM_ADDRESS: [u8; 20] = hex!("98329e006610472e6b372c080833f6d79ed833cf");
@@ -502,4 +564,39 @@ mod tests {
         assert_eq!(strings[0], "random bytes");
         assert_eq!(strings[1], "random bytes 2")
     }
+
+    #[test]
+    fn tuple_results() {
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            contract_call_result: TUPLE_RESULT.to_vec(),
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result).unwrap();
+
+        let first = [AbiType::Uint(8), AbiType::Address];
+        let second = [AbiType::Uint(256), AbiType::String];
+
+        let first_values = vec![
+            AbiValue::Uint(8, BigUint::from(1_u8)),
+            AbiValue::Address(EvmAddress::from(hex!("00000000000000000000000000000000000004d2"))),
+        ];
+        let second_values =
+            vec![AbiValue::Uint(256, BigUint::from(2_u8)), AbiValue::String("hello".to_owned())];
+
+        assert_eq!(result.get_tuple(0, &first).unwrap(), first_values);
+        assert_eq!(result.get_tuple(2, &second).unwrap(), second_values);
+        assert_eq!(result.get_u8(0).unwrap(), 1);
+
+        assert_eq!(
+            result
+                .get_values(&[AbiType::Tuple(first.to_vec()), AbiType::Tuple(second.to_vec())])
+                .unwrap(),
+            [AbiValue::Tuple(first_values), AbiValue::Tuple(second_values)]
+        );
+
+        // the string isn't a `uint256[]`, so its length is out of bounds.
+        assert_eq!(result.get_value(2, &"(uint256,uint256[])".parse().unwrap()), None);
+    }
 }
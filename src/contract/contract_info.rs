@@ -36,6 +36,8 @@ use crate::{
 
 /// Current information on a smart contract instance.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractInfo {
     /// ID of the contract instance, in the format used by transactions.
     pub contract_id: ContractId,
@@ -52,9 +54,11 @@ pub struct ContractInfo {
     pub admin_key: Option<Key>,
 
     /// The current time at which this contract instance (and its account) is set to expire.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_impls::timestamp::option"))]
     pub expiration_time: Option<OffsetDateTime>,
 
     /// The auto renew period for this contract instance.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_impls::duration::option"))]
     pub auto_renew_period: Option<Duration>,
 
     /// Number of bytes of storage being used by this instance.
@@ -4,22 +4,32 @@ use crate::protobuf::{
     FromProtobuf,
     ToProtobuf,
 };
-use crate::ContractId;
+use crate::{
+    AbiEvent,
+    ContractAbi,
+    ContractId,
+    NamedAbiValues,
+};
 
 /// The log information for an event returned by a smart contract function call.
 /// One function call may return several such events.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractLogInfo {
     /// Address of the contract that emitted the event.
     pub contract_id: ContractId,
 
     /// Bloom filter for this log.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::bytes"))]
     pub bloom: Vec<u8>,
 
     /// A list of topics this log is relevent to.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::bytes::vec"))]
     pub topics: Vec<Vec<u8>>,
 
     /// The log's data payload.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::bytes"))]
     pub data: Vec<u8>,
 }
 
@@ -38,6 +48,17 @@ impl ContractLogInfo {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Decodes this log as an instance of one of the events in `abi`, see [`ContractAbi::decode_log`].
+    ///
+    /// # Errors
+    /// - [`Error::Abi`](crate::Error::Abi) if no event in `abi` matches this log.
+    pub fn decode_event<'a>(
+        &self,
+        abi: &'a ContractAbi,
+    ) -> crate::Result<(&'a AbiEvent, NamedAbiValues)> {
+        abi.decode_log(self)
+    }
 }
 
 impl FromProtobuf<services::ContractLoginfo> for ContractLogInfo {
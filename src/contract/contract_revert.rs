@@ -0,0 +1,432 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+
+use hedera_proto::services;
+
+use crate::contract::{
+    AbiType,
+    AbiValue,
+    ContractAbi,
+    NamedAbiValues,
+};
+use crate::{
+    ContractFunctionResult,
+    FromProtobuf,
+};
+
+/// Selector of the builtin `Error(string)` error, used by `revert("reason")` and `require(cond, "reason")`.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Selector of the builtin `Panic(uint256)` error, used by failing assertions, overflows, and the like.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// The data a smart contract call reverted with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractRevert {
+    /// The error message the network reported for the call.
+    pub error_message: String,
+
+    /// The raw revert data, starting with the selector of the error, empty if the contract reverted without any.
+    pub data: Vec<u8>,
+}
+
+/// Why a smart contract call reverted, decoded from a [`ContractRevert`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContractRevertReason {
+    /// `revert("reason")` or `require(cond, "reason")`.
+    Error(String),
+
+    /// A Solidity panic, such as a failed `assert` or an arithmetic overflow.
+    ///
+    /// See [`panic_description`](Self::panic_description) for what the code means.
+    Panic(u64),
+
+    /// A custom error declared in the contract's ABI.
+    Custom {
+        /// The name of the error.
+        name: String,
+
+        /// The parameters of the error.
+        values: NamedAbiValues,
+    },
+
+    /// The revert data is empty, or isn't anything known.
+    Unknown,
+}
+
+impl ContractRevert {
+    /// Returns the revert of `result`, or `None` if the call didn't fail.
+    pub(crate) fn from_result(result: &ContractFunctionResult) -> Option<Self> {
+        let error_message = result.error_message.as_ref()?;
+
+        // the network reports the revert data as hex in the error message, when there is any.
+        let data = error_message
+            .strip_prefix("0x")
+            .and_then(|it| hex::decode(it).ok())
+            .filter(|it| !it.is_empty())
+            .unwrap_or_else(|| revert_data(&result.bytes));
+
+        Some(Self { error_message: error_message.clone(), data })
+    }
+
+    /// Returns the revert carried by a failed `ContractCallQuery` response, if any.
+    pub(crate) fn from_response(response: &services::Response) -> Option<Box<Self>> {
+        let Some(services::response::Response::ContractCallLocal(response)) = &response.response
+        else {
+            return None;
+        };
+
+        let result =
+            ContractFunctionResult::from_protobuf(response.function_result.clone()?).ok()?;
+
+        Self::from_result(&result).map(Box::new)
+    }
+
+    /// Decodes the reason for the revert, if it's a builtin `Error(string)` or `Panic(uint256)`.
+    ///
+    /// Use [`decode`](Self::decode) to decode custom errors as well.
+    #[must_use]
+    pub fn reason(&self) -> ContractRevertReason {
+        if let Some(params) = self.data.strip_prefix(&ERROR_SELECTOR) {
+            if let Ok(AbiValue::String(reason)) = decode_single(AbiType::String, params) {
+                return ContractRevertReason::Error(reason);
+            }
+        }
+
+        if let Some(params) = self.data.strip_prefix(&PANIC_SELECTOR) {
+            if let Ok(AbiValue::Uint(_, code)) = decode_single(AbiType::Uint(256), params) {
+                if let Ok(code) = u64::try_from(&code) {
+                    return ContractRevertReason::Panic(code);
+                }
+            }
+        }
+
+        ContractRevertReason::Unknown
+    }
+
+    /// Decodes the reason for the revert, trying the custom errors declared in `abi` after the builtin ones.
+    #[must_use]
+    pub fn decode(&self, abi: &ContractAbi) -> ContractRevertReason {
+        match self.reason() {
+            ContractRevertReason::Unknown => match abi.decode_error(&self.data) {
+                Ok((error, values)) => {
+                    ContractRevertReason::Custom { name: error.name.clone(), values }
+                }
+                Err(_) => ContractRevertReason::Unknown,
+            },
+            reason => reason,
+        }
+    }
+}
+
+impl fmt::Display for ContractRevert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.reason() {
+            ContractRevertReason::Unknown if self.data.is_empty() => {
+                f.write_str(&self.error_message)
+            }
+            ContractRevertReason::Unknown => {
+                write!(f, "reverted with unknown data 0x{}", hex::encode(&self.data))
+            }
+            reason => write!(f, "{reason}"),
+        }
+    }
+}
+
+impl ContractRevertReason {
+    /// Returns what a [`Panic`](Self::Panic) code means, or `None` if this isn't a panic, or the code is unknown.
+    #[must_use]
+    pub fn panic_description(&self) -> Option<&'static str> {
+        let Self::Panic(code) = self else {
+            return None;
+        };
+
+        let description = match code {
+            0x00 => "generic compiler inserted panic",
+            0x01 => "assertion failed",
+            0x11 => "arithmetic overflow or underflow",
+            0x12 => "division or modulo by zero",
+            0x21 => "conversion to an invalid enum value",
+            0x22 => "access to an incorrectly encoded storage byte array",
+            0x31 => "`pop()` on an empty array",
+            0x32 => "array index out of bounds",
+            0x41 => "allocated too much memory or created too large an array",
+            0x51 => "called a zero-initialized internal function",
+            _ => return None,
+        };
+
+        Some(description)
+    }
+}
+
+impl fmt::Display for ContractRevertReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error(reason) => write!(f, "reverted with reason {reason:?}"),
+            Self::Panic(code) => {
+                write!(f, "panicked with code {code:#04x}")?;
+                if let Some(description) = self.panic_description() {
+                    write!(f, " ({description})")?;
+                }
+
+                Ok(())
+            }
+            Self::Custom { name, values } => {
+                write!(f, "reverted with error {name}(")?;
+                for (i, (name, value)) in values.names().iter().zip(values.values()).enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    if !name.is_empty() {
+                        write!(f, "{name}: ")?;
+                    }
+
+                    write!(f, "{value}")?;
+                }
+
+                f.write_str(")")
+            }
+            Self::Unknown => f.write_str("reverted for an unknown reason"),
+        }
+    }
+}
+
+fn decode_single(ty: AbiType, params: &[u8]) -> crate::Result<AbiValue> {
+    AbiValue::decode_params(&[ty], params)?
+        .pop()
+        .ok_or_else(|| crate::Error::abi("expected a single value"))
+}
+
+/// Recovers the revert data from the bytes of a failed call.
+///
+/// [`ContractFunctionResult`] strips the `Error(string)` selector off of its bytes,
+/// so bytes that are exactly an encoded string get it back.
+fn revert_data(bytes: &[u8]) -> Vec<u8> {
+    let is_stripped_error =
+        bytes.len() >= 64 && bytes.len() % 32 == 0 && decode_single(AbiType::String, bytes).is_ok();
+
+    if is_stripped_error {
+        [&ERROR_SELECTOR[..], bytes].concat()
+    } else {
+        bytes.to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use expect_test::expect;
+    use hedera_proto::services;
+    use hex_literal::hex;
+
+    use super::{
+        ContractRevert,
+        ContractRevertReason,
+    };
+    use crate::contract::{
+        AbiError,
+        AbiParam,
+        AbiType,
+        AbiValue,
+        ContractAbi,
+    };
+    use crate::execute::Execute;
+    use crate::protobuf::ToProtobuf;
+    use crate::{
+        ContractCallQuery,
+        ContractFunctionResult,
+        ContractId,
+        Error,
+        Status,
+        TransactionId,
+    };
+
+    // `Error("Not enough Ether provided.")`
+    const ERROR_DATA: [u8; 100] = hex!(
+        "08c379a0"
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "000000000000000000000000000000000000000000000000000000000000001a"
+        "4e6f7420656e6f7567682045746865722070726f76696465642e000000000000"
+    );
+
+    fn revert(data: &[u8]) -> ContractRevert {
+        ContractRevert { error_message: "CONTRACT_REVERT_EXECUTED".to_owned(), data: data.to_vec() }
+    }
+
+    fn failed_result(bytes: &[u8], error_message: &str) -> ContractFunctionResult {
+        ContractFunctionResult {
+            contract_id: ContractId::new(0, 0, 1001),
+            evm_address: None,
+            bytes: bytes.to_vec(),
+            error_message: Some(error_message.to_owned()),
+            bloom: Vec::new(),
+            gas_used: 0,
+            gas: 0,
+            hbar_amount: 0,
+            contract_function_parameters_bytes: Vec::new(),
+            sender_account_id: None,
+            logs: Vec::new(),
+            contract_nonces: Vec::new(),
+            signer_nonce: None,
+        }
+    }
+
+    #[test]
+    fn error_reason() {
+        let revert = revert(&ERROR_DATA);
+
+        assert_eq!(
+            revert.reason(),
+            ContractRevertReason::Error("Not enough Ether provided.".to_owned())
+        );
+
+        expect![[r#"reverted with reason "Not enough Ether provided.""#]]
+            .assert_eq(&revert.to_string());
+    }
+
+    #[test]
+    fn panic_reason() {
+        let revert = revert(&hex!(
+            "4e487b71"
+            "0000000000000000000000000000000000000000000000000000000000000011"
+        ));
+
+        let reason = revert.reason();
+        assert_eq!(reason, ContractRevertReason::Panic(0x11));
+        assert_eq!(reason.panic_description(), Some("arithmetic overflow or underflow"));
+
+        expect!["panicked with code 0x11 (arithmetic overflow or underflow)"]
+            .assert_eq(&revert.to_string());
+    }
+
+    #[test]
+    fn custom_reason() {
+        let abi = ContractAbi {
+            errors: vec![AbiError {
+                name: "InsufficientBalance".to_owned(),
+                inputs: vec![
+                    AbiParam {
+                        name: "available".to_owned(),
+                        ty: AbiType::Uint(256),
+                        indexed: false,
+                    },
+                    AbiParam {
+                        name: "required".to_owned(),
+                        ty: AbiType::Uint(256),
+                        indexed: false,
+                    },
+                ],
+            }],
+            ..ContractAbi::default()
+        };
+
+        assert_eq!(abi.errors[0].selector(), hex!("cf479181"));
+
+        let revert = revert(&hex!(
+            "cf479181"
+            "0000000000000000000000000000000000000000000000000000000000000005"
+            "000000000000000000000000000000000000000000000000000000000000000a"
+        ));
+
+        assert_eq!(revert.reason(), ContractRevertReason::Unknown);
+
+        let reason = revert.decode(&abi);
+        let ContractRevertReason::Custom { name, values } = &reason else {
+            panic!("expected a custom error, got {reason:?}");
+        };
+
+        assert_eq!(name, "InsufficientBalance");
+        assert_eq!(values.get("required"), Some(&AbiValue::from(10_u8)));
+
+        expect!["reverted with error InsufficientBalance(available: 5, required: 10)"]
+            .assert_eq(&reason.to_string());
+    }
+
+    #[test]
+    fn from_result() {
+        // the network reports the revert data in the error message
+        let message = format!("0x{}", hex::encode(ERROR_DATA));
+        let revert = ContractRevert::from_result(&failed_result(&[], &message)).unwrap();
+        assert_eq!(revert.data, ERROR_DATA);
+
+        // `ContractFunctionResult` strips the `Error(string)` selector
+        let revert = ContractRevert::from_result(&failed_result(
+            &ERROR_DATA[4..],
+            "CONTRACT_REVERT_EXECUTED",
+        ))
+        .unwrap();
+        assert_eq!(revert.data, ERROR_DATA);
+
+        let revert =
+            ContractRevert::from_result(&failed_result(&[], "CONTRACT_REVERT_EXECUTED")).unwrap();
+        assert_eq!(revert.reason(), ContractRevertReason::Unknown);
+        expect!["CONTRACT_REVERT_EXECUTED"].assert_eq(&revert.to_string());
+    }
+
+    #[test]
+    fn from_query_response() {
+        let response = services::Response {
+            response: Some(services::response::Response::ContractCallLocal(
+                services::ContractCallLocalResponse {
+                    function_result: Some(
+                        failed_result(&ERROR_DATA[4..], "CONTRACT_REVERT_EXECUTED").to_protobuf(),
+                    ),
+                    ..Default::default()
+                },
+            )),
+        };
+
+        let transaction_id: TransactionId = "0.0.2@1554158542.000000000".parse().unwrap();
+
+        let error = ContractCallQuery::new().make_error_pre_check(
+            Status::ContractRevertExecuted,
+            Some(&transaction_id),
+            response,
+        );
+
+        assert_matches!(
+            error,
+            Error::ContractReverted { status: Status::ContractRevertExecuted, .. }
+        );
+        assert_eq!(
+            error.contract_revert().map(ContractRevert::reason),
+            Some(ContractRevertReason::Error("Not enough Ether provided.".to_owned()))
+        );
+        assert!(error
+            .to_string()
+            .ends_with(r#": reverted with reason "Not enough Ether provided.""#));
+
+        // any other kind of response doesn't carry a revert.
+        let error = ContractCallQuery::new().make_error_pre_check(
+            Status::ContractRevertExecuted,
+            Some(&transaction_id),
+            services::Response { response: None },
+        );
+
+        assert_matches!(
+            error,
+            Error::QueryPaymentPreCheckStatus { status: Status::ContractRevertExecuted, .. }
+        );
+        assert!(error.contract_revert().is_none());
+    }
+}
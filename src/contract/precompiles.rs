@@ -0,0 +1,672 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use num_bigint::BigUint;
+
+use crate::contract::{
+    AbiType,
+    AbiValue,
+};
+use crate::{
+    AccountId,
+    ContractFunctionParameters,
+    ContractFunctionResult,
+    ContractId,
+    Error,
+    Hbar,
+    Status,
+    TokenId,
+    TokenType,
+};
+
+/// Call data for, and results of, the Hedera Token Service system contract at `0x167`.
+///
+/// Every function returns the call data to pass to
+/// [`ContractExecuteTransaction::function_parameters`](crate::ContractExecuteTransaction::function_parameters)
+/// or [`ContractCallQuery::function_parameters`](crate::ContractCallQuery::function_parameters),
+/// with [`CONTRACT_ID`](Self::CONTRACT_ID) as the contract to call.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct TokenServicePrecompile;
+
+/// Call data for, and results of, the exchange rate system contract at `0x168`.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct ExchangeRatePrecompile;
+
+/// Call data for, and results of, the pseudorandom number generator system contract at `0x169`.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct PrngPrecompile;
+
+/// The result of a `mintToken` call to the [`TokenServicePrecompile`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MintTokenResult {
+    /// The status of the mint.
+    pub status: Status,
+
+    /// The total supply of the token after the mint.
+    pub new_total_supply: u64,
+
+    /// The serial numbers of the minted NFTs, empty for fungible tokens.
+    pub serial_numbers: Vec<i64>,
+}
+
+/// The result of a `burnToken` call to the [`TokenServicePrecompile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BurnTokenResult {
+    /// The status of the burn.
+    pub status: Status,
+
+    /// The total supply of the token after the burn.
+    pub new_total_supply: u64,
+}
+
+impl TokenServicePrecompile {
+    /// The ID of the Hedera Token Service system contract.
+    pub const CONTRACT_ID: ContractId = ContractId::new(0, 0, 0x167);
+
+    /// Returns the call data for `associateToken(address,address)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn associate_token(account_id: AccountId, token_id: TokenId) -> crate::Result<Vec<u8>> {
+        Ok(ContractFunctionParameters::new()
+            .add_address(&account_address(account_id)?)
+            .add_address(&token_id.to_solidity_address()?)
+            .to_bytes(Some("associateToken")))
+    }
+
+    /// Returns the call data for `associateTokens(address,address[])`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn associate_tokens(
+        account_id: AccountId,
+        token_ids: &[TokenId],
+    ) -> crate::Result<Vec<u8>> {
+        let token_addresses = token_addresses(token_ids)?;
+
+        Ok(ContractFunctionParameters::new()
+            .add_address(&account_address(account_id)?)
+            .add_address_array(&token_addresses.iter().map(String::as_str).collect::<Vec<_>>())
+            .to_bytes(Some("associateTokens")))
+    }
+
+    /// Returns the call data for `dissociateToken(address,address)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn dissociate_token(account_id: AccountId, token_id: TokenId) -> crate::Result<Vec<u8>> {
+        Ok(ContractFunctionParameters::new()
+            .add_address(&account_address(account_id)?)
+            .add_address(&token_id.to_solidity_address()?)
+            .to_bytes(Some("dissociateToken")))
+    }
+
+    /// Returns the call data for `dissociateTokens(address,address[])`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn dissociate_tokens(
+        account_id: AccountId,
+        token_ids: &[TokenId],
+    ) -> crate::Result<Vec<u8>> {
+        let token_addresses = token_addresses(token_ids)?;
+
+        Ok(ContractFunctionParameters::new()
+            .add_address(&account_address(account_id)?)
+            .add_address_array(&token_addresses.iter().map(String::as_str).collect::<Vec<_>>())
+            .to_bytes(Some("dissociateTokens")))
+    }
+
+    /// Returns the call data for `transferToken(address,address,address,int64)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn transfer_token(
+        token_id: TokenId,
+        sender: AccountId,
+        receiver: AccountId,
+        amount: i64,
+    ) -> crate::Result<Vec<u8>> {
+        Ok(ContractFunctionParameters::new()
+            .add_address(&token_id.to_solidity_address()?)
+            .add_address(&account_address(sender)?)
+            .add_address(&account_address(receiver)?)
+            .add_int64(amount)
+            .to_bytes(Some("transferToken")))
+    }
+
+    /// Returns the call data for `transferNFT(address,address,address,int64)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn transfer_nft(
+        token_id: TokenId,
+        sender: AccountId,
+        receiver: AccountId,
+        serial_number: i64,
+    ) -> crate::Result<Vec<u8>> {
+        Ok(ContractFunctionParameters::new()
+            .add_address(&token_id.to_solidity_address()?)
+            .add_address(&account_address(sender)?)
+            .add_address(&account_address(receiver)?)
+            .add_int64(serial_number)
+            .to_bytes(Some("transferNFT")))
+    }
+
+    /// Returns the call data for `mintToken(address,int64,bytes[])`.
+    ///
+    /// `amount` is for fungible tokens, `metadata` has one entry per NFT to mint.
+    /// Decode the result with [`decode_mint_token`](Self::decode_mint_token).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn mint_token(
+        token_id: TokenId,
+        amount: i64,
+        metadata: &[&[u8]],
+    ) -> crate::Result<Vec<u8>> {
+        Ok(ContractFunctionParameters::new()
+            .add_address(&token_id.to_solidity_address()?)
+            .add_int64(amount)
+            .add_bytes_array(metadata)
+            .to_bytes(Some("mintToken")))
+    }
+
+    /// Returns the call data for `burnToken(address,int64,int64[])`.
+    ///
+    /// `amount` is for fungible tokens, `serial_numbers` are the NFTs to burn.
+    /// Decode the result with [`decode_burn_token`](Self::decode_burn_token).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn burn_token(
+        token_id: TokenId,
+        amount: i64,
+        serial_numbers: &[i64],
+    ) -> crate::Result<Vec<u8>> {
+        Ok(ContractFunctionParameters::new()
+            .add_address(&token_id.to_solidity_address()?)
+            .add_int64(amount)
+            .add_int64_array(serial_numbers)
+            .to_bytes(Some("burnToken")))
+    }
+
+    /// Returns the call data for `approve(address,address,uint256)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn approve(token_id: TokenId, spender: AccountId, amount: u64) -> crate::Result<Vec<u8>> {
+        Ok(ContractFunctionParameters::new()
+            .add_address(&token_id.to_solidity_address()?)
+            .add_address(&account_address(spender)?)
+            .add_uint256(BigUint::from(amount))
+            .to_bytes(Some("approve")))
+    }
+
+    /// Returns the call data for `wipeTokenAccount(address,address,int64)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn wipe_token_account(
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+    ) -> crate::Result<Vec<u8>> {
+        Ok(ContractFunctionParameters::new()
+            .add_address(&token_id.to_solidity_address()?)
+            .add_address(&account_address(account_id)?)
+            .add_int64(amount)
+            .to_bytes(Some("wipeTokenAccount")))
+    }
+
+    /// Returns the call data for `freezeToken(address,address)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn freeze_token(token_id: TokenId, account_id: AccountId) -> crate::Result<Vec<u8>> {
+        token_and_account("freezeToken", token_id, account_id)
+    }
+
+    /// Returns the call data for `unfreezeToken(address,address)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn unfreeze_token(token_id: TokenId, account_id: AccountId) -> crate::Result<Vec<u8>> {
+        token_and_account("unfreezeToken", token_id, account_id)
+    }
+
+    /// Returns the call data for `grantTokenKyc(address,address)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn grant_token_kyc(token_id: TokenId, account_id: AccountId) -> crate::Result<Vec<u8>> {
+        token_and_account("grantTokenKyc", token_id, account_id)
+    }
+
+    /// Returns the call data for `revokeTokenKyc(address,address)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if an ID can't be converted to a solidity address.
+    pub fn revoke_token_kyc(token_id: TokenId, account_id: AccountId) -> crate::Result<Vec<u8>> {
+        token_and_account("revokeTokenKyc", token_id, account_id)
+    }
+
+    /// Returns the call data for `pauseToken(address)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `token_id` can't be converted to a solidity address.
+    pub fn pause_token(token_id: TokenId) -> crate::Result<Vec<u8>> {
+        token_only("pauseToken", token_id)
+    }
+
+    /// Returns the call data for `unpauseToken(address)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `token_id` can't be converted to a solidity address.
+    pub fn unpause_token(token_id: TokenId) -> crate::Result<Vec<u8>> {
+        token_only("unpauseToken", token_id)
+    }
+
+    /// Returns the call data for `deleteToken(address)`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `token_id` can't be converted to a solidity address.
+    pub fn delete_token(token_id: TokenId) -> crate::Result<Vec<u8>> {
+        token_only("deleteToken", token_id)
+    }
+
+    /// Returns the call data for `isToken(address)`.
+    ///
+    /// Decode the result with [`decode_is_token`](Self::decode_is_token).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `token_id` can't be converted to a solidity address.
+    pub fn is_token(token_id: TokenId) -> crate::Result<Vec<u8>> {
+        token_only("isToken", token_id)
+    }
+
+    /// Returns the call data for `getTokenType(address)`.
+    ///
+    /// Decode the result with [`decode_token_type`](Self::decode_token_type).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `token_id` can't be converted to a solidity address.
+    pub fn get_token_type(token_id: TokenId) -> crate::Result<Vec<u8>> {
+        token_only("getTokenType", token_id)
+    }
+
+    /// Decodes the response code every Hedera Token Service function returns first.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `result` doesn't start with a response code.
+    /// - [`Error::ResponseStatusUnrecognized`] if the response code isn't a known [`Status`].
+    pub fn decode_response_code(result: &ContractFunctionResult) -> crate::Result<Status> {
+        let code = result.get_i64(0).ok_or_else(|| Error::abi("missing response code"))?;
+
+        let code = i32::try_from(code).map_err(|_| Error::abi("response code out of range"))?;
+
+        Status::try_from(code).map_err(|_| Error::ResponseStatusUnrecognized(code))
+    }
+
+    /// Decodes the result of a `mintToken` call.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `result` isn't a `mintToken` result.
+    /// - [`Error::ResponseStatusUnrecognized`] if the response code isn't a known [`Status`].
+    pub fn decode_mint_token(result: &ContractFunctionResult) -> crate::Result<MintTokenResult> {
+        let status = Self::decode_response_code(result)?;
+
+        let values = result
+            .get_values(&[
+                AbiType::Int(64),
+                AbiType::Int(64),
+                AbiType::Array(Box::new(AbiType::Int(64))),
+            ])
+            .ok_or_else(|| Error::abi("expected the results of `mintToken`"))?;
+
+        let serial_numbers = values[2]
+            .as_slice()
+            .unwrap_or_default()
+            .iter()
+            .map(as_i64)
+            .collect::<crate::Result<_>>()?;
+
+        Ok(MintTokenResult { status, new_total_supply: as_u64(&values[1])?, serial_numbers })
+    }
+
+    /// Decodes the result of a `burnToken` call.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `result` isn't a `burnToken` result.
+    /// - [`Error::ResponseStatusUnrecognized`] if the response code isn't a known [`Status`].
+    pub fn decode_burn_token(result: &ContractFunctionResult) -> crate::Result<BurnTokenResult> {
+        let status = Self::decode_response_code(result)?;
+
+        let new_total_supply = result
+            .get_value(1, &AbiType::Int(64))
+            .ok_or_else(|| Error::abi("expected the results of `burnToken`"))?;
+
+        Ok(BurnTokenResult { status, new_total_supply: as_u64(&new_total_supply)? })
+    }
+
+    /// Decodes the result of an `isToken` call.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `result` isn't an `isToken` result.
+    /// - [`Error::ResponseStatusUnrecognized`] if the response code isn't a known [`Status`].
+    pub fn decode_is_token(result: &ContractFunctionResult) -> crate::Result<(Status, bool)> {
+        let status = Self::decode_response_code(result)?;
+
+        let is_token =
+            result.get_bool(1).ok_or_else(|| Error::abi("expected the results of `isToken`"))?;
+
+        Ok((status, is_token))
+    }
+
+    /// Decodes the result of a `getTokenType` call.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `result` isn't a `getTokenType` result, or the token type is unknown.
+    /// - [`Error::ResponseStatusUnrecognized`] if the response code isn't a known [`Status`].
+    pub fn decode_token_type(
+        result: &ContractFunctionResult,
+    ) -> crate::Result<(Status, TokenType)> {
+        let status = Self::decode_response_code(result)?;
+
+        let token_type = match result.get_i32(1) {
+            Some(0) => TokenType::FungibleCommon,
+            Some(1) => TokenType::NonFungibleUnique,
+            Some(other) => return Err(Error::abi(format!("unknown token type `{other}`"))),
+            None => return Err(Error::abi("expected the results of `getTokenType`")),
+        };
+
+        Ok((status, token_type))
+    }
+
+    /// Decodes the result of a function which creates a token, such as `createFungibleToken`,
+    /// returning the ID of the new token.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `result` isn't the result of creating a token.
+    /// - [`Error::ResponseStatusUnrecognized`] if the response code isn't a known [`Status`].
+    pub fn decode_create_token(
+        result: &ContractFunctionResult,
+    ) -> crate::Result<(Status, TokenId)> {
+        let status = Self::decode_response_code(result)?;
+
+        let address = result
+            .get_address(1)
+            .ok_or_else(|| Error::abi("expected the results of creating a token"))?;
+
+        Ok((status, TokenId::from_solidity_address(&address)?))
+    }
+}
+
+impl ExchangeRatePrecompile {
+    /// The ID of the exchange rate system contract.
+    pub const CONTRACT_ID: ContractId = ContractId::new(0, 0, 0x168);
+
+    /// Returns the call data for `tinycentsToTinybars(uint256)`.
+    ///
+    /// Decode the result with [`decode_tinybars`](Self::decode_tinybars).
+    #[must_use]
+    pub fn tinycents_to_tinybars(tinycents: u64) -> Vec<u8> {
+        ContractFunctionParameters::new()
+            .add_uint256(BigUint::from(tinycents))
+            .to_bytes(Some("tinycentsToTinybars"))
+    }
+
+    /// Returns the call data for `tinybarsToTinycents(uint256)`.
+    ///
+    /// Decode the result with [`decode_tinycents`](Self::decode_tinycents).
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `amount` is negative.
+    pub fn tinybars_to_tinycents(amount: Hbar) -> crate::Result<Vec<u8>> {
+        let tinybars = u64::try_from(amount.to_tinybars())
+            .map_err(|_| Error::abi("can't convert a negative amount to tinycents"))?;
+
+        Ok(ContractFunctionParameters::new()
+            .add_uint256(BigUint::from(tinybars))
+            .to_bytes(Some("tinybarsToTinycents")))
+    }
+
+    /// Decodes the result of a `tinycentsToTinybars` call.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `result` isn't an amount of tinybars.
+    pub fn decode_tinybars(result: &ContractFunctionResult) -> crate::Result<Hbar> {
+        let tinybars = decode_u64(result)?;
+
+        let tinybars = i64::try_from(tinybars).map_err(|_| Error::abi("tinybars out of range"))?;
+
+        Ok(Hbar::from_tinybars(tinybars))
+    }
+
+    /// Decodes the result of a `tinybarsToTinycents` call.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `result` isn't an amount of tinycents.
+    pub fn decode_tinycents(result: &ContractFunctionResult) -> crate::Result<u64> {
+        decode_u64(result)
+    }
+}
+
+impl PrngPrecompile {
+    /// The ID of the pseudorandom number generator system contract.
+    pub const CONTRACT_ID: ContractId = ContractId::new(0, 0, 0x169);
+
+    /// Returns the call data for `getPseudorandomSeed()`.
+    ///
+    /// Decode the result with [`decode_pseudorandom_seed`](Self::decode_pseudorandom_seed).
+    #[must_use]
+    pub fn get_pseudorandom_seed() -> Vec<u8> {
+        ContractFunctionParameters::new().to_bytes(Some("getPseudorandomSeed"))
+    }
+
+    /// Decodes the result of a `getPseudorandomSeed` call.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `result` isn't a 32 byte seed.
+    pub fn decode_pseudorandom_seed(result: &ContractFunctionResult) -> crate::Result<[u8; 32]> {
+        result.get_bytes32(0).copied().ok_or_else(|| Error::abi("expected a 32 byte seed"))
+    }
+}
+
+/// Returns the address of `account_id`, which is its EVM address if it has one.
+fn account_address(account_id: AccountId) -> crate::Result<String> {
+    match account_id.evm_address {
+        Some(evm_address) => Ok(hex::encode(evm_address.to_bytes())),
+        None => account_id.to_solidity_address(),
+    }
+}
+
+fn token_addresses(token_ids: &[TokenId]) -> crate::Result<Vec<String>> {
+    token_ids.iter().map(TokenId::to_solidity_address).collect()
+}
+
+fn token_only(function: &str, token_id: TokenId) -> crate::Result<Vec<u8>> {
+    Ok(ContractFunctionParameters::new()
+        .add_address(&token_id.to_solidity_address()?)
+        .to_bytes(Some(function)))
+}
+
+fn token_and_account(
+    function: &str,
+    token_id: TokenId,
+    account_id: AccountId,
+) -> crate::Result<Vec<u8>> {
+    Ok(ContractFunctionParameters::new()
+        .add_address(&token_id.to_solidity_address()?)
+        .add_address(&account_address(account_id)?)
+        .to_bytes(Some(function)))
+}
+
+fn decode_u64(result: &ContractFunctionResult) -> crate::Result<u64> {
+    let value = result.get_u256(0).ok_or_else(|| Error::abi("expected a `uint256`"))?;
+
+    u64::try_from(&value).map_err(|_| Error::abi("`uint256` out of range"))
+}
+
+fn as_i64(value: &AbiValue) -> crate::Result<i64> {
+    value
+        .as_int()
+        .and_then(|it| i64::try_from(it).ok())
+        .ok_or_else(|| Error::abi("expected an `int64`"))
+}
+
+fn as_u64(value: &AbiValue) -> crate::Result<u64> {
+    u64::try_from(as_i64(value)?).map_err(|_| Error::abi("expected a non-negative `int64`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{
+        ExchangeRatePrecompile,
+        PrngPrecompile,
+        TokenServicePrecompile,
+    };
+    use crate::{
+        AccountId,
+        ContractFunctionResult,
+        ContractId,
+        Hbar,
+        Status,
+        TokenId,
+        TokenType,
+    };
+
+    fn result(bytes: &[u8]) -> ContractFunctionResult {
+        ContractFunctionResult {
+            contract_id: ContractId::new(0, 0, 0x167),
+            evm_address: None,
+            bytes: bytes.to_vec(),
+            error_message: None,
+            bloom: Vec::new(),
+            gas_used: 0,
+            gas: 0,
+            hbar_amount: 0,
+            contract_function_parameters_bytes: Vec::new(),
+            sender_account_id: None,
+            logs: Vec::new(),
+            contract_nonces: Vec::new(),
+            signer_nonce: None,
+        }
+    }
+
+    #[test]
+    fn associate_token() {
+        let call = TokenServicePrecompile::associate_token(
+            AccountId::new(0, 0, 1001),
+            TokenId::new(0, 0, 1002),
+        )
+        .unwrap();
+
+        assert_eq!(
+            call,
+            hex!(
+                "49146bde"
+                "00000000000000000000000000000000000000000000000000000000000003e9"
+                "00000000000000000000000000000000000000000000000000000000000003ea"
+            )
+        );
+    }
+
+    #[test]
+    fn selectors() {
+        let token_id = TokenId::new(0, 0, 1002);
+        let account_id = AccountId::new(0, 0, 1001);
+
+        let cases = [
+            (
+                TokenServicePrecompile::transfer_token(token_id, account_id, account_id, 1)
+                    .unwrap(),
+                hex!("eca36917"),
+            ),
+            (TokenServicePrecompile::mint_token(token_id, 0, &[b"nft"]).unwrap(), hex!("e0f4059a")),
+            (TokenServicePrecompile::burn_token(token_id, 0, &[1, 2]).unwrap(), hex!("d6910d06")),
+            (TokenServicePrecompile::is_token(token_id).unwrap(), hex!("19f37361")),
+            (TokenServicePrecompile::get_token_type(token_id).unwrap(), hex!("93272baf")),
+            (ExchangeRatePrecompile::tinycents_to_tinybars(100), hex!("2e3cff6a")),
+            (PrngPrecompile::get_pseudorandom_seed(), hex!("d83bf9a1")),
+        ];
+
+        for (call, selector) in cases {
+            assert_eq!(call[..4], selector);
+        }
+    }
+
+    #[test]
+    fn decode_mint_token() {
+        let result = result(&hex!(
+            "0000000000000000000000000000000000000000000000000000000000000016"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "0000000000000000000000000000000000000000000000000000000000000060"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+            "0000000000000000000000000000000000000000000000000000000000000002"
+        ));
+
+        let minted = TokenServicePrecompile::decode_mint_token(&result).unwrap();
+
+        assert_eq!(minted.status, Status::Success);
+        assert_eq!(minted.new_total_supply, 2);
+        assert_eq!(minted.serial_numbers, [1, 2]);
+    }
+
+    #[test]
+    fn decode_token_type() {
+        let result = result(&hex!(
+            "0000000000000000000000000000000000000000000000000000000000000016"
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        ));
+
+        assert_eq!(
+            TokenServicePrecompile::decode_token_type(&result).unwrap(),
+            (Status::Success, TokenType::NonFungibleUnique)
+        );
+    }
+
+    #[test]
+    fn decode_create_token() {
+        let result = result(&hex!(
+            "0000000000000000000000000000000000000000000000000000000000000016"
+            "00000000000000000000000000000000000000000000000000000000000003ea"
+        ));
+
+        assert_eq!(
+            TokenServicePrecompile::decode_create_token(&result).unwrap(),
+            (Status::Success, TokenId::new(0, 0, 1002))
+        );
+    }
+
+    #[test]
+    fn decode_tinybars() {
+        let result =
+            result(&hex!("0000000000000000000000000000000000000000000000000000000005f5e100"));
+
+        assert_eq!(ExchangeRatePrecompile::decode_tinybars(&result).unwrap(), Hbar::new(1));
+    }
+}
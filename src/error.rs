@@ -24,7 +24,10 @@ use std::result::Result as StdResult;
 use crate::entity_id::Checksum;
 use crate::{
     AccountId,
+    ContractId,
+    ContractRevert,
     Hbar,
+    RequestType,
     Status,
     TransactionId,
 };
@@ -142,8 +145,8 @@ pub enum Error {
     ///
     /// Examples of when this can happen (non-exhaustive):
     /// - [`PrivateKey::derive`](fn@crate::PrivateKey::derive) when the `PrivateKey` doesn't have a chain code.
-    /// - [`PrivateKey::derive`](fn@crate::PrivateKey::derive)
-    ///   or [`PrivateKey::legacy_derive`](fn@crate::PrivateKey::legacy_derive) on an `Ecsda` key.
+    /// - [`PrivateKey::derive`](fn@crate::PrivateKey::derive) when an `Ecdsa` key's child index makes an invalid key.
+    /// - [`PrivateKey::legacy_derive`](fn@crate::PrivateKey::legacy_derive) on an `Ecsda` key.
     #[error("Failed to derive a key: {0}")]
     KeyDerive(#[source] BoxStdError),
 
@@ -203,12 +206,85 @@ pub enum Error {
         transaction_id: Option<Box<TransactionId>>,
     },
 
+    /// A smart contract call failed with `status`, and its result says what it reverted with.
+    ///
+    /// Returned instead of [`QueryPreCheckStatus`](Self::QueryPreCheckStatus) or
+    /// [`QueryPaymentPreCheckStatus`](Self::QueryPaymentPreCheckStatus) by a failed
+    /// [`ContractCallQuery`](crate::ContractCallQuery), and instead of [`ReceiptStatus`](Self::ReceiptStatus)
+    /// by a [`TransactionRecordQuery`](crate::TransactionRecordQuery) for a failed contract call.
+    ///
+    /// Receipts don't include the call's result, so a [`TransactionReceiptQuery`](crate::TransactionReceiptQuery)
+    /// or [`TransactionResponse::get_receipt`](crate::TransactionResponse::get_receipt) still returns
+    /// [`ReceiptStatus`](Self::ReceiptStatus), use [`TransactionResponse::get_record`](crate::TransactionResponse::get_record) to get the revert.
+    #[error(
+        "contract call for transaction `{transaction_id:?}` failed with status `{status:?}`: {revert}"
+    )]
+    ContractReverted {
+        /// The Error's status code.
+        status: Status,
+        /// The associated transaction's ID, or the query's payment transaction's ID.
+        transaction_id: Option<Box<TransactionId>>,
+        /// What the contract call reverted with.
+        revert: Box<ContractRevert>,
+    },
+
     /// Failed to verify a signature.
     #[error("failed to verify a signature: {0}")]
     SignatureVerify(#[source] BoxStdError),
+
+    /// A [`Signer`](crate::Signer) failed to produce a signature.
+    #[error("failed to sign: {0}")]
+    Signature(#[source] BoxStdError),
+
+    /// The fee schedule given to a [`FeeEstimator`](crate::FeeEstimator) has no prices for a kind of request.
+    #[error("the fee schedule has no prices for `{0:?}`")]
+    FeeScheduleMissing(RequestType),
+
+    /// A value couldn't be encoded to, or decoded from, the Solidity ABI.
+    #[error("failed to encode or decode a Solidity ABI value: {0}")]
+    Abi(#[source] BoxStdError),
+
+    /// A library placeholder in a [`ContractArtifact`](crate::ContractArtifact)'s bytecode couldn't be linked, or was left unlinked.
+    #[error("failed to link a library: {0}")]
+    LibraryLink(#[source] BoxStdError),
+
+    /// The runtime bytecode of a contract deployed by a [`ContractCreateFlow`](crate::ContractCreateFlow)
+    /// doesn't match the deployed bytecode of its artifact.
+    #[error("runtime bytecode of contract `{0}` doesn't match the artifact's deployed bytecode")]
+    DeployedBytecodeMismatch(Box<ContractId>),
+
+    /// A [`SubmissionJournal`](crate::SubmissionJournal) failed to record or read its entries.
+    #[error("submission journal failed: {0}")]
+    Journal(#[source] std::io::Error),
+
+    /// A request to the mirror node REST API failed, or its response couldn't be understood.
+    #[cfg(feature = "mirror-rest")]
+    #[error("mirror node REST request failed: {0}")]
+    MirrorRest(#[source] BoxStdError),
+
+    /// The mirror node REST API responded with a non-success HTTP status.
+    #[cfg(feature = "mirror-rest")]
+    #[error("mirror node REST request failed with HTTP status {status}: {message}")]
+    MirrorRestStatus {
+        /// The HTTP status code, for instance `404` when the requested entity doesn't exist.
+        status: u16,
+        /// The error message returned by the mirror node.
+        message: String,
+    },
 }
 
 impl Error {
+    /// Returns what a smart contract call reverted with, if this is a [`ContractReverted`](Self::ContractReverted) error.
+    ///
+    /// Use [`ContractRevert::decode`] to decode custom errors with the contract's ABI.
+    #[must_use]
+    pub fn contract_revert(&self) -> Option<&ContractRevert> {
+        match self {
+            Self::ContractReverted { revert, .. } => Some(revert),
+            _ => None,
+        }
+    }
+
     pub(crate) fn from_protobuf<E: Into<BoxStdError>>(error: E) -> Self {
         Self::FromProtobuf(error.into())
     }
@@ -228,6 +304,23 @@ impl Error {
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    pub(crate) fn signature(error: impl Into<BoxStdError>) -> Self {
+        Self::Signature(error.into())
+    }
+
+    pub(crate) fn abi(error: impl Into<BoxStdError>) -> Self {
+        Self::Abi(error.into())
+    }
+
+    pub(crate) fn library_link(error: impl Into<BoxStdError>) -> Self {
+        Self::LibraryLink(error.into())
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    pub(crate) fn mirror_rest(error: impl Into<BoxStdError>) -> Self {
+        Self::MirrorRest(error.into())
+    }
 }
 
 /// Failed to parse a mnemonic.
@@ -280,4 +373,8 @@ pub enum MnemonicEntropyError {
     /// Used a passphrase with a legacy [`Mnemonic`](crate::Mnemonic).
     #[error("used a passphrase with a legacy mnemonic")]
     LegacyWithPassphrase,
+
+    /// Tried to derive an ECDSA key from a legacy [`Mnemonic`](crate::Mnemonic), which has no BIP-39 seed.
+    #[error("legacy mnemonics aren't supported for ECDSA key derivation")]
+    LegacyEcdsa,
 }
@@ -0,0 +1,49 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+/// Returns the hash `personal_sign` (EIP-191 version `0x45`) signs for `message`:
+/// `keccak256("\x19Ethereum Signed Message:\n" || len(message) || message)`.
+pub(crate) fn hash_personal_message(message: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak256::new_with_prefix(b"\x19Ethereum Signed Message:\n");
+    hasher.update(message.len().to_string());
+    hasher.update(message);
+
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::hash_personal_message;
+
+    #[test]
+    fn personal_message_hash() {
+        assert_eq!(
+            hash_personal_message(b"Some data"),
+            hex!("1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655")
+        );
+    }
+}
@@ -0,0 +1,436 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::borrow::Cow;
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+};
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use crate::contract::{
+    AbiType,
+    AbiValue,
+};
+use crate::Error;
+
+const DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// EIP-712 typed structured data, in the JSON format `eth_signTypedData_v4` takes.
+///
+/// Sign it with [`PrivateKey::sign_typed_data`](crate::PrivateKey::sign_typed_data),
+/// and recover the signer with [`PublicKey::recover_typed_data`](crate::PublicKey::recover_typed_data).
+#[derive(Debug, Clone, PartialEq, serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Eip712TypedData {
+    types: BTreeMap<String, Vec<Eip712Field>>,
+    primary_type: String,
+    domain: serde_json::Value,
+    message: serde_json::Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde_derive::Deserialize)]
+struct Eip712Field {
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+impl Eip712TypedData {
+    /// Parses typed data from JSON with `types`, `primaryType`, `domain` and `message` fields.
+    ///
+    /// `types` may leave out `EIP712Domain`, in which case it's made of the fields present in `domain`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `json` isn't valid typed data.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        serde_json::from_str(json).map_err(Error::basic_parse)
+    }
+
+    /// Returns the name of the struct the message is an instance of.
+    #[must_use]
+    pub fn primary_type(&self) -> &str {
+        &self.primary_type
+    }
+
+    /// Returns the hash of the domain, which is the `DOMAIN_SEPARATOR` a verifying contract uses.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if the domain doesn't match its type.
+    pub fn domain_separator(&self) -> crate::Result<[u8; 32]> {
+        self.hash_struct(DOMAIN_TYPE, &self.domain)
+    }
+
+    /// Returns the `hashStruct` of the message.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if the message doesn't match its type.
+    pub fn struct_hash(&self) -> crate::Result<[u8; 32]> {
+        self.hash_struct(&self.primary_type, &self.message)
+    }
+
+    /// Returns the hash which gets signed: `keccak256("\x19\x01" || domainSeparator || hashStruct(message))`.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if the domain or the message doesn't match its type.
+    pub fn signing_hash(&self) -> crate::Result<[u8; 32]> {
+        let mut hasher = Keccak256::new_with_prefix([0x19, 0x01]);
+        hasher.update(self.domain_separator()?);
+
+        // when the primary type is the domain itself, there's no message to hash.
+        if self.primary_type != DOMAIN_TYPE {
+            hasher.update(self.struct_hash()?);
+        }
+
+        Ok(hasher.finalize().into())
+    }
+
+    /// Returns the type string of `ty`, such as `Mail(Person from,Person to,string contents)Person(string name,address wallet)`.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `ty` isn't a struct in `types`.
+    pub fn encode_type(&self, ty: &str) -> crate::Result<String> {
+        let mut dependencies = BTreeSet::new();
+        self.collect_dependencies(ty, &mut dependencies)?;
+        dependencies.remove(ty);
+
+        let mut encoded = String::new();
+        for ty in std::iter::once(ty).chain(dependencies.iter().map(String::as_str)) {
+            let fields: Vec<_> = self
+                .fields(ty)?
+                .iter()
+                .map(|field| format!("{} {}", field.ty, field.name))
+                .collect();
+
+            encoded.push_str(&format!("{ty}({})", fields.join(",")));
+        }
+
+        Ok(encoded)
+    }
+
+    fn collect_dependencies(&self, ty: &str, found: &mut BTreeSet<String>) -> crate::Result<()> {
+        let ty = base_type(ty);
+
+        if found.contains(ty) || !self.is_struct(ty) {
+            return Ok(());
+        }
+
+        found.insert(ty.to_owned());
+
+        for field in self.fields(ty)?.iter() {
+            self.collect_dependencies(&field.ty, found)?;
+        }
+
+        Ok(())
+    }
+
+    fn is_struct(&self, ty: &str) -> bool {
+        ty == DOMAIN_TYPE || self.types.contains_key(ty)
+    }
+
+    fn fields(&self, ty: &str) -> crate::Result<Cow<'_, [Eip712Field]>> {
+        if let Some(fields) = self.types.get(ty) {
+            return Ok(Cow::Borrowed(fields));
+        }
+
+        if ty != DOMAIN_TYPE {
+            return Err(Error::abi(format!("unknown EIP-712 type `{ty}`")));
+        }
+
+        // the domain's type is whichever of its standard fields it has.
+        let fields = [
+            ("name", "string"),
+            ("version", "string"),
+            ("chainId", "uint256"),
+            ("verifyingContract", "address"),
+            ("salt", "bytes32"),
+        ]
+        .into_iter()
+        .filter(|(name, _)| self.domain.get(name).is_some_and(|it| !it.is_null()))
+        .map(|(name, ty)| Eip712Field { name: name.to_owned(), ty: ty.to_owned() })
+        .collect();
+
+        Ok(Cow::Owned(fields))
+    }
+
+    fn hash_struct(&self, ty: &str, value: &serde_json::Value) -> crate::Result<[u8; 32]> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| Error::abi(format!("expected an object for EIP-712 type `{ty}`")))?;
+
+        let mut hasher = Keccak256::new_with_prefix(Keccak256::digest(self.encode_type(ty)?));
+
+        for field in self.fields(ty)?.iter() {
+            let value = object.get(&field.name).ok_or_else(|| {
+                Error::abi(format!("missing field `{}` of EIP-712 type `{ty}`", field.name))
+            })?;
+
+            hasher.update(self.encode_value(&field.ty, value)?);
+        }
+
+        Ok(hasher.finalize().into())
+    }
+
+    fn encode_value(&self, ty: &str, value: &serde_json::Value) -> crate::Result<[u8; 32]> {
+        if let Some(element_ty) = ty.strip_suffix(']') {
+            let (element_ty, len) = element_ty
+                .rsplit_once('[')
+                .ok_or_else(|| Error::abi(format!("invalid EIP-712 type `{ty}`")))?;
+
+            let values = value
+                .as_array()
+                .ok_or_else(|| Error::abi(format!("expected an array for EIP-712 type `{ty}`")))?;
+
+            if !len.is_empty() && len.parse() != Ok(values.len()) {
+                return Err(Error::abi(format!(
+                    "wrong number of elements for EIP-712 type `{ty}`"
+                )));
+            }
+
+            let mut hasher = Keccak256::new();
+            for value in values {
+                hasher.update(self.encode_value(element_ty, value)?);
+            }
+
+            return Ok(hasher.finalize().into());
+        }
+
+        if self.is_struct(ty) {
+            return self.hash_struct(ty, value);
+        }
+
+        match ty {
+            "string" => {
+                let value = value
+                    .as_str()
+                    .ok_or_else(|| Error::abi("expected a string for EIP-712 type `string`"))?;
+
+                Ok(Keccak256::digest(value).into())
+            }
+
+            "bytes" => Ok(Keccak256::digest(hex_bytes(value)?).into()),
+
+            _ => {
+                let value = atomic_value(&ty.parse()?, value)?;
+
+                value.encode()?.try_into().map_err(|_| Error::abi(format!("`{ty}` isn't atomic")))
+            }
+        }
+    }
+}
+
+/// Strips any array suffixes off of `ty`.
+fn base_type(ty: &str) -> &str {
+    ty.split_once('[').map_or(ty, |(base, _)| base)
+}
+
+fn atomic_value(ty: &AbiType, value: &serde_json::Value) -> crate::Result<AbiValue> {
+    let mismatch =
+        || Error::abi(format!("expected a value for EIP-712 type `{ty}`, found `{value}`"));
+
+    let value = match ty {
+        AbiType::Address => AbiValue::Address(value.as_str().ok_or_else(mismatch)?.parse()?),
+        AbiType::Bool => AbiValue::Bool(value.as_bool().ok_or_else(mismatch)?),
+        AbiType::Uint(bits) => {
+            AbiValue::Uint(*bits, integer(value)?.to_biguint().ok_or_else(mismatch)?)
+        }
+        AbiType::Int(bits) => AbiValue::Int(*bits, integer(value)?),
+        AbiType::FixedBytes(len) => {
+            let bytes = hex_bytes(value)?;
+            if bytes.len() != *len {
+                return Err(mismatch());
+            }
+
+            AbiValue::FixedBytes(bytes)
+        }
+        _ => return Err(Error::abi(format!("`{ty}` isn't an EIP-712 type"))),
+    };
+
+    Ok(value)
+}
+
+/// Reads an integer from a JSON number, or a decimal or `0x` prefixed hex string.
+fn integer(value: &serde_json::Value) -> crate::Result<BigInt> {
+    let invalid = || Error::abi(format!("expected an integer, found `{value}`"));
+
+    if let Some(value) = value.as_u64() {
+        return Ok(BigInt::from(value));
+    }
+
+    if let Some(value) = value.as_i64() {
+        return Ok(BigInt::from(value));
+    }
+
+    let value = value.as_str().ok_or_else(invalid)?;
+    let (negative, digits) =
+        value.strip_prefix('-').map_or((false, value), |digits| (true, digits));
+
+    let magnitude = match digits.strip_prefix("0x") {
+        Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+        None => BigUint::parse_bytes(digits.as_bytes(), 10),
+    }
+    .ok_or_else(invalid)?;
+
+    let magnitude = BigInt::from(magnitude);
+    Ok(if negative { -magnitude } else { magnitude })
+}
+
+fn hex_bytes(value: &serde_json::Value) -> crate::Result<Vec<u8>> {
+    let value = value
+        .as_str()
+        .and_then(|it| it.strip_prefix("0x"))
+        .ok_or_else(|| Error::abi(format!("expected `0x` prefixed hex, found `{value}`")))?;
+
+    hex::decode(value).map_err(Error::abi)
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::Eip712TypedData;
+    use crate::{
+        EvmAddress,
+        PrivateKey,
+        PublicKey,
+    };
+
+    // the example from EIP-712 itself.
+    const MAIL: &str = r#"{
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "version", "type": "string" },
+                { "name": "chainId", "type": "uint256" },
+                { "name": "verifyingContract", "type": "address" }
+            ],
+            "Person": [
+                { "name": "name", "type": "string" },
+                { "name": "wallet", "type": "address" }
+            ],
+            "Mail": [
+                { "name": "from", "type": "Person" },
+                { "name": "to", "type": "Person" },
+                { "name": "contents", "type": "string" }
+            ]
+        },
+        "primaryType": "Mail",
+        "domain": {
+            "name": "Ether Mail",
+            "version": "1",
+            "chainId": 1,
+            "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+        },
+        "message": {
+            "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+            "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+            "contents": "Hello, Bob!"
+        }
+    }"#;
+
+    #[test]
+    fn encode_type() {
+        let typed_data = Eip712TypedData::from_json(MAIL).unwrap();
+
+        assert_eq!(
+            typed_data.encode_type("Mail").unwrap(),
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+    }
+
+    #[test]
+    fn hashes() {
+        let typed_data = Eip712TypedData::from_json(MAIL).unwrap();
+
+        assert_eq!(
+            typed_data.domain_separator().unwrap(),
+            hex!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+        );
+        assert_eq!(
+            typed_data.struct_hash().unwrap(),
+            hex!("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")
+        );
+        assert_eq!(
+            typed_data.signing_hash().unwrap(),
+            hex!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+        );
+    }
+
+    #[test]
+    fn implicit_domain_type() {
+        let mut json: serde_json::Value = serde_json::from_str(MAIL).unwrap();
+        json["types"].as_object_mut().unwrap().remove("EIP712Domain");
+
+        let typed_data = Eip712TypedData::from_json(&json.to_string()).unwrap();
+
+        assert_eq!(
+            typed_data.domain_separator().unwrap(),
+            hex!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+        );
+    }
+
+    #[test]
+    fn mismatched_message() {
+        let mut json: serde_json::Value = serde_json::from_str(MAIL).unwrap();
+        json["message"]["from"]["wallet"] = serde_json::Value::Bool(true);
+
+        let typed_data = Eip712TypedData::from_json(&json.to_string()).unwrap();
+
+        assert!(typed_data.signing_hash().is_err());
+    }
+
+    #[test]
+    fn sign_and_recover() {
+        let typed_data = Eip712TypedData::from_json(MAIL).unwrap();
+
+        // `keccak256("cow")`, the key of the sender in the example.
+        let key = PrivateKey::from_bytes_ecdsa(&hex!(
+            "c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4"
+        ))
+        .unwrap();
+
+        let signature = key.sign_typed_data(&typed_data).unwrap();
+
+        assert_eq!(
+            signature,
+            hex!(
+                "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d"
+                "07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562"
+                "1c"
+            )
+        );
+
+        let signer = PublicKey::recover_typed_data(&typed_data, &signature).unwrap();
+
+        assert_eq!(signer, key.public_key());
+        assert_eq!(
+            signer.to_evm_address(),
+            Some(EvmAddress::from(hex!("cd2a3d9f938e13cd947ec05abc7fe734df8dd826")))
+        );
+    }
+}
@@ -24,9 +24,17 @@ use bytes::{
     BufMut,
     BytesMut,
 };
-use rlp::Rlp;
+use rlp::{
+    Decodable,
+    Encodable,
+    Rlp,
+    RlpStream,
+};
 
-use crate::Error;
+use crate::{
+    Error,
+    EvmAddress,
+};
 
 /// Data for an [`EthereumTransaction`](crate::EthereumTransaction).
 #[derive(Debug, Clone)]
@@ -35,6 +43,9 @@ pub enum EthereumData {
     /// Data for a legacy ethereum transaction.
     Legacy(LegacyEthereumData),
 
+    /// Data for an Eip 2930 ethereum transaction.
+    Eip2930(Eip2930EthereumData),
+
     /// Data for an Eip 1559 ethereum transaction.
     Eip1559(Eip1559EthereumData),
 }
@@ -43,13 +54,18 @@ impl EthereumData {
     pub(super) fn call_data_mut(&mut self) -> &mut Vec<u8> {
         match self {
             EthereumData::Legacy(it) => &mut it.call_data,
+            EthereumData::Eip2930(it) => &mut it.call_data,
             EthereumData::Eip1559(it) => &mut it.call_data,
         }
     }
 
     pub(crate) fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
         match bytes.split_first() {
-            // note: eating the 2 here involves a bit of extra work.
+            // note: eating the type byte here involves a bit of extra work.
+            Some((1, bytes)) => Eip2930EthereumData::decode_rlp(&Rlp::new(bytes))
+                .map(Self::Eip2930)
+                .map_err(Error::basic_parse),
+
             Some((2, bytes)) => Eip1559EthereumData::decode_rlp(&Rlp::new(bytes))
                 .map(Self::Eip1559)
                 .map_err(Error::basic_parse),
@@ -64,6 +80,7 @@ impl EthereumData {
     pub fn to_bytes(&self) -> Vec<u8> {
         match self {
             EthereumData::Legacy(it) => it.to_bytes(),
+            EthereumData::Eip2930(it) => it.to_bytes(),
             EthereumData::Eip1559(it) => it.to_bytes(),
         }
     }
@@ -167,6 +184,201 @@ impl LegacyEthereumData {
     }
 }
 
+/// An address, and the storage keys in it, that a transaction plans to access (see EIP-2930).
+#[derive(Clone, PartialEq, Eq)]
+pub struct AccessListItem {
+    /// The address of the account or contract.
+    pub address: EvmAddress,
+
+    /// The storage keys that will be accessed.
+    pub storage_keys: Vec<[u8; 32]>,
+}
+
+impl fmt::Debug for AccessListItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessListItem")
+            .field("address", &self.address)
+            .field("storage_keys", &HexList(&self.storage_keys))
+            .finish()
+    }
+}
+
+impl Encodable for AccessListItem {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(2).append(&self.address.0.as_slice());
+
+        s.begin_list(self.storage_keys.len());
+
+        for key in &self.storage_keys {
+            s.append(&key.as_slice());
+        }
+    }
+}
+
+impl Decodable for AccessListItem {
+    fn decode(rlp: &Rlp) -> Result<Self, rlp::DecoderError> {
+        if rlp.item_count()? != 2 {
+            return Err(rlp::DecoderError::RlpIncorrectListLen);
+        }
+
+        let address = EvmAddress::try_from(rlp.val_at::<Vec<u8>>(0)?)
+            .map_err(|_| rlp::DecoderError::Custom("Invalid access list address"))?;
+
+        let storage_keys = rlp
+            .list_at::<Vec<u8>>(1)?
+            .into_iter()
+            .map(|key| {
+                <[u8; 32]>::try_from(key)
+                    .map_err(|_| rlp::DecoderError::Custom("Invalid access list storage key"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { address, storage_keys })
+    }
+}
+
+/// Data for an Eip 2930 ethereum transaction.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Eip2930EthereumData {
+    /// ID of the chain.
+    pub chain_id: Vec<u8>,
+
+    /// Transaction's nonce.
+    pub nonce: Vec<u8>,
+
+    /// Price for 1 gas.
+    pub gas_price: Vec<u8>,
+
+    /// The amount of gas available for the transaction.
+    pub gas_limit: Vec<u8>,
+
+    /// The receiver of the transaction.
+    pub to: Vec<u8>,
+
+    /// The transaction value.
+    pub value: Vec<u8>,
+
+    /// The raw call data.
+    pub call_data: Vec<u8>,
+
+    /// Specifies an array of addresses and storage keys that the transaction plans to access.
+    pub access_list: Vec<AccessListItem>,
+
+    /// Recovery parameter used to ease the signature verification.
+    pub recovery_id: Vec<u8>,
+
+    /// The R value of the signature.
+    pub r: Vec<u8>,
+
+    /// The S value of the signature.
+    pub s: Vec<u8>,
+}
+
+// manual impl of debug for the hex encoding of everything.
+impl fmt::Debug for Eip2930EthereumData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            chain_id,
+            nonce,
+            gas_price,
+            gas_limit,
+            to,
+            value,
+            call_data,
+            access_list,
+            recovery_id,
+            r,
+            s,
+        } = self;
+
+        f.debug_struct("Eip2930EthereumData")
+            .field("chain_id", &hex::encode(chain_id))
+            .field("nonce", &hex::encode(nonce))
+            .field("gas_price", &hex::encode(gas_price))
+            .field("gas_limit", &hex::encode(gas_limit))
+            .field("to", &hex::encode(to))
+            .field("value", &hex::encode(value))
+            .field("call_data", &hex::encode(call_data))
+            .field("access_list", access_list)
+            .field("recovery_id", &hex::encode(recovery_id))
+            .field("r", &hex::encode(r))
+            .field("s", &hex::encode(s))
+            .finish()
+    }
+}
+
+impl Eip2930EthereumData {
+    fn decode_rlp(rlp: &Rlp) -> Result<Self, rlp::DecoderError> {
+        if rlp.item_count()? != 11 {
+            return Err(rlp::DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            chain_id: rlp.val_at(0)?,
+            nonce: rlp.val_at(1)?,
+            gas_price: rlp.val_at(2)?,
+            gas_limit: rlp.val_at(3)?,
+            to: rlp.val_at(4)?,
+            value: rlp.val_at(5)?,
+            call_data: rlp.val_at(6)?,
+            access_list: rlp.list_at(7)?,
+            recovery_id: rlp.val_at(8)?,
+            r: rlp.val_at(9)?,
+            s: rlp.val_at(10)?,
+        })
+    }
+
+    /// Deserialize this data from rlp encoded bytes.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if decoding the bytes fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let (&first, bytes) = bytes
+            .split_first()
+            .ok_or_else(|| Error::basic_parse("Empty ethereum transaction data"))?;
+
+        if first != 1 {
+            return Err(Error::basic_parse(rlp::DecoderError::Custom("Invalid kind")));
+        }
+
+        Self::decode_rlp(&Rlp::new(bytes)).map_err(Error::basic_parse)
+    }
+
+    fn encode(&self, with_signature: bool) -> Vec<u8> {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x01);
+        let mut rlp =
+            rlp::RlpStream::new_list_with_buffer(buffer, if with_signature { 11 } else { 8 });
+
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.gas_price)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list::<AccessListItem, _>(&self.access_list);
+
+        if with_signature {
+            rlp.append(&self.recovery_id).append(&self.r).append(&self.s);
+        }
+
+        rlp.out().to_vec()
+    }
+
+    /// Returns the bytes that are hashed to sign this transaction, which are all the fields except the signature.
+    pub(super) fn to_signing_bytes(&self) -> Vec<u8> {
+        self.encode(false)
+    }
+
+    /// Convert this data to rlp encoded bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.encode(true)
+    }
+}
+
 /// Data for an Eip 1559 ethereum transaction.
 #[derive(Clone)]
 #[non_exhaustive]
@@ -198,7 +410,7 @@ pub struct Eip1559EthereumData {
     pub call_data: Vec<u8>,
 
     /// Specifies an array of addresses and storage keys that the transaction plans to access.
-    pub access_list: Vec<Vec<u8>>,
+    pub access_list: Vec<AccessListItem>,
 
     /// Recovery parameter used to ease the signature verification.
     pub recovery_id: Vec<u8>,
@@ -213,14 +425,6 @@ pub struct Eip1559EthereumData {
 // manual impl of debug for the hex encoding of everything.
 impl fmt::Debug for Eip1559EthereumData {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        struct HexList<'a, T: AsRef<[u8]>>(&'a [T]);
-
-        impl<'a, T: AsRef<[u8]>> fmt::Debug for HexList<'a, T> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                f.debug_list().entries(self.0.iter().map(hex::encode)).finish()
-            }
-        }
-
         let Self {
             chain_id,
             nonce,
@@ -245,7 +449,7 @@ impl fmt::Debug for Eip1559EthereumData {
             .field("to", &hex::encode(to))
             .field("value", &hex::encode(value))
             .field("call_data", &hex::encode(call_data))
-            .field("access_list", &HexList(access_list))
+            .field("access_list", access_list)
             .field("recovery_id", &hex::encode(recovery_id))
             .field("r", &hex::encode(r))
             .field("s", &hex::encode(s))
@@ -291,12 +495,11 @@ impl Eip1559EthereumData {
         Self::decode_rlp(&Rlp::new(bytes)).map_err(Error::basic_parse)
     }
 
-    /// Convert this data to rlp encoded bytes.
-    #[must_use]
-    pub fn to_bytes(&self) -> Vec<u8> {
+    fn encode(&self, with_signature: bool) -> Vec<u8> {
         let mut buffer = BytesMut::new();
         buffer.put_u8(0x02);
-        let mut rlp = rlp::RlpStream::new_list_with_buffer(buffer, 12);
+        let mut rlp =
+            rlp::RlpStream::new_list_with_buffer(buffer, if with_signature { 12 } else { 9 });
 
         rlp.append(&self.chain_id)
             .append(&self.nonce)
@@ -306,13 +509,33 @@ impl Eip1559EthereumData {
             .append(&self.to)
             .append(&self.value)
             .append(&self.call_data)
-            .append_list::<Vec<_>, _>(self.access_list.as_slice())
-            .append(&self.recovery_id)
-            .append(&self.r)
-            .append(&self.s);
+            .append_list::<AccessListItem, _>(&self.access_list);
+
+        if with_signature {
+            rlp.append(&self.recovery_id).append(&self.r).append(&self.s);
+        }
 
         rlp.out().to_vec()
     }
+
+    /// Returns the bytes that are hashed to sign this transaction, which are all the fields except the signature.
+    pub(super) fn to_signing_bytes(&self) -> Vec<u8> {
+        self.encode(false)
+    }
+
+    /// Convert this data to rlp encoded bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.encode(true)
+    }
+}
+
+struct HexList<'a, T: AsRef<[u8]>>(&'a [T]);
+
+impl<'a, T: AsRef<[u8]>> fmt::Debug for HexList<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter().map(hex::encode)).finish()
+    }
 }
 
 #[cfg(test)]
@@ -320,7 +543,12 @@ mod test {
     use expect_test::expect;
     use hex_literal::hex;
 
-    use crate::ethereum::EthereumData;
+    use crate::ethereum::{
+        AccessListItem,
+        Eip1559EthereumData,
+        EthereumData,
+    };
+    use crate::EvmAddress;
     // https://github.com/hashgraph/hedera-services/blob/1e01d9c6b8923639b41359c55413640b589c4ec7/hapi-utils/src/test/java/com/hedera/services/ethereum/EthTxDataTest.java#L49
     const RAW_TX_TYPE_0: &[u8]  =
         &hex!("f864012f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc18180827653820277a0f9fbff985d374be4a55f296915002eec11ac96f1ce2df183adf992baa9390b2fa00c1e867cc960d9c74ec2e6a662b7908ec4c8cc9f3091e886bcefbeb2290fb792");
@@ -380,4 +608,22 @@ mod test {
         "#]]
         .assert_debug_eq(&data);
     }
+
+    #[test]
+    fn eip1559_access_list() {
+        let items = vec![
+            AccessListItem {
+                address: EvmAddress::from([0x11; 20]),
+                storage_keys: vec![[0x22; 32]],
+            },
+            AccessListItem { address: EvmAddress::from([0x33; 20]), storage_keys: Vec::new() },
+        ];
+
+        let mut data = Eip1559EthereumData::from_bytes(RAW_TX_TYPE_2).unwrap();
+        data.access_list = items.clone();
+
+        let data = Eip1559EthereumData::from_bytes(&data.to_bytes()).unwrap();
+
+        assert_eq!(data.access_list, items);
+    }
 }
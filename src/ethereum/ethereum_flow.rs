@@ -124,6 +124,12 @@ impl EthereumFlow {
     }
 }
 
+impl From<EthereumData> for EthereumFlow {
+    fn from(data: EthereumData) -> Self {
+        Self { ethereum_data: Some(data), max_gas_allowance: None }
+    }
+}
+
 fn split_call_data(call_data: Vec<u8>) -> (Vec<u8>, Option<Vec<u8>>) {
     const FILE_APPEND_DEFAULT_CHUNK_SIZE: usize = 4096;
 
@@ -0,0 +1,509 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use sha3::Digest;
+
+use super::ethereum_data::{
+    AccessListItem,
+    Eip1559EthereumData,
+    Eip2930EthereumData,
+    EthereumData,
+    LegacyEthereumData,
+};
+use crate::{
+    EthereumFlow,
+    EvmAddress,
+    PrivateKey,
+};
+
+/// The kind of ethereum transaction an [`EthereumTransactionBuilder`] makes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EthereumTransactionType {
+    /// A legacy (type 0) transaction, with EIP-155 replay protection.
+    Legacy,
+
+    /// An EIP-2930 (type 1) transaction, which adds an access list.
+    Eip2930,
+
+    /// An EIP-1559 (type 2) transaction, which replaces the gas price with a max fee and a priority fee.
+    Eip1559,
+}
+
+/// Builds and signs a raw ethereum transaction, for use with an [`EthereumFlow`]
+/// or an [`EthereumTransaction`](crate::EthereumTransaction).
+///
+/// Amounts are in weibars (1 tinybar is 10<sup>10</sup> weibars).
+///
+/// # Examples
+///
+/// ```
+/// use hedera::{EthereumTransactionBuilder, EvmAddress, PrivateKey};
+///
+/// let key = PrivateKey::generate_ecdsa();
+///
+/// let data = EthereumTransactionBuilder::eip1559(298)
+///     .nonce(0)
+///     .max_fee_per_gas(710_000_000_000)
+///     .gas_limit(150_000)
+///     .to("0x00000000000000000000000000000000000003e9".parse::<EvmAddress>().unwrap())
+///     .call_data(vec![0x12, 0x34])
+///     .sign(&key)
+///     .unwrap();
+///
+/// assert_eq!(data.to_bytes()[0], 0x02);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EthereumTransactionBuilder {
+    kind: EthereumTransactionType,
+    chain_id: u64,
+    nonce: u64,
+    gas_price: u128,
+    max_priority_fee_per_gas: u128,
+    max_fee_per_gas: u128,
+    gas_limit: u64,
+    to: Option<EvmAddress>,
+    value: u128,
+    call_data: Vec<u8>,
+    access_list: Vec<AccessListItem>,
+}
+
+impl EthereumTransactionBuilder {
+    /// Create a new builder for a `kind` transaction on the chain with the ID `chain_id`.
+    ///
+    /// Hedera's chain IDs are `295` (mainnet), `296` (testnet), `297` (previewnet), and `298` (local networks).
+    #[must_use]
+    pub fn new(kind: EthereumTransactionType, chain_id: u64) -> Self {
+        Self {
+            kind,
+            chain_id,
+            nonce: 0,
+            gas_price: 0,
+            max_priority_fee_per_gas: 0,
+            max_fee_per_gas: 0,
+            gas_limit: 0,
+            to: None,
+            value: 0,
+            call_data: Vec::new(),
+            access_list: Vec::new(),
+        }
+    }
+
+    /// Create a new builder for a legacy (EIP-155) transaction.
+    #[must_use]
+    pub fn legacy(chain_id: u64) -> Self {
+        Self::new(EthereumTransactionType::Legacy, chain_id)
+    }
+
+    /// Create a new builder for an EIP-2930 transaction.
+    #[must_use]
+    pub fn eip2930(chain_id: u64) -> Self {
+        Self::new(EthereumTransactionType::Eip2930, chain_id)
+    }
+
+    /// Create a new builder for an EIP-1559 transaction.
+    #[must_use]
+    pub fn eip1559(chain_id: u64) -> Self {
+        Self::new(EthereumTransactionType::Eip1559, chain_id)
+    }
+
+    /// Returns the kind of transaction this builds.
+    #[must_use]
+    pub fn get_kind(&self) -> EthereumTransactionType {
+        self.kind
+    }
+
+    /// Returns the ID of the chain the transaction is for.
+    #[must_use]
+    pub fn get_chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Returns the nonce of the signer's account.
+    #[must_use]
+    pub fn get_nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Sets the nonce of the signer's account.
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Returns the price for 1 gas.
+    #[must_use]
+    pub fn get_gas_price(&self) -> u128 {
+        self.gas_price
+    }
+
+    /// Sets the price for 1 gas.
+    ///
+    /// Only used by legacy and EIP-2930 transactions.
+    pub fn gas_price(&mut self, gas_price: u128) -> &mut Self {
+        self.gas_price = gas_price;
+        self
+    }
+
+    /// Returns the fee per gas that's paid on top of the base fee.
+    #[must_use]
+    pub fn get_max_priority_fee_per_gas(&self) -> u128 {
+        self.max_priority_fee_per_gas
+    }
+
+    /// Sets the fee per gas that's paid on top of the base fee.
+    ///
+    /// Only used by EIP-1559 transactions, and not used by Hedera.
+    pub fn max_priority_fee_per_gas(&mut self, fee: u128) -> &mut Self {
+        self.max_priority_fee_per_gas = fee;
+        self
+    }
+
+    /// Returns the maximum total fee per gas the signer is willing to pay.
+    #[must_use]
+    pub fn get_max_fee_per_gas(&self) -> u128 {
+        self.max_fee_per_gas
+    }
+
+    /// Sets the maximum total fee per gas the signer is willing to pay.
+    ///
+    /// Only used by EIP-1559 transactions.
+    pub fn max_fee_per_gas(&mut self, fee: u128) -> &mut Self {
+        self.max_fee_per_gas = fee;
+        self
+    }
+
+    /// Returns the amount of gas available for the transaction.
+    #[must_use]
+    pub fn get_gas_limit(&self) -> u64 {
+        self.gas_limit
+    }
+
+    /// Sets the amount of gas available for the transaction.
+    pub fn gas_limit(&mut self, gas_limit: u64) -> &mut Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    /// Returns the receiver of the transaction, `None` for a contract creation.
+    #[must_use]
+    pub fn get_to(&self) -> Option<EvmAddress> {
+        self.to
+    }
+
+    /// Sets the receiver of the transaction, leave it unset to create a contract.
+    pub fn to(&mut self, to: EvmAddress) -> &mut Self {
+        self.to = Some(to);
+        self
+    }
+
+    /// Returns the value sent with the transaction.
+    #[must_use]
+    pub fn get_value(&self) -> u128 {
+        self.value
+    }
+
+    /// Sets the value sent with the transaction.
+    pub fn value(&mut self, value: u128) -> &mut Self {
+        self.value = value;
+        self
+    }
+
+    /// Returns the call data of the transaction.
+    #[must_use]
+    pub fn get_call_data(&self) -> &[u8] {
+        &self.call_data
+    }
+
+    /// Sets the call data of the transaction, or the init code when creating a contract.
+    pub fn call_data(&mut self, call_data: Vec<u8>) -> &mut Self {
+        self.call_data = call_data;
+        self
+    }
+
+    /// Returns the addresses and storage keys the transaction plans to access.
+    #[must_use]
+    pub fn get_access_list(&self) -> &[AccessListItem] {
+        &self.access_list
+    }
+
+    /// Sets the addresses and storage keys the transaction plans to access.
+    ///
+    /// Only used by EIP-2930 and EIP-1559 transactions.
+    pub fn access_list(&mut self, access_list: Vec<AccessListItem>) -> &mut Self {
+        self.access_list = access_list;
+        self
+    }
+
+    /// Returns the Keccak-256 hash that's signed to sign the transaction.
+    #[must_use]
+    pub fn signing_hash(&self) -> [u8; 32] {
+        let bytes = match self.to_data(Vec::new(), Vec::new(), Vec::new()) {
+            // EIP-155: the chain ID takes the place of `v`, and `r` and `s` are `0`.
+            EthereumData::Legacy(mut it) => {
+                it.v = uint_bytes(self.chain_id.into());
+                it.to_bytes()
+            }
+            EthereumData::Eip2930(it) => it.to_signing_bytes(),
+            EthereumData::Eip1559(it) => it.to_signing_bytes(),
+        };
+
+        sha3::Keccak256::digest(bytes).into()
+    }
+
+    /// Signs the transaction with `key`, returning the signed transaction data.
+    ///
+    /// # Errors
+    /// - [`Error::Signature`](crate::Error::Signature) if `key` isn't an ECDSA(secp256k1) key.
+    pub fn sign(&self, key: &PrivateKey) -> crate::Result<EthereumData> {
+        let (signature, recovery_id) = key.sign_prehash_recoverable(&self.signing_hash())?;
+
+        let (r, s) = signature.split_at(32);
+
+        let v = match self.kind {
+            EthereumTransactionType::Legacy => {
+                uint_bytes(u128::from(self.chain_id) * 2 + 35 + u128::from(recovery_id))
+            }
+            EthereumTransactionType::Eip2930 | EthereumTransactionType::Eip1559 => {
+                uint_bytes(recovery_id.into())
+            }
+        };
+
+        Ok(self.to_data(v, trim_leading_zeros(r), trim_leading_zeros(s)))
+    }
+
+    /// Signs the transaction with `key`, and returns an [`EthereumFlow`] that submits it.
+    ///
+    /// # Errors
+    /// - [`Error::Signature`](crate::Error::Signature) if `key` isn't an ECDSA(secp256k1) key.
+    pub fn sign_flow(&self, key: &PrivateKey) -> crate::Result<EthereumFlow> {
+        self.sign(key).map(EthereumFlow::from)
+    }
+
+    fn to_data(&self, v: Vec<u8>, r: Vec<u8>, s: Vec<u8>) -> EthereumData {
+        let to = self.to.map(|it| it.to_bytes().to_vec()).unwrap_or_default();
+
+        match self.kind {
+            EthereumTransactionType::Legacy => EthereumData::Legacy(LegacyEthereumData {
+                nonce: uint_bytes(self.nonce.into()),
+                gas_price: uint_bytes(self.gas_price),
+                gas_limit: uint_bytes(self.gas_limit.into()),
+                to,
+                value: uint_bytes(self.value),
+                v,
+                call_data: self.call_data.clone(),
+                r,
+                s,
+            }),
+            EthereumTransactionType::Eip2930 => EthereumData::Eip2930(Eip2930EthereumData {
+                chain_id: uint_bytes(self.chain_id.into()),
+                nonce: uint_bytes(self.nonce.into()),
+                gas_price: uint_bytes(self.gas_price),
+                gas_limit: uint_bytes(self.gas_limit.into()),
+                to,
+                value: uint_bytes(self.value),
+                call_data: self.call_data.clone(),
+                access_list: self.access_list.clone(),
+                recovery_id: v,
+                r,
+                s,
+            }),
+            EthereumTransactionType::Eip1559 => EthereumData::Eip1559(Eip1559EthereumData {
+                chain_id: uint_bytes(self.chain_id.into()),
+                nonce: uint_bytes(self.nonce.into()),
+                max_priority_gas: uint_bytes(self.max_priority_fee_per_gas),
+                max_gas: uint_bytes(self.max_fee_per_gas),
+                gas_limit: uint_bytes(self.gas_limit.into()),
+                to,
+                value: uint_bytes(self.value),
+                call_data: self.call_data.clone(),
+                access_list: self.access_list.clone(),
+                recovery_id: v,
+                r,
+                s,
+            }),
+        }
+    }
+}
+
+/// Encodes `value` as big endian bytes without leading zeros, which is how RLP encodes integers.
+fn uint_bytes(value: u128) -> Vec<u8> {
+    trim_leading_zeros(&value.to_be_bytes())
+}
+
+fn trim_leading_zeros(bytes: &[u8]) -> Vec<u8> {
+    let start = bytes.iter().position(|&it| it != 0).unwrap_or(bytes.len());
+
+    bytes[start..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+    use k256::ecdsa::{
+        RecoveryId,
+        Signature,
+        VerifyingKey,
+    };
+
+    use super::{
+        uint_bytes,
+        EthereumTransactionBuilder,
+    };
+    use crate::ethereum::{
+        AccessListItem,
+        EthereumData,
+        EvmAddress,
+    };
+    use crate::{
+        Error,
+        PrivateKey,
+    };
+
+    fn key() -> PrivateKey {
+        PrivateKey::from_bytes_ecdsa(&[0x46; 32]).unwrap()
+    }
+
+    /// Asserts that the signature in `data` was made by `key` over `hash`.
+    #[track_caller]
+    fn assert_signed_by(hash: &[u8; 32], recovery_id: u8, r: &[u8], s: &[u8], key: &PrivateKey) {
+        let mut signature = [0; 64];
+        signature[32 - r.len()..32].copy_from_slice(r);
+        signature[64 - s.len()..].copy_from_slice(s);
+
+        let recovered = VerifyingKey::recover_from_prehash(
+            hash,
+            &Signature::from_slice(&signature).unwrap(),
+            RecoveryId::from_byte(recovery_id).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            recovered.to_encoded_point(true).as_bytes(),
+            key.public_key().to_bytes_raw().as_slice()
+        );
+    }
+
+    // the example from EIP-155.
+    #[test]
+    fn legacy() {
+        let mut builder = EthereumTransactionBuilder::legacy(1);
+        builder
+            .nonce(9)
+            .gas_price(20_000_000_000)
+            .gas_limit(21_000)
+            .to(EvmAddress::from([0x35; 20]))
+            .value(1_000_000_000_000_000_000);
+
+        assert_eq!(
+            builder.signing_hash(),
+            hex!("daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53")
+        );
+
+        let data = builder.sign(&key()).unwrap();
+
+        assert_eq!(
+            hex::encode(data.to_bytes()),
+            concat!(
+                "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025",
+                "a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276",
+                "a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+            )
+        );
+    }
+
+    #[test]
+    fn eip1559() {
+        let mut builder = EthereumTransactionBuilder::eip1559(298);
+        builder
+            .nonce(2)
+            .max_priority_fee_per_gas(0)
+            .max_fee_per_gas(710_000_000_000)
+            .gas_limit(150_000)
+            .to(EvmAddress::from([0x11; 20]))
+            .call_data(hex!("123456").to_vec());
+
+        let data = builder.sign(&key()).unwrap();
+        let bytes = data.to_bytes();
+
+        assert_eq!(bytes[0], 0x02);
+
+        let data =
+            assert_matches!(EthereumData::from_bytes(&bytes), Ok(EthereumData::Eip1559(it)) => it);
+
+        assert_eq!(data.chain_id, uint_bytes(298));
+        assert_eq!(data.nonce, [2_u8]);
+        assert!(data.max_priority_gas.is_empty());
+        assert_eq!(data.call_data, hex!("123456"));
+
+        let recovery_id = data.recovery_id.first().copied().unwrap_or(0);
+        assert_signed_by(&builder.signing_hash(), recovery_id, &data.r, &data.s, &key());
+    }
+
+    #[test]
+    fn eip2930_access_list() {
+        let access_list = vec![
+            AccessListItem {
+                address: EvmAddress::from([0x22; 20]),
+                storage_keys: vec![[0; 32], [1; 32]],
+            },
+            AccessListItem { address: EvmAddress::from([0x33; 20]), storage_keys: Vec::new() },
+        ];
+
+        let mut builder = EthereumTransactionBuilder::eip2930(296);
+        builder
+            .gas_price(710_000_000_000)
+            .gas_limit(21_000)
+            .to(EvmAddress::from([0x11; 20]))
+            .value(10_000_000_000)
+            .access_list(access_list.clone());
+
+        let bytes = builder.sign(&key()).unwrap().to_bytes();
+
+        assert_eq!(bytes[0], 0x01);
+
+        let data =
+            assert_matches!(EthereumData::from_bytes(&bytes), Ok(EthereumData::Eip2930(it)) => it);
+
+        assert_eq!(data.access_list, access_list);
+        assert_eq!(data.to_bytes(), bytes);
+
+        let recovery_id = data.recovery_id.first().copied().unwrap_or(0);
+        assert_signed_by(&builder.signing_hash(), recovery_id, &data.r, &data.s, &key());
+    }
+
+    #[test]
+    fn contract_creation() {
+        let mut builder = EthereumTransactionBuilder::eip1559(298);
+        builder.call_data(vec![0x60, 0x80]);
+
+        let data = builder.sign(&key()).unwrap();
+
+        let data = assert_matches!(data, EthereumData::Eip1559(it) => it);
+        assert!(data.to.is_empty());
+    }
+
+    #[test]
+    fn ed25519_key() {
+        let res = EthereumTransactionBuilder::legacy(296).sign(&PrivateKey::generate_ed25519());
+
+        assert_matches!(res, Err(Error::Signature(_)));
+    }
+}
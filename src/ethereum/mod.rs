@@ -18,18 +18,31 @@
  * ‍
  */
 
+mod eip191;
+#[cfg(feature = "serde")]
+mod eip712;
 mod ethereum_data;
 mod ethereum_flow;
 mod ethereum_transaction;
+mod ethereum_transaction_builder;
 mod evm_address;
 
+pub(crate) use eip191::hash_personal_message;
+#[cfg(feature = "serde")]
+pub use eip712::Eip712TypedData;
 pub use ethereum_data::{
+    AccessListItem,
     Eip1559EthereumData,
+    Eip2930EthereumData,
     EthereumData,
     LegacyEthereumData,
 };
 pub use ethereum_flow::EthereumFlow;
 pub use ethereum_transaction::EthereumTransaction;
 pub(crate) use ethereum_transaction::EthereumTransactionData;
+pub use ethereum_transaction_builder::{
+    EthereumTransactionBuilder,
+    EthereumTransactionType,
+};
 pub use evm_address::EvmAddress;
 pub(crate) use evm_address::SolidityAddress;
@@ -18,10 +18,17 @@
  * ‍
  */
 mod error;
+#[cfg(all(test, feature = "testing"))]
+mod tests;
 
 use std::any::type_name;
 use std::borrow::Cow;
 use std::ops::ControlFlow;
+use std::sync::atomic::{
+    AtomicBool,
+    AtomicUsize,
+    Ordering,
+};
 use std::time::{
     Duration,
     Instant,
@@ -33,6 +40,7 @@ use backoff::{
 };
 use futures_core::future::BoxFuture;
 use futures_util::StreamExt;
+use hedera_proto::services;
 use prost::Message;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
@@ -40,9 +48,22 @@ use tonic::metadata::AsciiMetadataValue;
 use tonic::transport::Channel;
 use triomphe::Arc;
 
-use crate::client::NetworkData;
+#[cfg(feature = "bulk-executor")]
+use crate::bulk_executor::{
+    current_throttle,
+    Throttle,
+};
+use crate::client::{
+    NetworkData,
+    TransactionIdGenerator,
+};
 use crate::execute::error::is_tonic_status_transient;
+use crate::interceptor::Interceptors;
 use crate::ping_query::PingQuery;
+use crate::submission_journal::{
+    self,
+    Journal,
+};
 use crate::{
     client,
     retry,
@@ -50,6 +71,8 @@ use crate::{
     BoxGrpcFuture,
     Client,
     Error,
+    RequestEvent,
+    ResponseEvent,
     Status,
     TransactionId,
     ValidateChecksums,
@@ -88,6 +111,14 @@ pub(crate) trait Execute: ValidateChecksums {
         None
     }
 
+    /// Returns whether to execute idempotently.
+    ///
+    /// Idempotent requests never regenerate their transaction ID,
+    /// and treat [`Status::DuplicateTransaction`] as an earlier attempt having gone through.
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
     /// Check whether to retry an pre-check status.
     fn should_retry_pre_check(&self, _status: Status) -> bool {
         false
@@ -101,13 +132,15 @@ pub(crate) trait Execute: ValidateChecksums {
 
     /// Create a new request for execution.
     ///
+    /// This is async because creating a request may involve signing it with a [`Signer`](crate::Signer).
+    ///
     /// A created request is cached per node until any request returns
     /// `TransactionExpired`; in which case, the request cache is cleared.
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)>;
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>>;
 
     /// Execute the created GRPC request against the provided GRPC channel.
     fn execute(
@@ -136,6 +169,14 @@ pub(crate) trait Execute: ValidateChecksums {
 
     /// Extract the pre-check status from the GRPC response.
     fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32>;
+
+    /// Returns the transaction that the request submits, if it submits one.
+    ///
+    /// Submitted transactions are recorded in the client's [`SubmissionJournal`](crate::SubmissionJournal).
+    #[allow(unused_variables)]
+    fn submitted_transaction(request: &Self::GrpcRequest) -> Option<&services::Transaction> {
+        None
+    }
 }
 
 struct ExecuteContext {
@@ -146,6 +187,16 @@ struct ExecuteContext {
     max_attempts: usize,
     // timeout for a single grpc request.
     grpc_timeout: Option<Duration>,
+    interceptors: Interceptors,
+    transaction_id_generator: TransactionIdGenerator,
+    journal: Option<Journal>,
+    // set once a journaled transaction was sent without hearing back from the node,
+    // after which a rejection from another node doesn't mean the transaction didn't go through.
+    journal_delivery_unknown: AtomicBool,
+    idempotent: bool,
+    // `Some` when this is part of a bulk execution.
+    #[cfg(feature = "bulk-executor")]
+    throttle: Option<Arc<Throttle>>,
 }
 
 pub(crate) async fn execute<E>(
@@ -167,6 +218,7 @@ where
 
     let operator_account_id = 'op: {
         if executable.transaction_id().is_some()
+            || executable.is_idempotent()
             || !executable
                 .regenerate_transaction_id()
                 .unwrap_or(client.default_regenerate_transaction_id())
@@ -198,12 +250,27 @@ where
             operator_account_id,
             network: client.net().0.load_full(),
             grpc_timeout: backoff.grpc_timeout,
+            interceptors: client.interceptors(),
+            transaction_id_generator: client.transaction_id_generator(),
+            journal: client.submission_journal(),
+            journal_delivery_unknown: AtomicBool::new(false),
+            idempotent: executable.is_idempotent(),
+            #[cfg(feature = "bulk-executor")]
+            throttle: current_throttle(),
         },
         executable,
     )
     .await
 }
 
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "hedera.execute",
+        skip_all,
+        fields(executable = type_name::<E>(), transaction_id = tracing::field::Empty)
+    )
+)]
 async fn execute_inner<E>(ctx: &ExecuteContext, executable: &E) -> crate::Result<E::Response>
 where
     E: Execute + Sync,
@@ -216,6 +283,15 @@ where
                 backoff_config: ctx.backoff_config.clone(),
                 max_attempts: ctx.max_attempts,
                 grpc_timeout: ctx.grpc_timeout,
+                interceptors: ctx.interceptors.clone(),
+                transaction_id_generator: ctx.transaction_id_generator.clone(),
+                // pings don't submit transactions.
+                journal: None,
+                journal_delivery_unknown: AtomicBool::new(false),
+                idempotent: false,
+                // pings shouldn't wait behind the requests they're checking the node for.
+                #[cfg(feature = "bulk-executor")]
+                throttle: None,
             };
             let ping_query = PingQuery::new(ctx.network.node_ids()[index]);
 
@@ -231,10 +307,17 @@ where
     // if we need to generate a transaction ID for this request (and one was not provided),
     // generate one now
     let explicit_transaction_id = executable.transaction_id();
-    let mut transaction_id = executable
-        .requires_transaction_id()
-        .then_some(explicit_transaction_id)
-        .and_then(|it| it.or_else(|| ctx.operator_account_id.map(TransactionId::generate)));
+    let mut transaction_id =
+        executable.requires_transaction_id().then_some(explicit_transaction_id).and_then(|it| {
+            it.or_else(|| {
+                ctx.operator_account_id.map(|it| ctx.transaction_id_generator.generate(it))
+            })
+        });
+
+    #[cfg(feature = "tracing")]
+    if let Some(transaction_id) = &transaction_id {
+        tracing::Span::current().record("transaction_id", tracing::field::display(transaction_id));
+    }
 
     // if we were explicitly given a list of nodes to use, we iterate through each
     // of the given nodes (in a random order)
@@ -245,7 +328,13 @@ where
 
     let explicit_node_indexes = explicit_node_indexes.as_deref();
 
-    let layer = move || async move {
+    // counts calls to `execute_single` across backoffs, for interceptors.
+    let attempts = &AtomicUsize::new(0);
+
+    let layer = move |backoff_delay: Duration| async move {
+        // only the first attempt after a backoff was actually delayed by it.
+        let mut backoff_delay = Some(backoff_delay);
+
         loop {
             let mut last_error: Option<Error> = None;
 
@@ -269,7 +358,17 @@ where
             let mut random_node_indexes = std::pin::pin!(random_node_indexes);
 
             while let Some(node_index) = random_node_indexes.next().await {
-                let tmp = execute_single(ctx, executable, node_index, &mut transaction_id).await;
+                let attempt = attempts.fetch_add(1, Ordering::Relaxed) + 1;
+
+                let tmp = execute_single(
+                    ctx,
+                    executable,
+                    node_index,
+                    attempt,
+                    backoff_delay.take().unwrap_or_default(),
+                    &mut transaction_id,
+                )
+                .await;
 
                 log::log!(
                     match &tmp {
@@ -321,11 +420,14 @@ fn map_tonic_error(
 ) -> retry::Error {
     const MIME_HTML: &[u8] = b"text/html";
 
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("grpc_status", tracing::field::debug(status.code()));
+
     match status.code() {
         // if the node says it isn't available, then we should just try again with a different node.
         tonic::Code::Unavailable | tonic::Code::ResourceExhausted => {
             // NOTE: this is an "unhealthy" node
-            network.mark_node_unhealthy(node_index);
+            mark_node_unhealthy(network, node_index);
 
             // try the next node in our allowed list, immediately
             retry::Error::Transient(status.into())
@@ -340,7 +442,7 @@ fn map_tonic_error(
             if status.metadata().get("content-type").map(AsciiMetadataValue::as_bytes)
                 == Some(MIME_HTML) =>
         {
-            network.mark_node_unhealthy(node_index);
+            mark_node_unhealthy(network, node_index);
 
             // hack to the hack:
             // if this is a free request let's try retrying it anyway...
@@ -351,7 +453,7 @@ fn map_tonic_error(
         }
 
         _ if is_tonic_status_transient(&status) => {
-            network.mark_node_unhealthy(node_index);
+            mark_node_unhealthy(network, node_index);
 
             retry::Error::Transient(status.into())
         }
@@ -361,10 +463,36 @@ fn map_tonic_error(
     }
 }
 
+fn mark_node_unhealthy(network: &client::NetworkData, node_index: usize) {
+    network.mark_node_unhealthy(node_index);
+
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("unhealthy", true);
+}
+
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(
+        name = "hedera.attempt",
+        skip_all,
+        fields(
+            node_account_id = %ctx.network.node_ids()[node_index],
+            attempt = attempt,
+            transaction_id = transaction_id.as_ref().map(tracing::field::display),
+            backoff = ?backoff,
+            status = tracing::field::Empty,
+            grpc_status = tracing::field::Empty,
+            unhealthy = false,
+        )
+    )
+)]
 async fn execute_single<E: Execute + Sync>(
     ctx: &ExecuteContext,
     executable: &E,
     node_index: usize,
+    attempt: usize,
+    // how long was spent backing off before this attempt, only used for tracing.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))] backoff: Duration,
     transaction_id: &mut Option<TransactionId>,
 ) -> retry::Result<ControlFlow<E::Response, Error>> {
     let (node_account_id, channel) = ctx.network.channel(node_index);
@@ -376,6 +504,7 @@ async fn execute_single<E: Execute + Sync>(
 
     let (request, context) = executable
         .make_request(transaction_id.as_ref(), node_account_id)
+        .await
         // Does not represent a network error or error returned by a node
         .map_err(retry::Error::Permanent)?;
 
@@ -384,22 +513,104 @@ async fn execute_single<E: Execute + Sync>(
         type_name::<E>()
     );
 
+    // in a bulk execution, wait for a free slot on this node and for any back-off to end.
+    #[cfg(feature = "bulk-executor")]
+    let _permit = match &ctx.throttle {
+        Some(throttle) => Some(throttle.acquire(node_account_id).await),
+        None => None,
+    };
+
+    // journal the transaction before it's sent, so it can be recovered if we never learn what happened to it.
+    let journaled = match (&ctx.journal, E::submitted_transaction(&request)) {
+        (Some(journal), Some(transaction)) => Some(
+            submission_journal::record_entry(journal, transaction)
+                .await
+                .map_err(retry::Error::Permanent)?,
+        ),
+        _ => None,
+    };
+
+    // encoding the request isn't free, so only do it when someone is going to look at it.
+    let request_bytes =
+        if ctx.interceptors.is_empty() { Vec::new() } else { request.encode_to_vec() };
+
+    ctx.interceptors.on_request(&RequestEvent {
+        executable: type_name::<E>(),
+        node_account_id,
+        attempt,
+        request: &request_bytes,
+    });
+
+    let intercept_response = |response: Result<&E::GrpcResponse, &tonic::Status>, latency| {
+        if ctx.interceptors.is_empty() {
+            return;
+        }
+
+        let response_bytes = response.map(Message::encode_to_vec);
+
+        ctx.interceptors.on_response(&ResponseEvent {
+            executable: type_name::<E>(),
+            node_account_id,
+            attempt,
+            request: &request_bytes,
+            response: response_bytes.as_deref().map_err(|&it| it),
+            pre_check_status: response
+                .ok()
+                .and_then(|it| E::response_pre_check_status(it).ok())
+                .and_then(|it| Status::try_from(it).ok()),
+            latency,
+        });
+    };
+
+    let started_at = Instant::now();
     let fut = executable.execute(channel, request);
 
     let response = match ctx.grpc_timeout {
         Some(it) => match tokio::time::timeout(it, fut).await {
             Ok(it) => it,
             Err(_) => {
-                return Ok(ControlFlow::Continue(crate::Error::GrpcStatus(
-                    tonic::Status::deadline_exceeded("explicitly given grpc timeout was exceeded"),
-                )))
+                let status =
+                    tonic::Status::deadline_exceeded("explicitly given grpc timeout was exceeded");
+
+                #[cfg(feature = "tracing")]
+                tracing::Span::current()
+                    .record("grpc_status", tracing::field::debug(status.code()));
+
+                intercept_response(Err(&status), started_at.elapsed());
+
+                if journaled.is_some() {
+                    ctx.journal_delivery_unknown.store(true, Ordering::Relaxed);
+                }
+
+                return Ok(ControlFlow::Continue(crate::Error::GrpcStatus(status)));
             }
         },
         None => fut.await,
     };
 
-    let response = response.map(tonic::Response::into_inner).map_err(|status| {
-        map_tonic_error(status, &ctx.network, node_index, transaction_id.is_none())
+    let response = response.map(tonic::Response::into_inner);
+
+    intercept_response(response.as_ref(), started_at.elapsed());
+
+    if journaled.is_some() && response.is_err() {
+        ctx.journal_delivery_unknown.store(true, Ordering::Relaxed);
+    }
+
+    #[cfg(feature = "bulk-executor")]
+    if let (Some(throttle), Err(status)) = (&ctx.throttle, &response) {
+        if status.code() == tonic::Code::ResourceExhausted {
+            throttle.back_off();
+        }
+    }
+
+    // retrying an idempotent request is as harmless as retrying a free one.
+    let response = response.map_err(|status| {
+        map_tonic_error(
+            status,
+            &ctx.network,
+            node_index,
+            transaction_id.is_none() || ctx.idempotent,
+        )
     });
 
     let response = match response {
@@ -421,6 +632,32 @@ async fn execute_single<E: Execute + Sync>(
         })
         .map_err(retry::Error::Permanent)?;
 
+    #[cfg(feature = "tracing")]
+    tracing::Span::current().record("status", tracing::field::debug(status));
+
+    #[cfg(feature = "bulk-executor")]
+    if let Some(throttle) = &ctx.throttle {
+        throttle.observe_status(status);
+    }
+
+    // the node turned the transaction down, so unless another node might have taken it, it's done.
+    if let (Some(journal), Some(journaled)) = (&ctx.journal, &journaled) {
+        if !matches!(status, Status::Ok | Status::DuplicateTransaction)
+            && !ctx.journal_delivery_unknown.load(Ordering::Relaxed)
+        {
+            submission_journal::remove_entry(journal, journaled).await;
+        }
+    }
+
+    // a duplicate idempotent request was already submitted, by an earlier attempt or an earlier execution,
+    // and the receipt for its transaction ID is the receipt of that submission.
+    // note: the response still names this node and this request's hash,
+    // the earlier submission might well have gone to another node, so only the transaction ID can be relied on.
+    let status = match status {
+        Status::DuplicateTransaction if ctx.idempotent => Status::Ok,
+        status => status,
+    };
+
     match status {
         Status::Ok if executable.should_retry(&response) => Err(retry::Error::Transient(
             executable.make_error_pre_check(status, transaction_id.as_ref(), response),
@@ -446,7 +683,7 @@ async fn execute_single<E: Execute + Sync>(
             // the transaction that was generated has since expired
             // re-generate the transaction ID and try again, immediately
 
-            let new = TransactionId::generate(ctx.operator_account_id.unwrap());
+            let new = ctx.transaction_id_generator.generate(ctx.operator_account_id.unwrap());
 
             *transaction_id = Some(new);
 
@@ -0,0 +1,327 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::testing::{
+    MockNetwork,
+    MockResponse,
+};
+use crate::{
+    AccountId,
+    Client,
+    FileAppendTransaction,
+    FileId,
+    Hbar,
+    PrivateKey,
+    Status,
+    TransactionId,
+    TransferTransaction,
+};
+
+#[cfg(feature = "tracing")]
+mod spans {
+    use std::collections::BTreeMap;
+    use std::fmt;
+
+    use parking_lot::Mutex;
+    use tracing::field::{
+        Field,
+        Visit,
+    };
+    use tracing::span::{
+        Attributes,
+        Id,
+        Record,
+    };
+    use tracing_subscriber::layer::{
+        Context,
+        SubscriberExt,
+    };
+    use tracing_subscriber::registry::LookupSpan;
+    use tracing_subscriber::Layer;
+    use triomphe::Arc;
+
+    type Fields = BTreeMap<&'static str, String>;
+
+    /// Collects the SDK's spans (and their fields) as they close.
+    #[derive(Clone, Default)]
+    pub(super) struct Spans(Arc<Mutex<Vec<(&'static str, Fields)>>>);
+
+    impl Spans {
+        /// Collects spans until the returned guard is dropped.
+        pub(super) fn capture() -> (Self, tracing::subscriber::DefaultGuard) {
+            let spans = Self::default();
+            let guard = tracing::subscriber::set_default(
+                tracing_subscriber::registry().with(spans.clone()),
+            );
+
+            (spans, guard)
+        }
+
+        pub(super) fn named(&self, name: &str) -> Vec<Fields> {
+            self.0
+                .lock()
+                .iter()
+                .filter(|(it, _)| *it == name)
+                .map(|(_, fields)| fields.clone())
+                .collect()
+        }
+    }
+
+    struct Visitor<'a>(&'a mut Fields);
+
+    impl Visit for Visitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.insert(field.name(), format!("{value:?}"));
+        }
+    }
+
+    impl<S: tracing::Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Spans {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+            let mut fields = Fields::new();
+            attrs.record(&mut Visitor(&mut fields));
+
+            ctx.span(id).unwrap().extensions_mut().insert(fields);
+        }
+
+        fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+            let span = ctx.span(id).unwrap();
+            let mut extensions = span.extensions_mut();
+
+            values.record(&mut Visitor(extensions.get_mut::<Fields>().unwrap()));
+        }
+
+        fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+            let span = ctx.span(&id).unwrap();
+
+            if span.name().starts_with("hedera.") {
+                let fields = span.extensions_mut().remove::<Fields>().unwrap();
+                self.0.lock().push((span.name(), fields));
+            }
+        }
+    }
+}
+
+async fn client(network: &MockNetwork) -> Client {
+    let client = network.client().unwrap();
+    client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+
+    // keep the pings out of the traces.
+    for node in network.nodes() {
+        client.ping(node.account_id()).await.unwrap();
+    }
+
+    client
+}
+
+fn transfer() -> TransferTransaction {
+    let mut tx = TransferTransaction::new();
+    tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+        .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1));
+
+    tx
+}
+
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn attempts_are_traced() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network).await;
+
+    let (spans, _guard) = spans::Spans::capture();
+
+    network.nodes()[0].push_response(MockResponse::Precheck(Status::Busy));
+
+    let response = transfer().execute(&client).await.unwrap();
+    let transaction_id = response.transaction_id.to_string();
+
+    let executions = spans.named("hedera.execute");
+    assert_eq!(executions.len(), 1);
+    assert_eq!(executions[0]["transaction_id"], transaction_id);
+
+    let attempts = spans.named("hedera.attempt");
+    assert_eq!(attempts.len(), 2);
+
+    for (attempt, fields) in attempts.iter().enumerate() {
+        assert_eq!(fields["attempt"], (attempt + 1).to_string());
+        assert_eq!(fields["node_account_id"], "0.0.3");
+        assert_eq!(fields["transaction_id"], transaction_id);
+        assert_eq!(fields["unhealthy"], "false");
+    }
+
+    assert_eq!(attempts[0]["status"], "Busy");
+    assert_eq!(attempts[0]["backoff"], "0ns");
+    assert_eq!(attempts[1]["status"], "Ok");
+    assert_ne!(attempts[1]["backoff"], "0ns");
+}
+
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn grpc_errors_are_traced() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network).await;
+
+    let (spans, _guard) = spans::Spans::capture();
+
+    network.nodes()[0]
+        .push_response(MockResponse::GrpcStatus(tonic::Status::permission_denied("no")));
+
+    transfer().execute(&client).await.unwrap_err();
+
+    let attempts = spans.named("hedera.attempt");
+    assert_eq!(attempts.len(), 1);
+    assert_eq!(attempts[0]["grpc_status"], "PermissionDenied");
+    assert!(!attempts[0].contains_key("status"));
+}
+
+#[tokio::test]
+async fn duplicate_is_a_pre_check_error() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network).await;
+
+    network.nodes()[0].push_response(MockResponse::Precheck(Status::DuplicateTransaction));
+
+    let err = transfer().execute(&client).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        crate::Error::TransactionPreCheckStatus { status: Status::DuplicateTransaction, .. }
+    ));
+}
+
+#[tokio::test]
+async fn idempotent_duplicate_is_success() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network).await;
+
+    let mut tx = transfer();
+    // an explicit node is retried even while it's marked unhealthy.
+    tx.node_account_ids([AccountId::new(0, 0, 3)]).idempotent(true);
+
+    // the first submission times out without an answer, the retry finds that the node has it already.
+    network.nodes()[0]
+        .push_response(MockResponse::GrpcStatus(tonic::Status::unavailable("timed out")))
+        .push_response(MockResponse::Precheck(Status::DuplicateTransaction));
+
+    let response = tx.execute(&client).await.unwrap();
+
+    assert_eq!(tx.get_transaction_id(), Some(response.transaction_id));
+    assert_eq!(response.get_receipt(&client).await.unwrap().status, Status::Success);
+
+    // executing it again is just as harmless.
+    network.nodes()[0].push_response(MockResponse::Precheck(Status::DuplicateTransaction));
+
+    let again = tx.execute(&client).await.unwrap();
+    assert_eq!(again.transaction_id, response.transaction_id);
+}
+
+#[tokio::test]
+async fn idempotent_duplicate_from_another_node() {
+    let network = MockNetwork::start(2).await.unwrap();
+    let client = client(&network).await;
+
+    let [first, second] = [network.nodes()[0].account_id(), network.nodes()[1].account_id()];
+    let transaction_id = TransactionId::generate(AccountId::new(0, 0, 2));
+
+    // the first submission gets through, but say its response was lost.
+    let mut tx = transfer();
+    tx.transaction_id(transaction_id).node_account_ids([first]).idempotent(true);
+    tx.execute(&client).await.unwrap();
+
+    // so it gets rebuilt and resubmitted to another node, which has seen it by then.
+    network.nodes()[1].push_response(MockResponse::Precheck(Status::DuplicateTransaction));
+
+    let mut tx = transfer();
+    tx.transaction_id(transaction_id).node_account_ids([second]).idempotent(true);
+    let response = tx.execute(&client).await.unwrap();
+
+    assert_eq!(response.transaction_id, transaction_id);
+    // the node is the one that answered with the duplicate, not the one that got it through.
+    assert_eq!(response.node_account_id, second);
+    assert_eq!(response.get_receipt(&client).await.unwrap().status, Status::Success);
+
+    assert!(network.nodes()[0]
+        .requests()
+        .iter()
+        .any(|path| path == "/proto.CryptoService/cryptoTransfer"));
+}
+
+#[tokio::test]
+async fn idempotent_chunks_keep_transaction_ids() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network).await;
+
+    let mut tx = FileAppendTransaction::new();
+    tx.file_id(FileId::new(0, 0, 1001))
+        .contents(vec![0; 25])
+        .chunk_size(10)
+        .node_account_ids([AccountId::new(0, 0, 3)])
+        .idempotent(true);
+
+    let responses = tx.execute_all(&client).await.unwrap();
+    let transaction_ids: Vec<_> = responses.iter().map(|it| it.transaction_id).collect();
+
+    assert_eq!(transaction_ids.len(), 3);
+    assert_eq!(tx.get_transaction_id(), Some(transaction_ids[0]));
+
+    for (chunk, transaction_id) in transaction_ids.iter().enumerate() {
+        assert_eq!(transaction_id.account_id, AccountId::new(0, 0, 2));
+        assert_eq!(
+            transaction_id.valid_start,
+            transaction_ids[0].valid_start + time::Duration::nanoseconds(chunk as i64)
+        );
+    }
+
+    // the IDs were reserved along with the first one.
+    let next = client.generate_transaction_id(AccountId::new(0, 0, 2));
+    assert!(next.valid_start > transaction_ids[2].valid_start);
+
+    // resubmitting every chunk finds that the node has them all already.
+    for _ in 0..3 {
+        network.nodes()[0].push_response(MockResponse::Precheck(Status::DuplicateTransaction));
+    }
+
+    let again = tx.execute_all(&client).await.unwrap();
+    assert_eq!(again.iter().map(|it| it.transaction_id).collect::<Vec<_>>(), transaction_ids);
+}
+
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn idempotent_keeps_transaction_id() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network).await;
+
+    let (spans, _guard) = spans::Spans::capture();
+
+    network.nodes()[0].push_response(MockResponse::Precheck(Status::TransactionExpired));
+
+    let mut tx = transfer();
+    tx.idempotent(true);
+
+    let err = tx.execute(&client).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        crate::Error::TransactionPreCheckStatus { status: Status::TransactionExpired, .. }
+    ));
+
+    let attempts = spans.named("hedera.attempt");
+    assert_eq!(attempts.len(), 1);
+    assert_eq!(attempts[0]["transaction_id"], tx.get_transaction_id().unwrap().to_string());
+}
@@ -0,0 +1,632 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use prost::Message;
+
+use crate::query::QueryExecute;
+use crate::transaction::TransactionExecute;
+use crate::{
+    Error,
+    ExchangeRate,
+    FeeComponents,
+    FeeData,
+    FeeDataType,
+    FeeSchedule,
+    FeeSchedules,
+    Hbar,
+    Query,
+    RequestType,
+    Transaction,
+};
+
+#[cfg(test)]
+mod tests;
+
+// sizes (in bytes) and durations the network uses when it calculates fees.
+const LONG_SIZE: u64 = 8;
+const INT_SIZE: u64 = 4;
+const TX_HASH_SIZE: u64 = 48;
+const BASIC_ENTITY_ID_SIZE: u64 = 3 * LONG_SIZE;
+const BASIC_ACCOUNT_AMT_SIZE: u64 = BASIC_ENTITY_ID_SIZE + LONG_SIZE;
+const BASIC_TX_ID_SIZE: u64 = BASIC_ENTITY_ID_SIZE + LONG_SIZE;
+const EXCHANGE_RATE_SIZE: u64 = 2 * INT_SIZE + LONG_SIZE;
+const BASIC_RECEIPT_SIZE: u64 = INT_SIZE + 2 * EXCHANGE_RATE_SIZE;
+const BASIC_TX_RECORD_SIZE: u64 =
+    BASIC_RECEIPT_SIZE + TX_HASH_SIZE + LONG_SIZE + BASIC_TX_ID_SIZE + LONG_SIZE;
+const BASE_FILEINFO_SIZE: u64 = BASIC_ENTITY_ID_SIZE + LONG_SIZE;
+const BASIC_QUERY_HEADER: u64 = 212;
+const BASIC_QUERY_RES_HEADER: u64 = 2 * INT_SIZE + LONG_SIZE;
+const RECEIPT_STORAGE_TIME_SEC: u64 = 180;
+const HRS_DIVISOR: u64 = 3600;
+
+/// Prices in a fee schedule are in thousandths of a tinycent.
+const FEE_DIVISOR_FACTOR: u128 = 1000;
+
+/// How much of each priced resource a request uses, in the units of [`FeeComponents`].
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct ResourceUsage {
+    /// How many times the constant price is charged, `1` for anything that's charged at all.
+    pub constant: u64,
+
+    /// Bytes of request data.
+    pub bandwidth_byte: u64,
+
+    /// Signatures to verify.
+    pub verification: u64,
+
+    /// Storage in memory, in byte-hours.
+    pub ram_byte_hour: u64,
+
+    /// Storage on disk, in byte-hours.
+    pub storage_byte_hour: u64,
+
+    /// Gas used by smart contract execution.
+    pub contract_transaction_gas: u64,
+
+    /// Bytes of response data retrieved from memory.
+    pub response_memory_byte: u64,
+}
+
+impl ResourceUsage {
+    /// Returns the fee for this usage at `prices`, in tinycents.
+    ///
+    /// Like the network, the fee is clamped to `prices.min..=prices.max`,
+    /// and a fee that would round down to zero is charged as `1`.
+    fn fee_in_tinycents(&self, prices: &FeeComponents) -> u64 {
+        let fee = [
+            (prices.constant, self.constant),
+            (prices.bandwidth_byte, self.bandwidth_byte),
+            (prices.verification, self.verification),
+            (prices.ram_byte_hour, self.ram_byte_hour),
+            (prices.storage_byte_hour, self.storage_byte_hour),
+            (prices.contract_transaction_gas, self.contract_transaction_gas),
+            (prices.response_memory_byte, self.response_memory_byte),
+        ]
+        .into_iter()
+        .map(|(price, usage)| u128::from(price) * u128::from(usage))
+        .sum::<u128>();
+
+        let fee = fee.clamp(u128::from(prices.min), u128::from(prices.max.max(prices.min)));
+
+        match fee {
+            0 => 0,
+            fee => u64::try_from((fee / FEE_DIVISOR_FACTOR).max(1)).unwrap_or(u64::MAX),
+        }
+    }
+}
+
+/// The resources a transaction or query is expected to use, split the same way as [`FeeData`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FeeUsage {
+    /// The kind of request, which picks the prices from the fee schedule.
+    pub request_type: RequestType,
+
+    /// The subtype of the request, which picks between prices for the same `request_type`.
+    pub kind: FeeDataType,
+
+    /// Resources used by the node that receives the request.
+    pub node: ResourceUsage,
+
+    /// Resources used by the network to reach consensus on the request.
+    pub network: ResourceUsage,
+
+    /// Resources used to carry out the request.
+    pub service: ResourceUsage,
+}
+
+impl FeeUsage {
+    /// Estimates the resources `transaction` will use.
+    ///
+    /// The estimate is based on the transaction as it will be submitted,
+    /// so it should be signed by all the keys it needs first.
+    /// For chunked transactions this is the usage of the first (and largest) chunk,
+    /// which is what [`max_transaction_fee`](Transaction::max_transaction_fee) applies to.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if the transaction has no transaction ID and wasn't frozen with an operator.
+    /// - [`Error::BasicParse`] if the transaction's expiration time is too far from its valid start to estimate storage for.
+    ///
+    /// # Panics
+    /// - If `transaction` isn't frozen.
+    pub fn for_transaction<D: TransactionExecute>(
+        transaction: &Transaction<D>,
+    ) -> crate::Result<Self> {
+        assert!(transaction.is_frozen(), "Transaction must be frozen to estimate its fee");
+
+        let sources = transaction.make_sources()?;
+
+        Self::for_signed_transaction(&sources.signed_transactions()[0])
+    }
+
+    fn for_signed_transaction(
+        signed_transaction: &services::SignedTransaction,
+    ) -> crate::Result<Self> {
+        let body = services::TransactionBody::decode(signed_transaction.body_bytes.as_slice())
+            .map_err(Error::from_protobuf)?;
+
+        let data = body
+            .data
+            .as_ref()
+            .ok_or_else(|| Error::from_protobuf("unexpected missing `data` in transaction body"))?;
+
+        let signatures = signed_transaction.sig_map.as_ref().map_or(0, |it| it.sig_pair.len());
+
+        // the payer always has to sign, even if they haven't yet.
+        let verification = (signatures as u64).max(1);
+
+        let bandwidth_byte = (signed_transaction.body_bytes.len()
+            + signed_transaction.sig_map.as_ref().map_or(0, Message::encoded_len))
+            as u64;
+
+        let record_size = BASIC_TX_RECORD_SIZE + body.memo.len() as u64 + transfers_size(data);
+
+        let service = ResourceUsage {
+            constant: 1,
+            ram_byte_hour: byte_hours(record_size * RECEIPT_STORAGE_TIME_SEC),
+            storage_byte_hour: byte_hours(storage_byte_seconds(&body, data)?),
+            contract_transaction_gas: gas(data),
+            ..ResourceUsage::default()
+        };
+
+        Ok(Self {
+            request_type: transaction_request_type(data),
+            kind: transaction_kind(data),
+            node: ResourceUsage {
+                constant: 1,
+                bandwidth_byte,
+                // nodes only check the payer's signature.
+                verification: 1,
+                response_memory_byte: INT_SIZE,
+                ..ResourceUsage::default()
+            },
+            network: ResourceUsage {
+                constant: 1,
+                bandwidth_byte,
+                verification,
+                ram_byte_hour: byte_hours(BASIC_RECEIPT_SIZE * RECEIPT_STORAGE_TIME_SEC),
+                ..ResourceUsage::default()
+            },
+            service,
+        })
+    }
+
+    /// Estimates the resources `query` will use.
+    ///
+    /// Queries are only charged by the node that answers them,
+    /// and their response size isn't known ahead of time, so only the response header is accounted for.
+    #[must_use]
+    pub fn for_query<D: QueryExecute>(query: &Query<D>) -> Self {
+        let pb = query.data.to_query_protobuf(services::QueryHeader::default());
+
+        let request_type = match pb.query.as_ref() {
+            Some(it) => query_request_type(it),
+            None => RequestType::None,
+        };
+
+        let node = if query.data.is_payment_required() {
+            ResourceUsage {
+                constant: 1,
+                bandwidth_byte: BASIC_QUERY_HEADER + pb.encoded_len() as u64,
+                response_memory_byte: BASIC_QUERY_RES_HEADER,
+                ..ResourceUsage::default()
+            }
+        } else {
+            ResourceUsage::default()
+        };
+
+        Self {
+            request_type,
+            kind: FeeDataType::Default,
+            node,
+            network: ResourceUsage::default(),
+            service: ResourceUsage::default(),
+        }
+    }
+}
+
+/// The fee a transaction or query is expected to be charged, see [`FeeEstimator`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FeeEstimate {
+    /// The usage the fee was calculated from.
+    pub usage: FeeUsage,
+
+    /// The part of the fee paid to the node that receives the request.
+    pub node_fee: Hbar,
+
+    /// The part of the fee paid for reaching consensus on the request.
+    pub network_fee: Hbar,
+
+    /// The part of the fee paid for carrying out the request.
+    pub service_fee: Hbar,
+}
+
+impl FeeEstimate {
+    /// Returns the total fee.
+    #[must_use]
+    pub fn total(&self) -> Hbar {
+        self.node_fee + self.network_fee + self.service_fee
+    }
+}
+
+/// Calculates fees offline, from a fee schedule and an exchange rate.
+///
+/// The fee schedules and exchange rates are published in files `0.0.111` and `0.0.112`,
+/// which can be read with a [`FileContentsQuery`](crate::FileContentsQuery)
+/// and parsed with [`FeeSchedules::from_bytes`] and [`ExchangeRates::from_bytes`](crate::ExchangeRates::from_bytes).
+///
+/// The estimates use the same formulas as the network, but not all of its inputs can be known ahead of time
+/// (custom fees, the size of a query's response, and the like), so they are estimates rather than exact fees.
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn f(client: &hedera::Client) -> hedera::Result<()> {
+/// use hedera::{AccountId, ExchangeRates, FeeEstimator, FeeSchedules, FileContentsQuery, FileId, Hbar, TransferTransaction};
+///
+/// let schedules = FileContentsQuery::new().file_id(FileId::new(0, 0, 111)).execute(client).await?;
+/// let rates = FileContentsQuery::new().file_id(FileId::new(0, 0, 112)).execute(client).await?;
+///
+/// let estimator = FeeEstimator::new(
+///     FeeSchedules::from_bytes(&schedules.contents)?,
+///     ExchangeRates::from_bytes(&rates.contents)?.current_rate,
+/// );
+///
+/// let mut transaction = TransferTransaction::new();
+/// transaction
+///     .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(-1))
+///     .hbar_transfer(AccountId::new(0, 0, 1002), Hbar::new(1));
+///
+/// // estimating needs the transaction as it will be sent, so estimate a frozen copy.
+/// let fee = estimator.estimate_transaction(transaction.clone().freeze_with(client)?)?.total();
+///
+/// transaction.max_transaction_fee(fee).execute(client).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FeeEstimator {
+    schedules: FeeSchedules,
+    exchange_rate: ExchangeRate,
+}
+
+impl FeeEstimator {
+    /// Create a new `FeeEstimator` that prices requests with `schedules` at `exchange_rate`.
+    ///
+    /// The current fee schedule is used, or the next one if there's no current schedule.
+    #[must_use]
+    pub fn new(schedules: FeeSchedules, exchange_rate: ExchangeRate) -> Self {
+        Self { schedules, exchange_rate }
+    }
+
+    /// Returns the fee schedules used by this estimator.
+    #[must_use]
+    pub fn fee_schedules(&self) -> &FeeSchedules {
+        &self.schedules
+    }
+
+    /// Returns the exchange rate used by this estimator.
+    #[must_use]
+    pub fn exchange_rate(&self) -> &ExchangeRate {
+        &self.exchange_rate
+    }
+
+    /// Estimates the fee `transaction` will be charged, see [`FeeUsage::for_transaction`].
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if the transaction has no transaction ID and wasn't frozen with an operator.
+    /// - [`Error::BasicParse`] if the transaction's expiration time is too far from its valid start to estimate storage for.
+    /// - [`Error::FeeScheduleMissing`] if the fee schedule doesn't have prices for the transaction.
+    ///
+    /// # Panics
+    /// - If `transaction` isn't frozen.
+    pub fn estimate_transaction<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<FeeEstimate> {
+        self.estimate(FeeUsage::for_transaction(transaction)?)
+    }
+
+    /// Estimates the fee for `query`, see [`FeeUsage::for_query`].
+    ///
+    /// Queries that don't require a payment are always free.
+    ///
+    /// # Errors
+    /// - [`Error::FeeScheduleMissing`] if the fee schedule doesn't have prices for the query.
+    pub fn estimate_query<D: QueryExecute>(&self, query: &Query<D>) -> crate::Result<FeeEstimate> {
+        if !query.data.is_payment_required() {
+            return Ok(FeeEstimate {
+                usage: FeeUsage::for_query(query),
+                node_fee: Hbar::ZERO,
+                network_fee: Hbar::ZERO,
+                service_fee: Hbar::ZERO,
+            });
+        }
+
+        self.estimate(FeeUsage::for_query(query))
+    }
+
+    /// Prices `usage`.
+    ///
+    /// # Errors
+    /// - [`Error::FeeScheduleMissing`] if the fee schedule doesn't have prices for `usage.request_type`.
+    pub fn estimate(&self, usage: FeeUsage) -> crate::Result<FeeEstimate> {
+        let prices = self.prices(&usage.request_type, &usage.kind)?;
+
+        let node_fee = self.to_hbar(usage.node.fee_in_tinycents(&prices.node));
+        let network_fee = self.to_hbar(usage.network.fee_in_tinycents(&prices.network));
+        let service_fee = self.to_hbar(usage.service.fee_in_tinycents(&prices.service));
+
+        Ok(FeeEstimate { usage, node_fee, network_fee, service_fee })
+    }
+
+    fn schedule(&self) -> Option<&FeeSchedule> {
+        self.schedules.current.as_ref().or(self.schedules.next.as_ref())
+    }
+
+    /// Returns the prices for `kind` of `request_type`, falling back to the default prices if there are none for `kind`.
+    fn prices(&self, request_type: &RequestType, kind: &FeeDataType) -> crate::Result<&FeeData> {
+        let schedule = self.schedule().and_then(|it| {
+            it.transaction_fee_schedules.iter().find(|it| it.request_type == *request_type)
+        });
+
+        let Some(schedule) = schedule else {
+            return Err(Error::FeeScheduleMissing(request_type.clone()));
+        };
+
+        let prices = schedule
+            .fees
+            .iter()
+            .find(|it| it.kind == *kind)
+            .or_else(|| schedule.fees.iter().find(|it| it.kind == FeeDataType::Default));
+
+        #[allow(deprecated)]
+        let prices = prices.or(schedule.fee_data.as_deref());
+
+        prices.ok_or_else(|| Error::FeeScheduleMissing(request_type.clone()))
+    }
+
+    fn to_hbar(&self, tinycents: u64) -> Hbar {
+        let ExchangeRate { hbars, cents, .. } = self.exchange_rate;
+
+        // an exchange rate without cents can't be used to convert anything, treat it as free.
+        let tinybars = match cents {
+            0 => 0,
+            cents => u128::from(tinycents) * u128::from(hbars) / u128::from(cents),
+        };
+
+        Hbar::from_tinybars(i64::try_from(tinybars).unwrap_or(i64::MAX))
+    }
+}
+
+/// Converts byte-seconds to byte-hours, rounding anything non-zero up to at least `1`.
+fn byte_hours(byte_seconds: u64) -> u64 {
+    match byte_seconds {
+        0 => 0,
+        it => (it / HRS_DIVISOR).max(1),
+    }
+}
+
+/// The size the transfers in `data` add to its record.
+fn transfers_size(data: &services::transaction_body::Data) -> u64 {
+    use services::transaction_body::Data;
+
+    let (transfers, token_transfers) = match data {
+        Data::CryptoTransfer(it) => (it.transfers.as_ref(), it.token_transfers.as_slice()),
+        Data::TokenAirdrop(it) => (None, it.token_transfers.as_slice()),
+        _ => return 0,
+    };
+
+    let hbar_transfers = transfers.map_or(0, |it| it.account_amounts.len()) as u64;
+
+    // each token list has the token's ID, and every NFT transfer has both accounts and a serial number.
+    let token_transfers: u64 = token_transfers
+        .iter()
+        .map(|it| {
+            BASIC_ENTITY_ID_SIZE
+                + it.transfers.len() as u64 * BASIC_ACCOUNT_AMT_SIZE
+                + it.nft_transfers.len() as u64 * (2 * BASIC_ENTITY_ID_SIZE + LONG_SIZE)
+        })
+        .sum();
+
+    hbar_transfers * BASIC_ACCOUNT_AMT_SIZE + token_transfers
+}
+
+/// The storage `data` will use for the rest of its lifetime, in byte-seconds.
+fn storage_byte_seconds(
+    body: &services::TransactionBody,
+    data: &services::transaction_body::Data,
+) -> crate::Result<u64> {
+    use services::transaction_body::Data;
+
+    let (size, expiration_time) = match data {
+        Data::FileCreate(it) => {
+            (BASE_FILEINFO_SIZE + it.contents.len() as u64, it.expiration_time.as_ref())
+        }
+        Data::FileUpdate(it) => (it.contents.len() as u64, it.expiration_time.as_ref()),
+        _ => return Ok(0),
+    };
+
+    let valid_start =
+        body.transaction_id.as_ref().and_then(|it| it.transaction_valid_start.as_ref());
+
+    let lifetime = match (valid_start, expiration_time) {
+        (Some(start), Some(end)) => {
+            let seconds = end.seconds.checked_sub(start.seconds).ok_or_else(|| {
+                Error::basic_parse("expiration time is too far from the transaction's valid start")
+            })?;
+
+            u64::try_from(seconds).unwrap_or(0)
+        }
+        _ => 0,
+    };
+
+    size.checked_mul(lifetime).ok_or_else(|| {
+        Error::basic_parse("expiration time is too far from the transaction's valid start")
+    })
+}
+
+/// The gas limit `data` will be executed with.
+fn gas(data: &services::transaction_body::Data) -> u64 {
+    use services::transaction_body::Data;
+
+    let gas = match data {
+        Data::ContractCall(it) => it.gas,
+        Data::ContractCreateInstance(it) => it.gas,
+        _ => 0,
+    };
+
+    u64::try_from(gas).unwrap_or(0)
+}
+
+fn transaction_kind(data: &services::transaction_body::Data) -> FeeDataType {
+    use services::transaction_body::Data;
+
+    match data {
+        Data::CryptoTransfer(it) => {
+            if it.token_transfers.iter().any(|it| !it.nft_transfers.is_empty()) {
+                FeeDataType::TokenNonFungibleUnique
+            } else if it.token_transfers.is_empty() {
+                FeeDataType::Default
+            } else {
+                FeeDataType::TokenFungibleCommon
+            }
+        }
+        Data::TokenCreation(it) => {
+            let non_fungible = it.token_type() == services::TokenType::NonFungibleUnique;
+
+            match (non_fungible, it.custom_fees.is_empty()) {
+                (false, true) => FeeDataType::TokenFungibleCommon,
+                (false, false) => FeeDataType::TokenFungibleCommonWithCustomFees,
+                (true, true) => FeeDataType::TokenNonFungibleUnique,
+                (true, false) => FeeDataType::TokenNonFungibleUniqueWithCustomFees,
+            }
+        }
+        Data::TokenMint(it) => token_kind(!it.metadata.is_empty()),
+        Data::TokenBurn(it) => token_kind(!it.serial_numbers.is_empty()),
+        Data::TokenWipe(it) => token_kind(!it.serial_numbers.is_empty()),
+        Data::ScheduleCreate(it) => {
+            let data = it.scheduled_transaction_body.as_ref().and_then(|it| it.data.as_ref());
+
+            match data {
+                Some(services::schedulable_transaction_body::Data::ContractCall(_)) => {
+                    FeeDataType::ScheduleCreateContractCall
+                }
+                _ => FeeDataType::Default,
+            }
+        }
+        _ => FeeDataType::Default,
+    }
+}
+
+fn token_kind(non_fungible: bool) -> FeeDataType {
+    if non_fungible {
+        FeeDataType::TokenNonFungibleUnique
+    } else {
+        FeeDataType::TokenFungibleCommon
+    }
+}
+
+fn transaction_request_type(data: &services::transaction_body::Data) -> RequestType {
+    use services::transaction_body::Data;
+
+    match data {
+        Data::ContractCall(_) => RequestType::ContractCall,
+        Data::ContractCreateInstance(_) => RequestType::ContractCreate,
+        Data::ContractUpdateInstance(_) => RequestType::ContractUpdate,
+        Data::ContractDeleteInstance(_) => RequestType::ContractDelete,
+        Data::EthereumTransaction(_) => RequestType::EthereumTransaction,
+        Data::CryptoAddLiveHash(_) => RequestType::CryptoAddLiveHash,
+        Data::CryptoApproveAllowance(_) => RequestType::CryptoApproveAllowance,
+        Data::CryptoDeleteAllowance(_) => RequestType::CryptoDeleteAllowance,
+        Data::CryptoCreateAccount(_) => RequestType::CryptoCreate,
+        Data::CryptoDelete(_) => RequestType::CryptoDelete,
+        Data::CryptoDeleteLiveHash(_) => RequestType::CryptoDeleteLiveHash,
+        Data::CryptoTransfer(_) => RequestType::CryptoTransfer,
+        Data::CryptoUpdateAccount(_) => RequestType::CryptoUpdate,
+        Data::FileAppend(_) => RequestType::FileAppend,
+        Data::FileCreate(_) => RequestType::FileCreate,
+        Data::FileDelete(_) => RequestType::FileDelete,
+        Data::FileUpdate(_) => RequestType::FileUpdate,
+        Data::SystemDelete(_) => RequestType::SystemDelete,
+        Data::SystemUndelete(_) => RequestType::SystemUndelete,
+        Data::Freeze(_) => RequestType::Freeze,
+        Data::ConsensusCreateTopic(_) => RequestType::ConsensusCreateTopic,
+        Data::ConsensusUpdateTopic(_) => RequestType::ConsensusUpdateTopic,
+        Data::ConsensusDeleteTopic(_) => RequestType::ConsensusDeleteTopic,
+        Data::ConsensusSubmitMessage(_) => RequestType::ConsensusSubmitMessage,
+        Data::UncheckedSubmit(_) => RequestType::UncheckedSubmit,
+        Data::TokenCreation(_) => RequestType::TokenCreate,
+        Data::TokenFreeze(_) => RequestType::TokenFreezeAccount,
+        Data::TokenUnfreeze(_) => RequestType::TokenUnfreezeAccount,
+        Data::TokenGrantKyc(_) => RequestType::TokenGrantKycToAccount,
+        Data::TokenRevokeKyc(_) => RequestType::TokenRevokeKycFromAccount,
+        Data::TokenDeletion(_) => RequestType::TokenDelete,
+        Data::TokenUpdate(_) => RequestType::TokenUpdate,
+        Data::TokenMint(_) => RequestType::TokenMint,
+        Data::TokenBurn(_) => RequestType::TokenBurn,
+        Data::TokenWipe(_) => RequestType::TokenAccountWipe,
+        Data::TokenAssociate(_) => RequestType::TokenAssociateToAccount,
+        Data::TokenDissociate(_) => RequestType::TokenDissociateFromAccount,
+        Data::TokenFeeScheduleUpdate(_) => RequestType::TokenFeeScheduleUpdate,
+        Data::TokenPause(_) => RequestType::TokenPause,
+        Data::TokenUnpause(_) => RequestType::TokenUnpause,
+        Data::TokenUpdateNfts(_) => RequestType::TokenUpdateNfts,
+        Data::TokenReject(_) => RequestType::TokenReject,
+        Data::TokenAirdrop(_) => RequestType::TokenAirdrop,
+        Data::TokenClaimAirdrop(_) => RequestType::TokenClaimAirdrop,
+        Data::TokenCancelAirdrop(_) => RequestType::TokenCancelAirdrop,
+        Data::ScheduleCreate(_) => RequestType::ScheduleCreate,
+        Data::ScheduleDelete(_) => RequestType::ScheduleDelete,
+        Data::ScheduleSign(_) => RequestType::ScheduleSign,
+        Data::NodeStakeUpdate(_) => RequestType::NodeStakeUpdate,
+        Data::UtilPrng(_) => RequestType::UtilPrng,
+        Data::NodeCreate(_) => RequestType::NodeCreate,
+        Data::NodeUpdate(_) => RequestType::NodeUpdate,
+        Data::NodeDelete(_) => RequestType::NodeDelete,
+        Data::TssMessage(_) => RequestType::TssMessage,
+        Data::TssVote(_) => RequestType::TssVote,
+    }
+}
+
+fn query_request_type(query: &services::query::Query) -> RequestType {
+    use services::query::Query;
+
+    match query {
+        Query::ContractCallLocal(_) => RequestType::ContractCallLocal,
+        Query::ContractGetInfo(_) => RequestType::ContractGetInfo,
+        Query::ContractGetBytecode(_) => RequestType::ContractGetBytecode,
+        Query::CryptogetAccountBalance(_) => RequestType::CryptoGetAccountBalance,
+        Query::CryptoGetAccountRecords(_) => RequestType::CryptoGetAccountRecords,
+        Query::CryptoGetInfo(_) => RequestType::CryptoGetInfo,
+        Query::CryptoGetProxyStakers(_) => RequestType::CryptoGetStakers,
+        Query::FileGetContents(_) => RequestType::FileGetContents,
+        Query::FileGetInfo(_) => RequestType::FileGetInfo,
+        Query::TransactionGetReceipt(_) => RequestType::TransactionGetReceipt,
+        Query::TransactionGetRecord(_) => RequestType::TransactionGetRecord,
+        Query::ConsensusGetTopicInfo(_) => RequestType::ConsensusGetTopicInfo,
+        Query::NetworkGetVersionInfo(_) => RequestType::GetVersionInfo,
+        Query::TokenGetInfo(_) => RequestType::TokenGetInfo,
+        Query::ScheduleGetInfo(_) => RequestType::ScheduleGetInfo,
+        Query::TokenGetNftInfo(_) => RequestType::TokenGetNftInfo,
+        // the SDK doesn't send any other queries.
+        _ => RequestType::None,
+    }
+}
@@ -0,0 +1,286 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use assert_matches::assert_matches;
+use hedera_proto::services;
+use prost::Message;
+use time::OffsetDateTime;
+
+use super::ResourceUsage;
+use crate::{
+    AccountBalanceQuery,
+    AccountId,
+    AccountInfoQuery,
+    ContractExecuteTransaction,
+    ContractId,
+    Error,
+    ExchangeRate,
+    FeeComponents,
+    FeeData,
+    FeeDataType,
+    FeeEstimator,
+    FeeSchedule,
+    FeeSchedules,
+    FeeUsage,
+    Hbar,
+    RequestType,
+    TokenId,
+    TokenMintTransaction,
+    TransactionFeeSchedule,
+    TransferTransaction,
+};
+
+const ZERO_FEES: FeeComponents = FeeComponents {
+    min: 0,
+    max: 0,
+    constant: 0,
+    bandwidth_byte: 0,
+    verification: 0,
+    storage_byte_hour: 0,
+    ram_byte_hour: 0,
+    contract_transaction_gas: 0,
+    transfer_volume_hbar: 0,
+    response_memory_byte: 0,
+    response_disk_byte: 0,
+};
+
+const PRICES: FeeComponents = FeeComponents {
+    max: 1_000_000_000_000_000,
+    constant: 1_000_000,
+    bandwidth_byte: 1_000,
+    verification: 100_000,
+    ram_byte_hour: 10,
+    storage_byte_hour: 20,
+    contract_transaction_gas: 30,
+    response_memory_byte: 40,
+    ..ZERO_FEES
+};
+
+fn fee_data(kind: FeeDataType, prices: FeeComponents) -> FeeData {
+    FeeData { node: prices, network: prices, service: prices, kind }
+}
+
+fn estimator(fees: Vec<(RequestType, Vec<FeeData>)>) -> FeeEstimator {
+    #[allow(deprecated)]
+    let transaction_fee_schedules = fees
+        .into_iter()
+        .map(|(request_type, fees)| TransactionFeeSchedule { request_type, fee_data: None, fees })
+        .collect();
+
+    let schedules = FeeSchedules {
+        current: Some(FeeSchedule {
+            transaction_fee_schedules,
+            expiration_time: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+        }),
+        next: None,
+    };
+
+    let exchange_rate = ExchangeRate {
+        hbars: 1,
+        cents: 12,
+        expiration_time: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+    };
+
+    FeeEstimator::new(schedules, exchange_rate)
+}
+
+fn transfer() -> TransferTransaction {
+    let mut tx = TransferTransaction::new_for_tests();
+
+    tx.hbar_transfer(AccountId::new(0, 0, 5008), Hbar::from_tinybars(400))
+        .hbar_transfer(AccountId::new(0, 0, 5006), Hbar::from_tinybars(-400))
+        .freeze()
+        .unwrap();
+
+    tx
+}
+
+#[test]
+fn component_fee() {
+    let usage = ResourceUsage { constant: 1, bandwidth_byte: 10, ..ResourceUsage::default() };
+    let prices = FeeComponents { max: u64::MAX, constant: 5_000, bandwidth_byte: 100, ..ZERO_FEES };
+
+    assert_eq!(usage.fee_in_tinycents(&prices), 6);
+    assert_eq!(usage.fee_in_tinycents(&FeeComponents { min: 20_000, ..prices }), 20);
+    assert_eq!(usage.fee_in_tinycents(&FeeComponents { max: 3_000, ..prices }), 3);
+
+    // fees that round down to zero are still charged.
+    assert_eq!(
+        usage.fee_in_tinycents(&FeeComponents { constant: 1, bandwidth_byte: 0, ..prices }),
+        1
+    );
+    assert_eq!(ResourceUsage::default().fee_in_tinycents(&prices), 0);
+}
+
+#[test]
+fn transfer_usage() {
+    let usage = FeeUsage::for_transaction(&transfer()).unwrap();
+
+    assert_eq!(usage.request_type, RequestType::CryptoTransfer);
+    assert_eq!(usage.kind, FeeDataType::Default);
+
+    assert_eq!(usage.node.verification, 1);
+    assert_eq!(usage.network.verification, 1);
+    assert!(usage.node.bandwidth_byte > 0);
+    assert_eq!(usage.node.bandwidth_byte, usage.network.bandwidth_byte);
+
+    // a receipt, kept for 180 seconds.
+    assert_eq!(usage.network.ram_byte_hour, 1);
+
+    // a record with 2 transfers, kept for 180 seconds.
+    assert_eq!(usage.service.ram_byte_hour, (132 + 2 * 32) * 180 / 3600);
+    assert_eq!(usage.service.storage_byte_hour, 0);
+    assert_eq!(usage.service.contract_transaction_gas, 0);
+}
+
+#[test]
+fn transfer_fee() {
+    let tx = transfer();
+    let estimator = estimator(vec![(
+        RequestType::CryptoTransfer,
+        vec![fee_data(FeeDataType::Default, PRICES)],
+    )]);
+
+    let estimate = estimator.estimate_transaction(&tx).unwrap();
+    let bytes = estimate.usage.node.bandwidth_byte;
+
+    // 1 tinybar is worth 12 tinycents.
+    let node = (1_000_000 + 1_000 * bytes + 100_000 + 40 * 4) / 1_000 / 12;
+    let network = (1_000_000 + 1_000 * bytes + 100_000 + 10) / 1_000 / 12;
+    let service = (1_000_000 + 10 * 9) / 1_000 / 12;
+
+    assert_eq!(estimate.node_fee, Hbar::from_tinybars(node as i64));
+    assert_eq!(estimate.network_fee, Hbar::from_tinybars(network as i64));
+    assert_eq!(estimate.service_fee, Hbar::from_tinybars(service as i64));
+    assert_eq!(estimate.total(), Hbar::from_tinybars((node + network + service) as i64));
+}
+
+#[test]
+fn contract_gas() {
+    let mut tx = ContractExecuteTransaction::new_for_tests();
+    tx.contract_id(ContractId::new(0, 0, 5)).gas(100_000).freeze().unwrap();
+
+    let usage = FeeUsage::for_transaction(&tx).unwrap();
+
+    assert_eq!(usage.request_type, RequestType::ContractCall);
+    assert_eq!(usage.service.contract_transaction_gas, 100_000);
+}
+
+#[test]
+fn token_kind() {
+    let mut tx = TokenMintTransaction::new_for_tests();
+    tx.token_id(TokenId::new(0, 0, 5)).metadata([[1_u8, 2, 3]]).freeze().unwrap();
+
+    let cheap = FeeComponents { constant: 1_000, ..PRICES };
+
+    let with_subtype = estimator(vec![(
+        RequestType::TokenMint,
+        vec![
+            fee_data(FeeDataType::Default, PRICES),
+            fee_data(FeeDataType::TokenNonFungibleUnique, cheap),
+        ],
+    )]);
+
+    let estimate = with_subtype.estimate_transaction(&tx).unwrap();
+
+    assert_eq!(estimate.usage.kind, FeeDataType::TokenNonFungibleUnique);
+
+    let default = with_subtype
+        .estimate(FeeUsage { kind: FeeDataType::Default, ..estimate.usage.clone() })
+        .unwrap();
+
+    assert!(estimate.total() < default.total());
+
+    // without prices for the subtype the default prices are used.
+    let without_subtype =
+        estimator(vec![(RequestType::TokenMint, vec![fee_data(FeeDataType::Default, PRICES)])]);
+
+    assert_eq!(without_subtype.estimate_transaction(&tx).unwrap().total(), default.total());
+}
+
+#[test]
+fn storage_lifetime_overflow() {
+    let timestamp = |seconds| Some(services::Timestamp { seconds, nanos: 0 });
+
+    let body = services::TransactionBody {
+        transaction_id: Some(services::TransactionId {
+            transaction_valid_start: timestamp(i64::MIN),
+            ..Default::default()
+        }),
+        data: Some(services::transaction_body::Data::FileCreate(
+            services::FileCreateTransactionBody {
+                expiration_time: timestamp(i64::MAX),
+                ..Default::default()
+            },
+        )),
+        ..Default::default()
+    };
+
+    let signed_transaction =
+        services::SignedTransaction { body_bytes: body.encode_to_vec(), sig_map: None };
+
+    assert_matches!(
+        FeeUsage::for_signed_transaction(&signed_transaction),
+        Err(Error::BasicParse(_))
+    );
+}
+
+#[test]
+fn missing_schedule() {
+    let mut tx = TokenMintTransaction::new_for_tests();
+    tx.token_id(TokenId::new(0, 0, 5)).amount(5).freeze().unwrap();
+
+    let estimator = estimator(vec![(
+        RequestType::CryptoTransfer,
+        vec![fee_data(FeeDataType::Default, PRICES)],
+    )]);
+
+    assert_matches!(
+        estimator.estimate_transaction(&tx),
+        Err(Error::FeeScheduleMissing(RequestType::TokenMint))
+    );
+}
+
+#[test]
+fn query() {
+    let mut query = AccountInfoQuery::new();
+    query.account_id(AccountId::new(0, 0, 5005));
+
+    let estimator =
+        estimator(vec![(RequestType::CryptoGetInfo, vec![fee_data(FeeDataType::Default, PRICES)])]);
+
+    let estimate = estimator.estimate_query(&query).unwrap();
+
+    assert_eq!(estimate.usage.request_type, RequestType::CryptoGetInfo);
+    assert!(estimate.usage.node.bandwidth_byte > 212);
+    assert_eq!(estimate.usage.network, ResourceUsage::default());
+    assert_eq!(estimate.network_fee, Hbar::ZERO);
+    assert!(estimate.node_fee > Hbar::ZERO);
+}
+
+#[test]
+fn free_query() {
+    let mut query = AccountBalanceQuery::new();
+    query.account_id(AccountId::new(0, 0, 5005));
+
+    let estimate = estimator(Vec::new()).estimate_query(&query).unwrap();
+
+    assert_eq!(estimate.total(), Hbar::ZERO);
+}
@@ -773,7 +773,7 @@ impl ToProtobuf for FeeComponents {
             gas: self.contract_transaction_gas as i64,
             tv: self.transfer_volume_hbar as i64,
             bpr: self.response_memory_byte as i64,
-            sbpr: self.storage_byte_hour as i64,
+            sbpr: self.response_disk_byte as i64,
         }
     }
 }
@@ -843,7 +843,10 @@ mod tests {
     use expect_test::expect;
     use time::OffsetDateTime;
 
-    use crate::protobuf::ToProtobuf;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
     use crate::{
         FeeComponents,
         FeeData,
@@ -1047,6 +1050,27 @@ mod tests {
         assert_eq!(a, b);
     }
 
+    #[test]
+    fn fee_components_to_from_protobuf() {
+        let a = FeeComponents {
+            min: 1,
+            max: 2,
+            constant: 3,
+            bandwidth_byte: 4,
+            verification: 5,
+            storage_byte_hour: 6,
+            ram_byte_hour: 7,
+            contract_transaction_gas: 8,
+            transfer_volume_hbar: 9,
+            response_memory_byte: 10,
+            response_disk_byte: 11,
+        };
+
+        let b = FeeComponents::from_protobuf(a.to_protobuf()).unwrap();
+
+        assert_eq!(a, b);
+    }
+
     #[test]
     fn serialize_default() {
         let schedules = FeeSchedules { current: None, next: None };
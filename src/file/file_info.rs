@@ -35,6 +35,8 @@ use crate::{
 
 /// Response from [`FileInfoQuery`][crate::FileInfoQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FileInfo {
     /// The file ID of the file for which information is requested.
     pub file_id: FileId,
@@ -43,12 +45,14 @@ pub struct FileInfo {
     pub size: u64,
 
     /// Current time which this account is set to expire.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_impls::timestamp::option"))]
     pub expiration_time: Option<OffsetDateTime>,
 
     /// The auto renew period for this file.
     ///
     /// # Network Support
     /// Please note that this not supported on any hedera network at this time.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_impls::duration::option"))]
     pub auto_renew_period: Option<Duration>,
 
     /// The account to be used at this file's expiration time to extend the
@@ -0,0 +1,285 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::time::Duration;
+
+use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
+
+use crate::{
+    AccountId,
+    Status,
+};
+
+/// Observes every gRPC request a [`Client`](crate::Client) sends to a consensus node, and its outcome.
+///
+/// Interceptors are registered with [`Client::add_interceptor`](crate::Client::add_interceptor)
+/// and are called for every attempt of every transaction and query executed with that client,
+/// including retries and the pings the client uses to check that a node is healthy.
+///
+/// Interceptors are called inline while a request is being executed, so they should return quickly.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// use hedera::{Interceptor, ResponseEvent};
+///
+/// #[derive(Default)]
+/// struct Metrics {
+///     requests: AtomicU64,
+///     failed_prechecks: AtomicU64,
+/// }
+///
+/// impl Interceptor for Metrics {
+///     fn on_response(&self, event: &ResponseEvent<'_>) {
+///         self.requests.fetch_add(1, Ordering::Relaxed);
+///
+///         if event.pre_check_status.is_some_and(|it| it != hedera::Status::Ok) {
+///             self.failed_prechecks.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+/// ```
+pub trait Interceptor: Send + Sync {
+    /// Called right before a request is sent.
+    #[allow(unused_variables)]
+    fn on_request(&self, event: &RequestEvent<'_>) {}
+
+    /// Called once the node has answered a request, or the request has failed.
+    #[allow(unused_variables)]
+    fn on_response(&self, event: &ResponseEvent<'_>) {}
+}
+
+/// A request that's about to be sent to a node, see [`Interceptor::on_request`].
+#[derive(Debug, Clone, Copy)]
+pub struct RequestEvent<'a> {
+    /// The type name of what's being executed, for instance a transaction or a query.
+    ///
+    /// This is only meant for diagnostics, the exact format isn't stable.
+    pub executable: &'a str,
+
+    /// The node the request is being sent to.
+    pub node_account_id: AccountId,
+
+    /// The number of this attempt at executing the request, starting at `1`.
+    pub attempt: usize,
+
+    /// The protobuf encoded gRPC request.
+    ///
+    /// For transactions this is the signed `Transaction` that will be submitted to the node.
+    pub request: &'a [u8],
+}
+
+/// The outcome of a request to a node, see [`Interceptor::on_response`].
+#[derive(Debug, Clone, Copy)]
+pub struct ResponseEvent<'a> {
+    /// The type name of what's being executed, for instance a transaction or a query.
+    ///
+    /// This is only meant for diagnostics, the exact format isn't stable.
+    pub executable: &'a str,
+
+    /// The node the request was sent to.
+    pub node_account_id: AccountId,
+
+    /// The number of this attempt at executing the request, starting at `1`.
+    pub attempt: usize,
+
+    /// The protobuf encoded gRPC request.
+    pub request: &'a [u8],
+
+    /// The protobuf encoded gRPC response, or the status the gRPC call failed with.
+    pub response: Result<&'a [u8], &'a tonic::Status>,
+
+    /// The pre-check status of the response, if there was a response and it had a recognized status.
+    pub pre_check_status: Option<Status>,
+
+    /// How long it took for the node to answer.
+    pub latency: Duration,
+}
+
+/// The interceptors registered with a client.
+#[derive(Clone, Default)]
+pub(crate) struct Interceptors(Vec<Arc<dyn Interceptor>>);
+
+impl Interceptors {
+    pub(crate) fn push<I: Interceptor + 'static>(&mut self, interceptor: I) {
+        self.0.push(Arc::new(interceptor).unsize(Coercion!(to dyn Interceptor)));
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn on_request(&self, event: &RequestEvent<'_>) {
+        for it in &self.0 {
+            it.on_request(event);
+        }
+    }
+
+    pub(crate) fn on_response(&self, event: &ResponseEvent<'_>) {
+        for it in &self.0 {
+            it.on_response(event);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use std::any::type_name;
+
+    use hedera_proto::services;
+    use parking_lot::Mutex;
+    use prost::Message;
+    use triomphe::Arc;
+
+    use super::{
+        Interceptor,
+        RequestEvent,
+        ResponseEvent,
+    };
+    use crate::testing::{
+        MockNetwork,
+        MockResponse,
+    };
+    use crate::transaction_hash::TransactionHash;
+    use crate::{
+        AccountId,
+        Client,
+        Hbar,
+        PrivateKey,
+        Status,
+        TransferTransaction,
+    };
+
+    #[derive(Clone, Default)]
+    struct Recorder {
+        events: Arc<Mutex<Vec<String>>>,
+        requests: Arc<Mutex<Vec<(String, Vec<u8>)>>>,
+    }
+
+    impl Interceptor for Recorder {
+        fn on_request(&self, event: &RequestEvent<'_>) {
+            self.events
+                .lock()
+                .push(format!("request {} #{}", event.node_account_id, event.attempt));
+            self.requests.lock().push((event.executable.to_owned(), event.request.to_vec()));
+        }
+
+        fn on_response(&self, event: &ResponseEvent<'_>) {
+            let response = match event.response {
+                Ok(_) => format!("{:?}", event.pre_check_status),
+                Err(status) => format!("{:?}", status.code()),
+            };
+
+            self.events
+                .lock()
+                .push(format!("response {} #{} {response}", event.node_account_id, event.attempt));
+        }
+    }
+
+    async fn client(network: &MockNetwork) -> (Client, Recorder) {
+        let client = network.client().unwrap();
+        client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+
+        // pings are intercepted too, so get them out of the way first.
+        for node in network.nodes() {
+            client.ping(node.account_id()).await.unwrap();
+        }
+
+        let recorder = Recorder::default();
+        client.add_interceptor(recorder.clone());
+
+        (client, recorder)
+    }
+
+    fn transfer() -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1));
+
+        tx
+    }
+
+    #[tokio::test]
+    async fn sees_every_attempt() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let (client, recorder) = client(&network).await;
+
+        network.nodes()[0].push_response(MockResponse::Precheck(Status::Busy));
+
+        transfer().execute(&client).await.unwrap();
+
+        assert_eq!(
+            *recorder.events.lock(),
+            [
+                "request 0.0.3 #1",
+                "response 0.0.3 #1 Some(Busy)",
+                "request 0.0.3 #2",
+                "response 0.0.3 #2 Some(Ok)",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn sees_grpc_errors() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let (client, recorder) = client(&network).await;
+
+        network.nodes()[0]
+            .push_response(MockResponse::GrpcStatus(tonic::Status::permission_denied("no")));
+
+        transfer().execute(&client).await.unwrap_err();
+
+        assert_eq!(
+            *recorder.events.lock(),
+            ["request 0.0.3 #1", "response 0.0.3 #1 PermissionDenied"]
+        );
+    }
+
+    #[tokio::test]
+    async fn sees_signed_transaction() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let (client, recorder) = client(&network).await;
+
+        let response = transfer().execute(&client).await.unwrap();
+
+        let requests = recorder.requests.lock();
+        assert_eq!(requests.len(), 1);
+
+        let (executable, request) = &requests[0];
+        assert_eq!(executable, type_name::<TransferTransaction>());
+
+        let transaction = services::Transaction::decode(request.as_slice()).unwrap();
+        let signed =
+            services::SignedTransaction::decode(transaction.signed_transaction_bytes.as_slice())
+                .unwrap();
+
+        assert_eq!(signed.sig_map.unwrap().sig_pair.len(), 1);
+        assert_eq!(
+            TransactionHash::new(&transaction.signed_transaction_bytes).0,
+            response.transaction_hash.0
+        );
+    }
+}
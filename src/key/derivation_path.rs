@@ -0,0 +1,157 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// Set on a BIP-32 child index to request hardened derivation.
+const HARDENED: u32 = 1 << 31;
+
+/// A BIP-32 derivation path, like `m/44'/60'/0'/0/0`.
+///
+/// Hardened indices are written with a trailing `'` (or `h`/`H`), and are stored with their top bit set,
+/// which is how they're passed to [`PrivateKey::derive`](crate::PrivateKey::derive).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /// Create a path from its child indices, hardened indices must have their top bit set.
+    #[must_use]
+    pub fn new(indices: Vec<u32>) -> Self {
+        Self(indices)
+    }
+
+    /// Returns the BIP-44 path used for ECDSA(secp256k1) keys on the EVM, `m/44'/60'/0'/0/{index}`.
+    ///
+    /// This is the path used by MetaMask and the other Hedera SDKs.
+    #[must_use]
+    pub fn evm(index: u32) -> Self {
+        Self(vec![44 | HARDENED, 60 | HARDENED, HARDENED, 0, index])
+    }
+
+    /// Returns the child indices in this path, with hardened indices having their top bit set.
+    #[must_use]
+    pub fn indices(&self) -> &[u32] {
+        &self.0
+    }
+
+    /// Returns `true` if `index` is a hardened child index.
+    #[must_use]
+    pub fn is_hardened(index: u32) -> bool {
+        index & HARDENED != 0
+    }
+
+    /// Returns the hardened version of `index`.
+    #[must_use]
+    pub fn hardened(index: u32) -> u32 {
+        index | HARDENED
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("m")?;
+
+        for &index in &self.0 {
+            if Self::is_hardened(index) {
+                write!(f, "/{}'", index & !HARDENED)?;
+            } else {
+                write!(f, "/{index}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('/');
+
+        if parts.next() != Some("m") {
+            return Err(Error::basic_parse("expected derivation path to start with `m`"));
+        }
+
+        parts
+            .map(|part| {
+                let (index, hardened) = match part.strip_suffix(['\'', 'h', 'H']) {
+                    Some(index) => (index, true),
+                    None => (part, false),
+                };
+
+                // `u32::from_str` accepts a leading `+`.
+                if !index.bytes().all(|it| it.is_ascii_digit()) {
+                    return Err(Error::basic_parse(format!("invalid child index `{part}`")));
+                }
+
+                let index: u32 = index.parse().map_err(Error::basic_parse)?;
+
+                if Self::is_hardened(index) {
+                    return Err(Error::basic_parse(format!("child index `{part}` is too large")));
+                }
+
+                Ok(if hardened { index | HARDENED } else { index })
+            })
+            .collect::<crate::Result<_>>()
+            .map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use assert_matches::assert_matches;
+
+    use super::DerivationPath;
+    use crate::Error;
+
+    #[test]
+    fn parse() {
+        let path = DerivationPath::from_str("m/44'/60'/0'/0/7").unwrap();
+
+        assert_eq!(path, DerivationPath::evm(7));
+        assert_eq!(path.indices(), [0x8000_002c, 0x8000_003c, 0x8000_0000, 0, 7]);
+        assert_eq!(path.to_string(), "m/44'/60'/0'/0/7");
+    }
+
+    #[test]
+    fn parse_hardened_suffixes() {
+        let path = DerivationPath::from_str("m/1h/2H/3'").unwrap();
+
+        assert_eq!(path.to_string(), "m/1'/2'/3'");
+    }
+
+    #[test]
+    fn parse_master() {
+        assert_eq!(DerivationPath::from_str("m").unwrap(), DerivationPath::default());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in ["", "44'/60'", "m/", "m/44''", "m/-1", "m/+1", "m/a", "m/2147483648", "M/0"] {
+            assert_matches!(DerivationPath::from_str(s), Err(Error::BasicParse(_)), "{s}");
+        }
+    }
+}
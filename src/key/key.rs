@@ -32,6 +32,8 @@ use crate::{
 
 /// Any method that can be used to authorize an operation on Hedera.
 #[derive(Eq, PartialEq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[non_exhaustive]
 pub enum Key {
     // todo(sr): not happy with any of these (fix before merge)
@@ -10,6 +10,8 @@ use crate::Key;
 // todo: Copy over the _entire_ `Vec` API?.
 /// A list of keys with an optional threshold.
 #[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct KeyList {
     // todo: better doc comment?
     /// The list of keys.
@@ -0,0 +1,380 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+
+use hedera_proto::services;
+
+use crate::transaction::TransactionSources;
+use crate::{
+    ContractId,
+    DelegateContractId,
+    Key,
+    PublicKey,
+};
+
+/// The result of checking a [`Key`] against the signatures on a frozen transaction.
+///
+/// This mirrors the structure of the checked key, so that it can explain why the key is, or isn't, satisfied.
+///
+/// Its [`Display`](fmt::Display) impl is a human readable explanation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyRequirement {
+    /// A public key that has a valid signature on every node and chunk of the transaction.
+    Signed(PublicKey),
+
+    /// A public key that hasn't signed the transaction, or hasn't signed every node and chunk of it.
+    Missing(PublicKey),
+
+    /// A public key that signed the transaction, but with a signature that doesn't verify.
+    InvalidSignature(PublicKey),
+
+    /// A contract ID key, which can't be satisfied by signatures.
+    ContractId(ContractId),
+
+    /// A delegatable contract ID key, which can't be satisfied by signatures.
+    DelegateContractId(DelegateContractId),
+
+    /// A key list, which is satisfied once `threshold` of its `keys` are.
+    ///
+    /// An empty key list is never satisfied.
+    KeyList {
+        /// The number of keys that must be satisfied, all of them if the list had no threshold.
+        threshold: usize,
+
+        /// The requirements of each key in the list.
+        keys: Vec<KeyRequirement>,
+    },
+}
+
+impl KeyRequirement {
+    pub(crate) fn check(key: &Key, sources: &TransactionSources) -> Self {
+        match key {
+            Key::Single(public_key) => check_public_key(*public_key, sources),
+            Key::ContractId(id) => Self::ContractId(*id),
+            Key::DelegateContractId(id) => Self::DelegateContractId(*id),
+            Key::KeyList(list) => Self::KeyList {
+                threshold: list
+                    .threshold
+                    .map_or(list.len(), |it| usize::try_from(it).unwrap_or(usize::MAX)),
+                keys: list.iter().map(|it| Self::check(it, sources)).collect(),
+            },
+        }
+    }
+
+    /// Returns `true` if the signatures on the transaction satisfy this key.
+    #[must_use]
+    pub fn is_satisfied(&self) -> bool {
+        match self {
+            Self::Signed(_) => true,
+            Self::Missing(_)
+            | Self::InvalidSignature(_)
+            | Self::ContractId(_)
+            | Self::DelegateContractId(_) => false,
+            Self::KeyList { threshold, keys } => {
+                !keys.is_empty() && satisfied_count(keys) >= *threshold
+            }
+        }
+    }
+
+    /// Returns the public keys that could still sign to satisfy this key.
+    ///
+    /// For a threshold key list this is every unsigned key, even though only some of them are needed.
+    /// Keys under parts of this key that are already satisfied aren't included.
+    #[must_use]
+    pub fn missing_keys(&self) -> Vec<PublicKey> {
+        let mut missing = Vec::new();
+        self.collect_missing_keys(&mut missing);
+        missing
+    }
+
+    fn collect_missing_keys(&self, missing: &mut Vec<PublicKey>) {
+        match self {
+            Self::Missing(key) | Self::InvalidSignature(key) => {
+                if !missing.contains(key) {
+                    missing.push(*key);
+                }
+            }
+            Self::KeyList { keys, .. } if !self.is_satisfied() => {
+                for key in keys {
+                    key.collect_missing_keys(missing);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl fmt::Display for KeyRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signed(key) => write!(f, "`{key}` signed"),
+            Self::Missing(key) => write!(f, "`{key}` has not signed"),
+            Self::InvalidSignature(key) => write!(f, "`{key}` has an invalid signature"),
+            Self::ContractId(id) => write!(f, "contract `{id}` can't sign"),
+            Self::DelegateContractId(id) => write!(f, "delegatable contract `{id}` can't sign"),
+            Self::KeyList { keys, .. } if keys.is_empty() => {
+                f.write_str("an empty key list can't be satisfied")
+            }
+            Self::KeyList { threshold, keys } => {
+                write!(
+                    f,
+                    "{} of {threshold} required keys are satisfied: [",
+                    satisfied_count(keys)
+                )?;
+
+                for (i, key) in keys.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+
+                    write!(f, "{key}")?;
+                }
+
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+fn satisfied_count(keys: &[KeyRequirement]) -> usize {
+    keys.iter().filter(|it| it.is_satisfied()).count()
+}
+
+fn check_public_key(public_key: PublicKey, sources: &TransactionSources) -> KeyRequirement {
+    use services::signature_pair::Signature;
+
+    let pk_bytes = public_key.to_bytes_raw();
+
+    for signed_transaction in sources.signed_transactions() {
+        let mut sig_pairs = signed_transaction
+            .sig_map
+            .as_ref()
+            .map_or_else(|| [].as_slice(), |it| &it.sig_pair)
+            .iter()
+            .filter(|it| pk_bytes.starts_with(&it.pub_key_prefix))
+            .peekable();
+
+        if sig_pairs.peek().is_none() {
+            return KeyRequirement::Missing(public_key);
+        }
+
+        // a short prefix might match more than one signature, any valid one will do.
+        let verified = sig_pairs.any(|it| match &it.signature {
+            Some(Signature::Ed25519(sig) | Signature::EcdsaSecp256k1(sig)) => {
+                public_key.verify(&signed_transaction.body_bytes, sig).is_ok()
+            }
+            _ => false,
+        });
+
+        if !verified {
+            return KeyRequirement::InvalidSignature(public_key);
+        }
+    }
+
+    KeyRequirement::Signed(public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::KeyRequirement;
+    use crate::{
+        ContractId,
+        Hbar,
+        Key,
+        KeyList,
+        PrivateKey,
+        PublicKey,
+        TransactionId,
+        TransferTransaction,
+    };
+
+    fn signed_transaction(keys: &[&PrivateKey]) -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+
+        tx.hbar_transfer(2.into(), Hbar::new(2))
+            .hbar_transfer(101.into(), Hbar::new(-2))
+            .transaction_id(TransactionId {
+                account_id: 101.into(),
+                valid_start: OffsetDateTime::now_utc(),
+                nonce: None,
+                scheduled: false,
+            })
+            .node_account_ids([6.into(), 7.into()])
+            .freeze()
+            .unwrap();
+
+        for key in keys {
+            tx.sign((*key).clone());
+        }
+
+        tx
+    }
+
+    fn threshold_key(threshold: Option<u32>, keys: &[PublicKey]) -> Key {
+        let mut list: KeyList = keys.iter().copied().map(Key::Single).collect();
+        list.threshold = threshold;
+        Key::KeyList(list)
+    }
+
+    #[test]
+    fn single() -> crate::Result<()> {
+        let key1 = PrivateKey::generate_ed25519();
+        let key2 = PrivateKey::generate_ecdsa();
+
+        let tx = signed_transaction(&[&key1]);
+
+        let requirements = tx.check_key_requirements(&[
+            Key::Single(key1.public_key()),
+            Key::Single(key2.public_key()),
+        ])?;
+
+        assert_eq!(
+            requirements,
+            [KeyRequirement::Signed(key1.public_key()), KeyRequirement::Missing(key2.public_key())]
+        );
+
+        assert!(requirements[0].is_satisfied());
+        assert!(requirements[0].missing_keys().is_empty());
+        assert!(!requirements[1].is_satisfied());
+        assert_eq!(requirements[1].missing_keys(), [key2.public_key()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn threshold() -> crate::Result<()> {
+        let keys = [
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ecdsa(),
+        ];
+        let public_keys = keys.each_ref().map(PrivateKey::public_key);
+
+        let tx = signed_transaction(&[&keys[0], &keys[2]]);
+
+        let requirements = tx.check_key_requirements(&[
+            threshold_key(Some(2), &public_keys),
+            threshold_key(None, &public_keys),
+        ])?;
+
+        assert!(requirements[0].is_satisfied());
+        assert!(requirements[0].missing_keys().is_empty());
+
+        assert!(!requirements[1].is_satisfied());
+        assert_eq!(requirements[1].missing_keys(), [public_keys[1]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested() -> crate::Result<()> {
+        let keys = [
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ed25519(),
+        ];
+        let public_keys = keys.each_ref().map(PrivateKey::public_key);
+
+        // `keys[0]` and one of `keys[1..]`.
+        let key = Key::KeyList(KeyList::from([
+            Key::Single(public_keys[0]),
+            threshold_key(Some(1), &public_keys[1..]),
+        ]));
+
+        let tx = signed_transaction(&[&keys[0]]);
+        let requirement = tx.check_key_requirements(std::slice::from_ref(&key))?.remove(0);
+
+        assert!(!requirement.is_satisfied());
+        assert_eq!(requirement.missing_keys(), &public_keys[1..]);
+
+        let tx = signed_transaction(&[&keys[0], &keys[2]]);
+        let requirement = tx.check_key_requirements(std::slice::from_ref(&key))?.remove(0);
+
+        assert!(requirement.is_satisfied());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unsatisfiable() -> crate::Result<()> {
+        let tx = signed_transaction(&[]);
+
+        let requirements = tx.check_key_requirements(&[
+            Key::ContractId(ContractId::new(0, 0, 1001)),
+            Key::KeyList(KeyList::new()),
+        ])?;
+
+        assert!(requirements.iter().all(|it| !it.is_satisfied()));
+        assert!(requirements.iter().all(|it| it.missing_keys().is_empty()));
+
+        assert_eq!(requirements[0].to_string(), "contract `0.0.1001` can't sign");
+        assert_eq!(requirements[1].to_string(), "an empty key list can't be satisfied");
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_signature() -> crate::Result<()> {
+        let key = PrivateKey::generate_ed25519();
+
+        let mut tx = TransferTransaction::new();
+
+        tx.hbar_transfer(2.into(), Hbar::new(2))
+            .hbar_transfer(101.into(), Hbar::new(-2))
+            .transaction_id(TransactionId {
+                account_id: 101.into(),
+                valid_start: OffsetDateTime::now_utc(),
+                nonce: None,
+                scheduled: false,
+            })
+            .node_account_ids([6.into()])
+            .freeze()?
+            .add_signature(key.public_key(), key.sign(b"not the transaction"));
+
+        let requirements = tx.check_key_requirements(&[Key::Single(key.public_key())])?;
+
+        assert_eq!(requirements, [KeyRequirement::InvalidSignature(key.public_key())]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn display() -> crate::Result<()> {
+        let keys = [PrivateKey::generate_ed25519(), PrivateKey::generate_ed25519()];
+        let public_keys = keys.each_ref().map(PrivateKey::public_key);
+
+        let tx = signed_transaction(&[&keys[0]]);
+
+        let requirements = tx.check_key_requirements(&[threshold_key(None, &public_keys)])?;
+
+        assert_eq!(
+            requirements[0].to_string(),
+            format!(
+                "1 of 2 required keys are satisfied: [`{}` signed, `{}` has not signed]",
+                public_keys[0], public_keys[1]
+            )
+        );
+
+        Ok(())
+    }
+}
@@ -18,14 +18,18 @@
  * ‍
  */
 
+mod derivation_path;
 #[allow(clippy::module_inception)]
 mod key;
 mod key_list;
+mod key_requirement;
 mod private_key;
 mod public_key;
 
+pub use derivation_path::DerivationPath;
 pub use key::Key;
 pub use key_list::KeyList;
+pub use key_requirement::KeyRequirement;
 pub use private_key::PrivateKey;
 pub use public_key::PublicKey;
 
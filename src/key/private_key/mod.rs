@@ -43,6 +43,7 @@ use hmac::{
     Mac,
 };
 use k256::ecdsa::signature::DigestSigner;
+use k256::elliptic_curve::PrimeField;
 use pkcs8::der::oid::ObjectIdentifier;
 use pkcs8::der::{
     Decode,
@@ -53,9 +54,13 @@ use sha2::Sha512;
 use sha3::Digest;
 use triomphe::Arc;
 
+use crate::ethereum::hash_personal_message;
 use crate::signer::AnySigner;
+#[cfg(feature = "serde")]
+use crate::Eip712TypedData;
 use crate::{
     AccountId,
+    DerivationPath,
     Error,
     PublicKey,
     Transaction,
@@ -606,6 +611,56 @@ impl PrivateKey {
         }
     }
 
+    /// Signs the 32 byte `hash` of a message with this ECDSA(secp256k1) key,
+    /// returning the `r || s` signature and its recovery ID.
+    ///
+    /// The signature is normalized to a low `s`, as Ethereum requires.
+    pub(crate) fn sign_prehash_recoverable(
+        &self,
+        hash: &[u8; 32],
+    ) -> crate::Result<([u8; 64], u8)> {
+        let PrivateKeyData::Ecdsa(key) = &self.0.data else {
+            return Err(Error::signature("only ECDSA(secp256k1) keys make recoverable signatures"));
+        };
+
+        let (signature, recovery_id) =
+            key.sign_prehash_recoverable(hash).map_err(Error::signature)?;
+
+        Ok((signature.to_bytes().into(), recovery_id.to_byte()))
+    }
+
+    /// Signs `message` like `personal_sign` (EIP-191) does, with this ECDSA(secp256k1) key.
+    ///
+    /// Returns the `r || s || v` signature, with a `v` of 27 or 28, as `ecrecover` expects.
+    ///
+    /// # Errors
+    /// - [`Error::Signature`] if this isn't an ECDSA(secp256k1) key.
+    pub fn sign_personal_message(&self, message: &[u8]) -> crate::Result<[u8; 65]> {
+        self.sign_prehash_ethereum(&hash_personal_message(message))
+    }
+
+    /// Signs EIP-712 typed data like `eth_signTypedData_v4` does, with this ECDSA(secp256k1) key.
+    ///
+    /// Returns the `r || s || v` signature, with a `v` of 27 or 28, as `ecrecover` expects.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `typed_data`'s domain or message doesn't match its type.
+    /// - [`Error::Signature`] if this isn't an ECDSA(secp256k1) key.
+    #[cfg(feature = "serde")]
+    pub fn sign_typed_data(&self, typed_data: &Eip712TypedData) -> crate::Result<[u8; 65]> {
+        self.sign_prehash_ethereum(&typed_data.signing_hash()?)
+    }
+
+    fn sign_prehash_ethereum(&self, hash: &[u8; 32]) -> crate::Result<[u8; 65]> {
+        let (signature, recovery_id) = self.sign_prehash_recoverable(hash)?;
+
+        let mut out = [0; 65];
+        out[..64].copy_from_slice(&signature);
+        out[64] = 27 + recovery_id;
+
+        Ok(out)
+    }
+
     // I question the reason for this function existing.
     /// Signs the given transaction.
     ///
@@ -626,14 +681,18 @@ impl PrivateKey {
     /// Returns true if calling [`derive`](Self::derive) on `self` would succeed.
     #[must_use]
     pub fn is_derivable(&self) -> bool {
-        self.is_ed25519() && self.0.chain_code.is_some()
+        self.0.chain_code.is_some()
     }
 
     /// Derives a child key based on `index`.
     ///
+    /// Ed25519 keys always use hardened derivation (SLIP-10).
+    /// ECDSA(secp256k1) keys use BIP-32, where an `index` with its top bit set
+    /// (see [`DerivationPath::hardened`](crate::DerivationPath::hardened)) is hardened.
+    ///
     /// # Errors
-    /// - [`Error::KeyDerive`] if this is an Ecdsa key (unsupported operation)
     /// - [`Error::KeyDerive`] if this key has no `chain_code` (key is not derivable)
+    /// - [`Error::KeyDerive`] if `index` produces an invalid ECDSA key, in which case the next index should be used.
     // this is specifically for the two `try_into`s which depend on `split_array_ref`.
     // Any panic would indicate a bug in this crate or a dependency of it, not in user code.
     #[allow(clippy::missing_panics_doc)]
@@ -665,12 +724,77 @@ impl PrivateKey {
 
                 Ok(Self::new_derivable(data.into(), *chain_code))
             }
-            PrivateKeyData::Ecdsa(_) => {
-                Err(Error::key_derive("Ecdsa private keys don't currently support derivation"))
+            PrivateKeyData::Ecdsa(key) => {
+                let mut mac = Hmac::<Sha512>::new_from_slice(chain_code)
+                    .expect("HMAC can take keys of any size");
+
+                if index & HARDEND_MASK == 0 {
+                    mac.update(key.verifying_key().to_encoded_point(true).as_bytes());
+                } else {
+                    mac.update(&[0]);
+                    mac.update(&key.to_bytes());
+                }
+
+                let output: [u8; 64] =
+                    mac.chain_update(index.to_be_bytes()).finalize().into_bytes().into();
+
+                let (tweak, chain_code) = split_key_array(&output);
+
+                // BIP-32: the key is invalid if the tweak isn't less than the curve order, or if the child key is zero.
+                let tweak = Option::<k256::Scalar>::from(k256::Scalar::from_repr(
+                    *GenericArray::from_slice(tweak),
+                ));
+
+                let data = tweak.and_then(|tweak| {
+                    Option::from(k256::NonZeroScalar::new(
+                        *key.as_nonzero_scalar().as_ref() + tweak,
+                    ))
+                });
+
+                let data: k256::NonZeroScalar = data.ok_or_else(|| {
+                    Error::key_derive(format!(
+                        "index {index} makes an invalid key, use the next index"
+                    ))
+                })?;
+
+                Ok(Self::new_derivable(k256::ecdsa::SigningKey::from(data).into(), *chain_code))
             }
         }
     }
 
+    /// Derives a child key by following each index in `path` (see [`derive`](Self::derive)).
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if this key has no `chain_code` (key is not derivable)
+    /// - [`Error::KeyDerive`] if an index in `path` produces an invalid ECDSA key.
+    pub fn derive_path(&self, path: &DerivationPath) -> crate::Result<Self> {
+        path.indices().iter().try_fold(self.clone(), |key, &index| key.derive(index as i32))
+    }
+
+    /// Creates the BIP-32 master ECDSA(secp256k1) key for `seed`.
+    ///
+    /// The key is derivable, see [`derive_path`](Self::derive_path).
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if `seed` doesn't make a valid key (this is astronomically unlikely).
+    // the HMAC `expect` is unreachable, any panic would indicate a bug in a dependency of this crate.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn from_seed_ecdsa(seed: &[u8]) -> crate::Result<Self> {
+        let output: [u8; 64] = Hmac::<Sha512>::new_from_slice(b"Bitcoin seed")
+            .expect("HMAC can take keys of any size")
+            .chain_update(seed)
+            .finalize()
+            .into_bytes()
+            .into();
+
+        let (data, chain_code) = split_key_array(&output);
+
+        let data = k256::ecdsa::SigningKey::from_bytes(GenericArray::from_slice(data))
+            .map_err(Error::key_derive)?;
+
+        Ok(Self::new_derivable(data.into(), *chain_code))
+    }
+
     // todo: what do we do about i32?
     // It's basically just a cast to support them, but, unlike Java, operator overloading doesn't exist.
     /// Derive a `PrivateKey` based on `index`.
@@ -45,6 +45,7 @@ use pkcs8::ObjectIdentifier;
 use prost::Message;
 use sha2::Digest;
 
+use crate::ethereum::hash_personal_message;
 use crate::key::private_key::{
     ED25519_OID,
     K256_OID,
@@ -52,6 +53,8 @@ use crate::key::private_key::{
 use crate::protobuf::ToProtobuf;
 use crate::signer::AnySigner;
 use crate::transaction::TransactionSources;
+#[cfg(feature = "serde")]
+use crate::Eip712TypedData;
 use crate::{
     AccountId,
     Error,
@@ -347,6 +350,53 @@ impl PublicKey {
         }
     }
 
+    /// Recovers the ECDSA(secp256k1) key which signed the 32 byte `hash` from an `r || s || v` `signature`.
+    ///
+    /// `v` is either 0 or 1, or 27 or 28 as Ethereum wallets return it.
+    /// The address `ecrecover` returns for the signature is the [`to_evm_address`](Self::to_evm_address) of the recovered key.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if `signature` is malformed, or no key can be recovered from it.
+    pub fn recover_from_prehash(hash: &[u8; 32], signature: &[u8]) -> crate::Result<Self> {
+        let [signature @ .., v] = signature else {
+            return Err(Error::signature_verify("expected a 65 byte `r || s || v` signature"));
+        };
+
+        if signature.len() != 64 {
+            return Err(Error::signature_verify("expected a 65 byte `r || s || v` signature"));
+        }
+
+        let recovery_id = ecdsa::RecoveryId::from_byte(if *v >= 27 { v - 27 } else { *v })
+            .ok_or_else(|| Error::signature_verify(format!("invalid recovery ID `{v}`")))?;
+
+        let signature = ecdsa::Signature::try_from(signature).map_err(Error::signature_verify)?;
+
+        ecdsa::VerifyingKey::recover_from_prehash(hash, &signature, recovery_id)
+            .map(Self::ecdsa)
+            .map_err(Error::signature_verify)
+    }
+
+    /// Recovers the ECDSA(secp256k1) key which signed `message` with `personal_sign` (EIP-191).
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if `signature` is malformed, or no key can be recovered from it.
+    pub fn recover_personal_message(message: &[u8], signature: &[u8]) -> crate::Result<Self> {
+        Self::recover_from_prehash(&hash_personal_message(message), signature)
+    }
+
+    /// Recovers the ECDSA(secp256k1) key which signed EIP-712 `typed_data` with `eth_signTypedData_v4`.
+    ///
+    /// # Errors
+    /// - [`Error::Abi`] if `typed_data`'s domain or message doesn't match its type.
+    /// - [`Error::SignatureVerify`] if `signature` is malformed, or no key can be recovered from it.
+    #[cfg(feature = "serde")]
+    pub fn recover_typed_data(
+        typed_data: &Eip712TypedData,
+        signature: &[u8],
+    ) -> crate::Result<Self> {
+        Self::recover_from_prehash(&typed_data.signing_hash()?, signature)
+    }
+
     /// Verify a `signature` on a `msg` with this public key.
     ///
     /// # Errors
@@ -228,3 +228,40 @@ fn k256_uncompressed_pkcs8_ec_spki_der() {
         "03aaac1c3ac1bea0245b8e00ce1e2018f9eab61b6331fbef7266f2287750a65977"
     )
 }
+
+#[test]
+fn recover_personal_message() {
+    let signature = hex!(
+        "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd"
+        "6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a029"
+        "1c"
+    );
+
+    let pk = PublicKey::recover_personal_message(b"Some data", &signature).unwrap();
+
+    assert_eq!(
+        pk.to_evm_address(),
+        Some(EvmAddress::from(hex!("2c7536e3605d9c16a7a3d7b1898e529396a65c23")))
+    );
+
+    // a `v` of 0 or 1 works too.
+    let mut signature = signature;
+    signature[64] -= 27;
+
+    assert_eq!(PublicKey::recover_personal_message(b"Some data", &signature).unwrap(), pk);
+}
+
+#[test]
+fn recover_bad_signature() {
+    let mut signature = [0x11; 65];
+    assert_matches!(
+        PublicKey::recover_personal_message(b"Some data", &signature[..64]),
+        Err(crate::Error::SignatureVerify(_))
+    );
+
+    signature[64] = 29;
+    assert_matches!(
+        PublicKey::recover_personal_message(b"Some data", &signature),
+        Err(crate::Error::SignatureVerify(_))
+    );
+}
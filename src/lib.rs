@@ -119,6 +119,8 @@ mod protobuf;
 
 mod account;
 mod address_book;
+#[cfg(feature = "bulk-executor")]
+mod bulk_executor;
 mod client;
 mod contract;
 mod downcast;
@@ -127,12 +129,16 @@ mod error;
 mod ethereum;
 mod exchange_rates;
 mod execute;
+mod fee_estimator;
 mod fee_schedules;
 mod file;
 mod hbar;
+mod interceptor;
 mod key;
 mod ledger_id;
 mod mirror_query;
+#[cfg(feature = "mirror-rest")]
+mod mirror_rest;
 #[cfg(feature = "mnemonic")]
 mod mnemonic;
 mod network_version_info;
@@ -148,11 +154,16 @@ mod query;
 mod retry;
 mod schedule;
 mod semantic_version;
+#[cfg(feature = "serde")]
+mod serde_impls;
 mod service_endpoint;
 mod signer;
 mod staked_id;
 mod staking_info;
+mod submission_journal;
 mod system;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod token;
 mod topic;
 mod transaction;
@@ -188,9 +199,22 @@ pub use address_book::{
     NodeDeleteTransaction,
     NodeUpdateTransaction,
 };
+#[cfg(feature = "bulk-executor")]
+pub use bulk_executor::BulkExecutor;
 pub use client::Client;
+#[cfg(feature = "serde")]
+pub use client::ClientConfig;
 pub(crate) use client::Operator;
 pub use contract::{
+    AbiError,
+    AbiEvent,
+    AbiFunction,
+    AbiParam,
+    AbiType,
+    AbiValue,
+    BurnTokenResult,
+    ContractAbi,
+    ContractArtifact,
     ContractBytecodeQuery,
     ContractCallQuery,
     ContractCreateFlow,
@@ -204,8 +228,15 @@ pub use contract::{
     ContractInfoQuery,
     ContractLogInfo,
     ContractNonceInfo,
+    ContractRevert,
+    ContractRevertReason,
     ContractUpdateTransaction,
     DelegateContractId,
+    ExchangeRatePrecompile,
+    MintTokenResult,
+    NamedAbiValues,
+    PrngPrecompile,
+    TokenServicePrecompile,
 };
 pub use entity_id::EntityId;
 pub(crate) use entity_id::ValidateChecksums;
@@ -218,11 +249,17 @@ pub use error::{
     MnemonicEntropyError,
     MnemonicParseError,
 };
+#[cfg(feature = "serde")]
+pub use ethereum::Eip712TypedData;
 pub use ethereum::{
+    AccessListItem,
     Eip1559EthereumData,
+    Eip2930EthereumData,
     EthereumData,
     EthereumFlow,
     EthereumTransaction,
+    EthereumTransactionBuilder,
+    EthereumTransactionType,
     EvmAddress,
     LegacyEthereumData,
 };
@@ -230,6 +267,12 @@ pub use exchange_rates::{
     ExchangeRate,
     ExchangeRates,
 };
+pub use fee_estimator::{
+    FeeEstimate,
+    FeeEstimator,
+    FeeUsage,
+    ResourceUsage,
+};
 pub use fee_schedules::{
     FeeComponents,
     FeeData,
@@ -256,9 +299,16 @@ pub use hbar::{
     Tinybar,
 };
 pub use hedera_proto::services::ResponseCodeEnum as Status;
+pub use interceptor::{
+    Interceptor,
+    RequestEvent,
+    ResponseEvent,
+};
 pub use key::{
+    DerivationPath,
     Key,
     KeyList,
+    KeyRequirement,
     PrivateKey,
     PublicKey,
 };
@@ -268,6 +318,16 @@ pub use mirror_query::{
     AnyMirrorQueryResponse,
     MirrorQuery,
 };
+#[cfg(feature = "mirror-rest")]
+pub use mirror_rest::{
+    MirrorAccount,
+    MirrorContractLog,
+    MirrorContractResult,
+    MirrorNft,
+    MirrorRestClient,
+    MirrorTokenBalance,
+    MirrorTransaction,
+};
 #[cfg(feature = "mnemonic")]
 pub use mnemonic::Mnemonic;
 pub use network_version_info::NetworkVersionInfo;
@@ -299,7 +359,15 @@ pub use schedule::{
 };
 pub use semantic_version::SemanticVersion;
 pub use service_endpoint::ServiceEndpoint;
+pub use signer::Signer;
 pub use staking_info::StakingInfo;
+pub use submission_journal::{
+    FileJournal,
+    JournalEntry,
+    MemoryJournal,
+    RecoveredSubmission,
+    SubmissionJournal,
+};
 pub use system::{
     FreezeTransaction,
     FreezeType,
@@ -365,6 +433,7 @@ pub use topic::{
 pub use transaction::{
     AnyTransaction,
     Transaction,
+    TransactionSignatures,
 };
 pub use transaction_hash::TransactionHash;
 pub use transaction_id::TransactionId;
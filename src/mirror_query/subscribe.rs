@@ -203,7 +203,10 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
     timeout: std::time::Duration,
     request: R,
 ) -> impl Stream<Item = crate::Result<I>> + Send {
-    stream! {
+    #[cfg(feature = "tracing")]
+    let span = tracing::info_span!("hedera.subscribe", request = std::any::type_name::<R>());
+
+    let stream = stream! {
         let request = request;
 
         let mut backoff = ExponentialBackoff {
@@ -238,6 +241,9 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
                 backoff.reset();
                 backoff_inf.reset();
 
+                #[cfg(feature = "tracing")]
+                tracing::debug!("subscribed");
+
                 #[allow(unused_labels)]
                 'message: loop {
                     let message = stream.next().await.transpose();
@@ -247,6 +253,9 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
                         Ok(None) => {
                             // end of stream
                             // hopefully due to configured limits or expected conditions
+                            #[cfg(feature = "tracing")]
+                            tracing::debug!("stream ended");
+
                             return;
                         }
 
@@ -264,20 +273,36 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
             match status.code() {
                 tonic::Code::Unavailable | tonic::Code::ResourceExhausted => {
                     // encountered a temporarily down or overloaded service
-                    sleep(backoff_inf.next_backoff().unwrap()).await;
+                    let delay = backoff_inf.next_backoff().unwrap();
+
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(grpc_status = ?status.code(), backoff = ?delay, "resubscribing");
+
+                    sleep(delay).await;
                 }
 
                 tonic::Code::Unknown if status.message() == "error reading a body from connection: connection reset" => {
                     // connection was aborted by the server
-                    sleep(backoff_inf.next_backoff().unwrap()).await;
+                    let delay = backoff_inf.next_backoff().unwrap();
+
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(grpc_status = ?status.code(), backoff = ?delay, "resubscribing after connection reset");
+
+                    sleep(delay).await;
                 }
 
                 code if request.should_retry(code) => {
                     if let Some(duration) = backoff.next_backoff() {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(grpc_status = ?code, backoff = ?duration, "resubscribing");
+
                         sleep(duration).await;
                     } else {
                         // maximum time allowed has elapsed
                         // NOTE: it should be impossible to reach here without capturing at least one error
+                        #[cfg(feature = "tracing")]
+                        tracing::error!(grpc_status = ?code, "timed out resubscribing");
+
                         yield Err(Error::TimedOut(Error::from(status).into()));
                         return;
                     }
@@ -285,10 +310,22 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
                 _ => {
                     // encountered an un-recoverable failure when attempting
                     // to establish the stream
+                    #[cfg(feature = "tracing")]
+                    tracing::error!(grpc_status = ?status.code(), "subscription failed");
+
                     yield Err(Error::from(status));
                     return;
                 }
             }
         }
-    }
+    };
+
+    // `tracing::Instrument` only covers futures, so enter the span whenever the stream is polled instead.
+    #[cfg(feature = "tracing")]
+    let stream = {
+        let mut stream = Box::pin(stream);
+        futures_util::stream::poll_fn(move |cx| span.in_scope(|| stream.poll_next_unpin(cx)))
+    };
+
+    stream
 }
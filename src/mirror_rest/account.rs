@@ -0,0 +1,279 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use time::OffsetDateTime;
+
+use super::{
+    parse,
+    Links,
+    Page,
+};
+use crate::{
+    AccountId,
+    Error,
+    EvmAddress,
+    FromProtobuf,
+    Hbar,
+    Key,
+    NftId,
+    PublicKey,
+    TokenId,
+};
+
+/// An account as reported by the mirror node.
+#[derive(Debug, Clone)]
+pub struct MirrorAccount {
+    /// The account's ID.
+    pub account_id: AccountId,
+
+    /// The account's EVM address, if it has one.
+    pub evm_address: Option<EvmAddress>,
+
+    /// The account's key.
+    pub key: Option<Key>,
+
+    /// The account's hbar balance.
+    pub balance: Hbar,
+
+    /// When the balance was last updated.
+    pub balance_timestamp: Option<OffsetDateTime>,
+
+    /// Whether the account has been deleted.
+    pub deleted: bool,
+
+    /// The account's memo.
+    pub memo: String,
+
+    /// The account's ethereum nonce.
+    pub ethereum_nonce: i64,
+
+    /// When the account was created.
+    pub created_timestamp: Option<OffsetDateTime>,
+
+    /// When the account expires.
+    pub expiry_timestamp: Option<OffsetDateTime>,
+}
+
+/// An account's balance of a single token, as reported by the mirror node.
+#[derive(Debug, Clone)]
+pub struct MirrorTokenBalance {
+    /// The token.
+    pub token_id: TokenId,
+
+    /// The balance, in the token's smallest denomination.
+    pub balance: u64,
+
+    /// The token's decimals.
+    pub decimals: Option<u32>,
+
+    /// Whether the token was associated automatically.
+    pub automatic_association: bool,
+}
+
+/// An NFT as reported by the mirror node.
+#[derive(Debug, Clone)]
+pub struct MirrorNft {
+    /// The NFT's ID.
+    pub nft_id: NftId,
+
+    /// The account that owns the NFT, `None` if the NFT has been burned.
+    pub account_id: Option<AccountId>,
+
+    /// The account approved to spend the NFT, if any.
+    pub spender: Option<AccountId>,
+
+    /// The NFT's metadata.
+    pub metadata: Vec<u8>,
+
+    /// Whether the NFT has been deleted (burned or wiped).
+    pub deleted: bool,
+
+    /// When the NFT was minted.
+    pub created_timestamp: Option<OffsetDateTime>,
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(super) struct AccountJson {
+    account: String,
+    balance: Option<BalanceJson>,
+    created_timestamp: Option<String>,
+    #[serde(default)]
+    deleted: bool,
+    #[serde(default)]
+    ethereum_nonce: i64,
+    evm_address: Option<String>,
+    expiry_timestamp: Option<String>,
+    key: Option<KeyJson>,
+    #[serde(default)]
+    memo: String,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct BalanceJson {
+    balance: i64,
+    timestamp: Option<String>,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct KeyJson {
+    #[serde(rename = "_type")]
+    kind: String,
+    key: String,
+}
+
+impl KeyJson {
+    fn into_key(self) -> crate::Result<Key> {
+        let bytes = parse::hex_bytes(&self.key)?;
+
+        match self.kind.as_str() {
+            "ED25519" => PublicKey::from_bytes_ed25519(&bytes).map(Key::Single),
+            "ECDSA_SECP256K1" => PublicKey::from_bytes_ecdsa(&bytes).map(Key::Single),
+            "ProtobufEncoded" => <Key as FromProtobuf<services::Key>>::from_bytes(&bytes),
+            kind => Err(Error::basic_parse(format!("unknown key type `{kind}`"))),
+        }
+    }
+}
+
+impl TryFrom<AccountJson> for MirrorAccount {
+    type Error = Error;
+
+    fn try_from(value: AccountJson) -> Result<Self, Self::Error> {
+        let (balance, balance_timestamp) = match value.balance {
+            Some(it) => (Hbar::from_tinybars(it.balance), it.timestamp),
+            None => (Hbar::ZERO, None),
+        };
+
+        Ok(Self {
+            account_id: value.account.parse()?,
+            evm_address: value.evm_address.as_deref().map(str::parse).transpose()?,
+            key: value.key.map(KeyJson::into_key).transpose()?,
+            balance,
+            balance_timestamp: balance_timestamp.as_deref().map(parse::timestamp).transpose()?,
+            deleted: value.deleted,
+            memo: value.memo,
+            ethereum_nonce: value.ethereum_nonce,
+            created_timestamp: value
+                .created_timestamp
+                .as_deref()
+                .map(parse::timestamp)
+                .transpose()?,
+            expiry_timestamp: value
+                .expiry_timestamp
+                .as_deref()
+                .map(parse::timestamp)
+                .transpose()?,
+        })
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+struct TokenBalanceJson {
+    token_id: String,
+    balance: u64,
+    decimals: Option<u32>,
+    #[serde(default)]
+    automatic_association: bool,
+}
+
+impl TryFrom<TokenBalanceJson> for MirrorTokenBalance {
+    type Error = Error;
+
+    fn try_from(value: TokenBalanceJson) -> Result<Self, Self::Error> {
+        Ok(Self {
+            token_id: value.token_id.parse()?,
+            balance: value.balance,
+            decimals: value.decimals,
+            automatic_association: value.automatic_association,
+        })
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(super) struct TokensPage {
+    tokens: Vec<TokenBalanceJson>,
+    links: Links,
+}
+
+impl Page for TokensPage {
+    type Item = MirrorTokenBalance;
+
+    fn next(&self) -> Option<&str> {
+        self.links.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<crate::Result<Self::Item>> {
+        self.tokens.into_iter().map(TryInto::try_into).collect()
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+struct NftJson {
+    account_id: Option<String>,
+    created_timestamp: Option<String>,
+    #[serde(default)]
+    deleted: bool,
+    metadata: Option<String>,
+    serial_number: u64,
+    spender: Option<String>,
+    token_id: String,
+}
+
+impl TryFrom<NftJson> for MirrorNft {
+    type Error = Error;
+
+    fn try_from(value: NftJson) -> Result<Self, Self::Error> {
+        Ok(Self {
+            nft_id: NftId { token_id: value.token_id.parse()?, serial: value.serial_number },
+            account_id: value.account_id.as_deref().map(str::parse).transpose()?,
+            spender: value.spender.as_deref().map(str::parse).transpose()?,
+            metadata: value
+                .metadata
+                .as_deref()
+                .map(parse::base64_bytes)
+                .transpose()?
+                .unwrap_or_default(),
+            deleted: value.deleted,
+            created_timestamp: value
+                .created_timestamp
+                .as_deref()
+                .map(parse::timestamp)
+                .transpose()?,
+        })
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(super) struct NftsPage {
+    nfts: Vec<NftJson>,
+    links: Links,
+}
+
+impl Page for NftsPage {
+    type Item = MirrorNft;
+
+    fn next(&self) -> Option<&str> {
+        self.links.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<crate::Result<Self::Item>> {
+        self.nfts.into_iter().map(TryInto::try_into).collect()
+    }
+}
@@ -0,0 +1,218 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use time::OffsetDateTime;
+
+use super::{
+    parse,
+    Links,
+    Page,
+};
+use crate::{
+    ContractId,
+    ContractLogInfo,
+    Error,
+    EvmAddress,
+    Hbar,
+    Status,
+};
+
+/// The result of a contract call as reported by the mirror node.
+#[derive(Debug, Clone)]
+pub struct MirrorContractResult {
+    /// The contract that was called.
+    pub contract_id: ContractId,
+
+    /// When the call reached consensus.
+    pub timestamp: OffsetDateTime,
+
+    /// The result of the call's transaction.
+    pub result: Status,
+
+    /// The EVM address of the caller.
+    pub from: Option<EvmAddress>,
+
+    /// The EVM address of the callee.
+    pub to: Option<EvmAddress>,
+
+    /// The amount of hbar sent with the call.
+    pub amount: Hbar,
+
+    /// The gas limit of the call.
+    pub gas_limit: u64,
+
+    /// The gas used by the call.
+    pub gas_used: u64,
+
+    /// The ABI encoded parameters of the call.
+    pub function_parameters: Vec<u8>,
+
+    /// The data returned by the call.
+    pub call_result: Vec<u8>,
+
+    /// The error message, if the call failed.
+    pub error_message: Option<String>,
+
+    /// The hash of the call's transaction.
+    pub hash: Vec<u8>,
+}
+
+/// A contract log as reported by the mirror node.
+#[derive(Debug, Clone)]
+pub struct MirrorContractLog {
+    /// The log itself.
+    pub log: ContractLogInfo,
+
+    /// The log's index within its transaction.
+    pub index: u32,
+
+    /// When the transaction that emitted the log reached consensus.
+    pub timestamp: OffsetDateTime,
+
+    /// The contract that was called by the transaction, which can differ from the contract that emitted the log.
+    pub root_contract_id: Option<ContractId>,
+
+    /// The hash of the transaction that emitted the log.
+    pub transaction_hash: Vec<u8>,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct ContractResultJson {
+    #[serde(default)]
+    amount: i64,
+    call_result: Option<String>,
+    contract_id: String,
+    error_message: Option<String>,
+    from: Option<String>,
+    function_parameters: Option<String>,
+    #[serde(default)]
+    gas_limit: u64,
+    #[serde(default)]
+    gas_used: u64,
+    hash: String,
+    result: String,
+    timestamp: String,
+    to: Option<String>,
+}
+
+impl TryFrom<ContractResultJson> for MirrorContractResult {
+    type Error = Error;
+
+    fn try_from(value: ContractResultJson) -> Result<Self, Self::Error> {
+        Ok(Self {
+            contract_id: value.contract_id.parse()?,
+            timestamp: parse::timestamp(&value.timestamp)?,
+            result: parse::status(&value.result)?,
+            from: value.from.as_deref().map(str::parse).transpose()?,
+            to: value.to.as_deref().map(str::parse).transpose()?,
+            amount: Hbar::from_tinybars(value.amount),
+            gas_limit: value.gas_limit,
+            gas_used: value.gas_used,
+            function_parameters: value
+                .function_parameters
+                .as_deref()
+                .map(parse::hex_bytes)
+                .transpose()?
+                .unwrap_or_default(),
+            call_result: value
+                .call_result
+                .as_deref()
+                .map(parse::hex_bytes)
+                .transpose()?
+                .unwrap_or_default(),
+            error_message: value.error_message.filter(|it| !it.is_empty()),
+            hash: parse::hex_bytes(&value.hash)?,
+        })
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(super) struct ResultsPage {
+    results: Vec<ContractResultJson>,
+    links: Links,
+}
+
+impl Page for ResultsPage {
+    type Item = MirrorContractResult;
+
+    fn next(&self) -> Option<&str> {
+        self.links.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<crate::Result<Self::Item>> {
+        self.results.into_iter().map(TryInto::try_into).collect()
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+struct ContractLogJson {
+    bloom: Option<String>,
+    contract_id: String,
+    data: Option<String>,
+    index: u32,
+    root_contract_id: Option<String>,
+    timestamp: String,
+    #[serde(default)]
+    topics: Vec<String>,
+    transaction_hash: String,
+}
+
+impl TryFrom<ContractLogJson> for MirrorContractLog {
+    type Error = Error;
+
+    fn try_from(value: ContractLogJson) -> Result<Self, Self::Error> {
+        let log = ContractLogInfo {
+            contract_id: value.contract_id.parse()?,
+            bloom: value.bloom.as_deref().map(parse::hex_bytes).transpose()?.unwrap_or_default(),
+            topics: value
+                .topics
+                .iter()
+                .map(|it| parse::hex_bytes(it))
+                .collect::<crate::Result<_>>()?,
+            data: value.data.as_deref().map(parse::hex_bytes).transpose()?.unwrap_or_default(),
+        };
+
+        Ok(Self {
+            log,
+            index: value.index,
+            timestamp: parse::timestamp(&value.timestamp)?,
+            root_contract_id: value.root_contract_id.as_deref().map(str::parse).transpose()?,
+            transaction_hash: parse::hex_bytes(&value.transaction_hash)?,
+        })
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(super) struct LogsPage {
+    logs: Vec<ContractLogJson>,
+    links: Links,
+}
+
+impl Page for LogsPage {
+    type Item = MirrorContractLog;
+
+    fn next(&self) -> Option<&str> {
+        self.links.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<crate::Result<Self::Item>> {
+        self.logs.into_iter().map(TryInto::try_into).collect()
+    }
+}
@@ -0,0 +1,302 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+mod account;
+mod contract;
+mod parse;
+#[cfg(test)]
+mod tests;
+mod transaction;
+
+use bytes::Bytes;
+use futures_core::stream::BoxStream;
+use http_body_util::{
+    BodyExt,
+    Empty,
+};
+use hyper::Uri;
+use hyper_rustls::HttpsConnector;
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::rt::TokioExecutor;
+use serde::de::DeserializeOwned;
+use triomphe::Arc;
+
+pub use self::account::{
+    MirrorAccount,
+    MirrorNft,
+    MirrorTokenBalance,
+};
+pub use self::contract::{
+    MirrorContractLog,
+    MirrorContractResult,
+};
+pub use self::transaction::MirrorTransaction;
+use crate::{
+    AccountId,
+    Client,
+    ContractId,
+    Error,
+    TransactionId,
+};
+
+/// The port the mirror node REST API listens on in a local network.
+const LOCAL_REST_PORT: u16 = 5551;
+
+/// The number of items to request per page.
+const PAGE_LIMIT: u32 = 100;
+
+/// A client for the [mirror node REST API](https://docs.hedera.com/hedera/sdks-and-apis/rest-api).
+///
+/// Unlike the gRPC mirror queries, the REST API can look up accounts by alias or EVM address,
+/// list token balances and NFTs, and page through historical transactions and contract results.
+///
+/// Endpoints that return a list are exposed as streams that transparently follow the mirror node's pagination.
+///
+/// Requires the `mirror-rest` feature.
+///
+/// # Examples
+/// ```no_run
+/// # async fn example() -> hedera::Result<()> {
+/// use futures_util::TryStreamExt;
+/// use hedera::{AccountId, Client, MirrorRestClient};
+///
+/// let client = Client::for_testnet();
+/// let mirror = MirrorRestClient::for_client(&client)?;
+///
+/// let account = mirror.account(AccountId::new(0, 0, 1001)).await?;
+/// println!("balance: {}", account.balance);
+///
+/// let nfts: Vec<_> = mirror.account_nfts(account.account_id).try_collect().await?;
+/// println!("owns {} nfts", nfts.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct MirrorRestClient {
+    base_url: Arc<str>,
+    http: hyper_util::client::legacy::Client<HttpsConnector<HttpConnector>, Empty<Bytes>>,
+}
+
+impl MirrorRestClient {
+    /// Create a client for the REST API at `base_url`, for example `https://testnet.mirrornode.hedera.com`.
+    ///
+    /// `base_url` is the mirror node's root, without the `/api/v1` suffix.
+    #[must_use]
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+
+        while base_url.ends_with('/') {
+            base_url.pop();
+        }
+
+        let connector = hyper_rustls::HttpsConnectorBuilder::new()
+            .with_webpki_roots()
+            .https_or_http()
+            .enable_http1()
+            .build();
+
+        let http =
+            hyper_util::client::legacy::Client::builder(TokioExecutor::new()).build(connector);
+
+        Self { base_url: base_url.into(), http }
+    }
+
+    /// Create a client for the REST API of the mirror network that `client` is configured with.
+    ///
+    /// Mirror nodes serve the REST API on the same host as gRPC, except for local networks
+    /// (`localhost` / `127.0.0.1`), where it's served over plaintext on port `5551`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `client` has no mirror network.
+    pub fn for_client(client: &Client) -> crate::Result<Self> {
+        let address = client
+            .mirror_network()
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::basic_parse("client has no mirror network"))?;
+
+        Ok(Self::new(base_url_for(&address)))
+    }
+
+    /// Returns the base URL requests are made against.
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Look up an account.
+    ///
+    /// `account_id` can be a regular account ID, an alias ([`AccountId::alias`]),
+    /// or an EVM address ([`AccountId::evm_address`]).
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRestStatus`] with status `404` if the account doesn't exist.
+    /// - [`Error::MirrorRest`] if the request fails or the response is malformed.
+    pub async fn account(&self, account_id: AccountId) -> crate::Result<MirrorAccount> {
+        let json: account::AccountJson =
+            self.get(&format!("/api/v1/accounts/{}", parse::account_path(&account_id))).await?;
+
+        json.try_into()
+    }
+
+    /// Stream the token balances of an account.
+    pub fn account_tokens(
+        &self,
+        account_id: AccountId,
+    ) -> BoxStream<'static, crate::Result<MirrorTokenBalance>> {
+        self.paginate::<account::TokensPage>(format!(
+            "/api/v1/accounts/{}/tokens?limit={PAGE_LIMIT}",
+            parse::account_path(&account_id)
+        ))
+    }
+
+    /// Stream the NFTs owned by an account.
+    pub fn account_nfts(
+        &self,
+        account_id: AccountId,
+    ) -> BoxStream<'static, crate::Result<MirrorNft>> {
+        self.paginate::<account::NftsPage>(format!(
+            "/api/v1/accounts/{}/nfts?limit={PAGE_LIMIT}",
+            parse::account_path(&account_id)
+        ))
+    }
+
+    /// Stream the transactions that involve an account, most recent first.
+    pub fn account_transactions(
+        &self,
+        account_id: AccountId,
+    ) -> BoxStream<'static, crate::Result<MirrorTransaction>> {
+        self.paginate::<transaction::TransactionsPage>(format!(
+            "/api/v1/transactions?account.id={}&limit={PAGE_LIMIT}",
+            parse::account_path(&account_id)
+        ))
+    }
+
+    /// Stream all transactions on the network, most recent first.
+    pub fn transactions(&self) -> BoxStream<'static, crate::Result<MirrorTransaction>> {
+        self.paginate::<transaction::TransactionsPage>(format!(
+            "/api/v1/transactions?limit={PAGE_LIMIT}"
+        ))
+    }
+
+    /// Fetch the transactions with the given ID.
+    ///
+    /// A transaction ID maps to more than one transaction when the transaction triggered child transactions,
+    /// or when it was submitted more than once.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRestStatus`] with status `404` if the transaction doesn't exist.
+    /// - [`Error::MirrorRest`] if the request fails or the response is malformed.
+    pub async fn transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> crate::Result<Vec<MirrorTransaction>> {
+        let page: transaction::TransactionsPage = self
+            .get(&format!("/api/v1/transactions/{}", parse::transaction_id_path(transaction_id)))
+            .await?;
+
+        page.transactions.into_iter().map(TryInto::try_into).collect()
+    }
+
+    /// Stream the results of calls to a contract, most recent first.
+    pub fn contract_results(
+        &self,
+        contract_id: ContractId,
+    ) -> BoxStream<'static, crate::Result<MirrorContractResult>> {
+        self.paginate::<contract::ResultsPage>(format!(
+            "/api/v1/contracts/{}/results?limit={PAGE_LIMIT}",
+            parse::contract_path(&contract_id)
+        ))
+    }
+
+    /// Stream the logs emitted by a contract, most recent first.
+    pub fn contract_logs(
+        &self,
+        contract_id: ContractId,
+    ) -> BoxStream<'static, crate::Result<MirrorContractLog>> {
+        self.paginate::<contract::LogsPage>(format!(
+            "/api/v1/contracts/{}/results/logs?limit={PAGE_LIMIT}",
+            parse::contract_path(&contract_id)
+        ))
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> crate::Result<T> {
+        let uri: Uri = format!("{}{path}", self.base_url).parse().map_err(Error::mirror_rest)?;
+
+        log::debug!("mirror node REST request: GET {uri}");
+
+        let response = self.http.get(uri).await.map_err(Error::mirror_rest)?;
+        let status = response.status();
+        let body = response.into_body().collect().await.map_err(Error::mirror_rest)?.to_bytes();
+
+        if !status.is_success() {
+            return Err(Error::MirrorRestStatus {
+                status: status.as_u16(),
+                message: parse::error_message(&body),
+            });
+        }
+
+        serde_json::from_slice(&body).map_err(Error::mirror_rest)
+    }
+
+    fn paginate<P: Page>(&self, path: String) -> BoxStream<'static, crate::Result<P::Item>> {
+        let this = self.clone();
+
+        Box::pin(async_stream::try_stream! {
+            let mut next = Some(path);
+
+            while let Some(path) = next.take() {
+                let page: P = this.get(&path).await?;
+                next = page.next().map(ToOwned::to_owned);
+
+                for item in page.into_items() {
+                    yield item?;
+                }
+            }
+        })
+    }
+}
+
+/// A single page of a paginated REST response.
+trait Page: DeserializeOwned + Send + 'static {
+    type Item: Send + 'static;
+
+    /// The path of the next page, if there is one.
+    fn next(&self) -> Option<&str>;
+
+    fn into_items(self) -> Vec<crate::Result<Self::Item>>;
+}
+
+/// The `links` object at the end of every paginated response.
+#[derive(serde_derive::Deserialize)]
+struct Links {
+    next: Option<String>,
+}
+
+/// Derive the REST base URL from a gRPC mirror network address.
+fn base_url_for(address: &str) -> String {
+    let (host, port) = address.rsplit_once(':').unwrap_or((address, "443"));
+
+    match (host, port) {
+        ("localhost" | "127.0.0.1", _) => format!("http://{host}:{LOCAL_REST_PORT}"),
+        (_, "443") => format!("https://{host}"),
+        _ => format!("https://{host}:{port}"),
+    }
+}
@@ -0,0 +1,213 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Conversions between the mirror node's JSON representations and SDK types.
+
+use base64::Engine;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use crate::{
+    AccountId,
+    ContractId,
+    Error,
+    Status,
+    ToProtobuf,
+    TransactionId,
+};
+
+/// Format `account_id` the way the mirror node expects it in a path.
+///
+/// Aliases are encoded as unpadded base32 of the protobuf encoded key.
+pub(super) fn account_path(account_id: &AccountId) -> String {
+    if let Some(evm_address) = &account_id.evm_address {
+        evm_address.to_string()
+    } else if let Some(alias) = &account_id.alias {
+        base32(&ToProtobuf::to_bytes(alias))
+    } else {
+        format!("{}.{}.{}", account_id.shard, account_id.realm, account_id.num)
+    }
+}
+
+pub(super) fn contract_path(contract_id: &ContractId) -> String {
+    match &contract_id.evm_address {
+        Some(evm_address) => format!("0x{}", hex::encode(evm_address)),
+        None => format!("{}.{}.{}", contract_id.shard, contract_id.realm, contract_id.num),
+    }
+}
+
+/// Format `transaction_id` as `<account>-<seconds>-<nanos>`.
+pub(super) fn transaction_id_path(transaction_id: &TransactionId) -> String {
+    let account_id = &transaction_id.account_id;
+    let valid_start = transaction_id.valid_start;
+
+    format!(
+        "{}.{}.{}-{}-{:09}",
+        account_id.shard,
+        account_id.realm,
+        account_id.num,
+        valid_start.unix_timestamp(),
+        valid_start.nanosecond()
+    )
+}
+
+/// Extract the error message from an error response, falling back to the raw body.
+pub(super) fn error_message(body: &[u8]) -> String {
+    #[derive(serde_derive::Deserialize)]
+    struct ErrorResponse {
+        #[serde(rename = "_status")]
+        status: ErrorStatus,
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    struct ErrorStatus {
+        messages: Vec<ErrorMessage>,
+    }
+
+    #[derive(serde_derive::Deserialize)]
+    struct ErrorMessage {
+        message: String,
+    }
+
+    match serde_json::from_slice::<ErrorResponse>(body) {
+        Ok(it) => {
+            it.status.messages.into_iter().map(|it| it.message).collect::<Vec<_>>().join("; ")
+        }
+        Err(_) => String::from_utf8_lossy(body).into_owned(),
+    }
+}
+
+/// Parse a `<seconds>.<fraction>` timestamp, the fraction has up to 9 digits.
+pub(super) fn timestamp(s: &str) -> crate::Result<OffsetDateTime> {
+    let (seconds, fraction) = s.split_once('.').unwrap_or((s, ""));
+
+    if fraction.len() > 9 || !fraction.bytes().all(|it| it.is_ascii_digit()) {
+        return Err(Error::basic_parse(format!("invalid timestamp `{s}`")));
+    }
+
+    let seconds: i64 = seconds.parse().map_err(Error::basic_parse)?;
+
+    // the fraction is a decimal, `1.5` is a second and a half, not a second and 5 nanoseconds.
+    let nanos: i64 = format!("{fraction:0<9}").parse().map_err(Error::basic_parse)?;
+
+    OffsetDateTime::from_unix_timestamp(seconds)
+        .map(|it| it + Duration::nanoseconds(nanos))
+        .map_err(Error::basic_parse)
+}
+
+/// Parse a hex string, with or without a `0x` prefix.
+pub(super) fn hex_bytes(s: &str) -> crate::Result<Vec<u8>> {
+    hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(Error::basic_parse)
+}
+
+pub(super) fn base64_bytes(s: &str) -> crate::Result<Vec<u8>> {
+    base64::engine::general_purpose::STANDARD.decode(s).map_err(Error::basic_parse)
+}
+
+pub(super) fn status(s: &str) -> crate::Result<Status> {
+    Status::from_str_name(s).ok_or_else(|| Error::basic_parse(format!("unknown status `{s}`")))
+}
+
+/// Unpadded RFC 4648 base32.
+fn base32(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+    let mut output = String::with_capacity((bytes.len() * 8 + 4) / 5);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+
+    for &byte in bytes {
+        buffer = (buffer << 8) | u16::from(byte);
+        bits += 8;
+
+        while bits >= 5 {
+            bits -= 5;
+            output.push(ALPHABET[usize::from((buffer >> bits) & 0x1f)] as char);
+        }
+    }
+
+    if bits > 0 {
+        output.push(ALPHABET[usize::from((buffer << (5 - bits)) & 0x1f)] as char);
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::{
+        base32,
+        timestamp,
+        transaction_id_path,
+    };
+    use crate::{
+        AccountId,
+        TransactionId,
+    };
+
+    #[test]
+    fn base32_rfc4648_vectors() {
+        assert_eq!(base32(b""), "");
+        assert_eq!(base32(b"f"), "MY");
+        assert_eq!(base32(b"fo"), "MZXQ");
+        assert_eq!(base32(b"foo"), "MZXW6");
+        assert_eq!(base32(b"foob"), "MZXW6YQ");
+        assert_eq!(base32(b"fooba"), "MZXW6YTB");
+        assert_eq!(base32(b"foobar"), "MZXW6YTBOI");
+    }
+
+    #[test]
+    fn parse_timestamp() {
+        let ts = timestamp("1234567890.000000007").unwrap();
+
+        assert_eq!(ts.unix_timestamp(), 1_234_567_890);
+        assert_eq!(ts.nanosecond(), 7);
+    }
+
+    #[test]
+    fn parse_timestamp_short_fraction() {
+        let ts = timestamp("1234567890.5").unwrap();
+
+        assert_eq!(ts.unix_timestamp(), 1_234_567_890);
+        assert_eq!(ts.nanosecond(), 500_000_000);
+
+        assert_eq!(timestamp("1234567890").unwrap().nanosecond(), 0);
+
+        assert!(timestamp("1234567890.1234567890").is_err());
+        assert!(timestamp("1234567890.-5").is_err());
+    }
+
+    #[test]
+    fn transaction_id_to_path() {
+        let id = TransactionId {
+            account_id: AccountId::new(0, 0, 8),
+            valid_start: OffsetDateTime::from_unix_timestamp(1_234_567_890).unwrap()
+                + time::Duration::nanoseconds(6),
+            nonce: None,
+            scheduled: false,
+        };
+
+        assert_eq!(transaction_id_path(&id), "0.0.8-1234567890-000000006");
+    }
+}
@@ -0,0 +1,242 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+
+use assert_matches::assert_matches;
+use futures_util::TryStreamExt;
+use tokio::io::{
+    AsyncReadExt,
+    AsyncWriteExt,
+};
+use tokio::net::TcpListener;
+
+use super::{
+    base_url_for,
+    MirrorRestClient,
+};
+use crate::{
+    AccountId,
+    ContractId,
+    Error,
+    EvmAddress,
+    Hbar,
+    Status,
+};
+
+/// Serve canned responses keyed by request path (including the query), anything else is a `404`.
+async fn stub(routes: HashMap<&'static str, &'static str>) -> MirrorRestClient {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let routes = routes.clone();
+
+            tokio::spawn(async move {
+                let mut request = Vec::new();
+                let mut buf = [0; 1024];
+
+                while !request.ends_with(b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).await.unwrap();
+                    if n == 0 {
+                        return;
+                    }
+
+                    request.extend_from_slice(&buf[..n]);
+                }
+
+                let request = String::from_utf8(request).unwrap();
+                let path = request.split(' ').nth(1).unwrap();
+
+                let (status, body) = match routes.get(path) {
+                    Some(body) => ("200 OK", *body),
+                    None => {
+                        ("404 Not Found", r#"{"_status":{"messages":[{"message":"Not found"}]}}"#)
+                    }
+                };
+
+                let response = format!(
+                    "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+
+                stream.write_all(response.as_bytes()).await.unwrap();
+            });
+        }
+    });
+
+    MirrorRestClient::new(format!("http://{address}/"))
+}
+
+#[test]
+fn base_url() {
+    assert_eq!(
+        base_url_for("testnet.mirrornode.hedera.com:443"),
+        "https://testnet.mirrornode.hedera.com"
+    );
+    assert_eq!(base_url_for("127.0.0.1:5600"), "http://127.0.0.1:5551");
+    assert_eq!(base_url_for("mirror.example.com:8443"), "https://mirror.example.com:8443");
+}
+
+#[tokio::test]
+async fn account_by_evm_address() {
+    let evm_address: EvmAddress = "0x00000000000000000000000000000000000003e9".parse().unwrap();
+
+    let client = stub(HashMap::from([(
+        "/api/v1/accounts/0x00000000000000000000000000000000000003e9",
+        r#"{
+            "account": "0.0.1001",
+            "balance": {"balance": 250000000, "timestamp": "1700000000.000000001", "tokens": []},
+            "created_timestamp": "1600000000.000000000",
+            "deleted": false,
+            "ethereum_nonce": 3,
+            "evm_address": "0x00000000000000000000000000000000000003e9",
+            "expiry_timestamp": null,
+            "key": {"_type": "ED25519", "key": "e0c8ec2758a5879ffac226a13c0c516b799e72e35141a0dd828f94d37988a4b7"},
+            "memo": "hello"
+        }"#,
+    )]))
+    .await;
+
+    let account = client.account(AccountId::from_evm_address(&evm_address)).await.unwrap();
+
+    assert_eq!(account.account_id, AccountId::new(0, 0, 1001));
+    assert_eq!(account.evm_address, Some(evm_address));
+    assert_eq!(account.balance, Hbar::from_tinybars(250_000_000));
+    assert_eq!(account.ethereum_nonce, 3);
+    assert_eq!(account.memo, "hello");
+    assert!(account.key.is_some());
+    assert!(account.expiry_timestamp.is_none());
+}
+
+#[tokio::test]
+async fn missing_account_is_404() {
+    let client = stub(HashMap::new()).await;
+
+    let res = client.account(AccountId::new(0, 0, 5)).await;
+
+    assert_matches!(res, Err(Error::MirrorRestStatus { status: 404, message }) if message == "Not found");
+}
+
+#[tokio::test]
+async fn nfts_follow_pagination() {
+    let client = stub(HashMap::from([
+        (
+            "/api/v1/accounts/0.0.1001/nfts?limit=100",
+            r#"{
+                "nfts": [
+                    {"account_id": "0.0.1001", "deleted": false, "metadata": "aGVsbG8=", "serial_number": 2, "spender": null, "token_id": "0.0.7"}
+                ],
+                "links": {"next": "/api/v1/accounts/0.0.1001/nfts?limit=100&serialnumber=lt:2"}
+            }"#,
+        ),
+        (
+            "/api/v1/accounts/0.0.1001/nfts?limit=100&serialnumber=lt:2",
+            r#"{
+                "nfts": [
+                    {"account_id": "0.0.1001", "deleted": false, "metadata": "", "serial_number": 1, "spender": "0.0.9", "token_id": "0.0.7"}
+                ],
+                "links": {"next": null}
+            }"#,
+        ),
+    ]))
+    .await;
+
+    let nfts: Vec<_> = client.account_nfts(AccountId::new(0, 0, 1001)).try_collect().await.unwrap();
+
+    assert_eq!(nfts.len(), 2);
+    assert_eq!(nfts[0].nft_id.to_string(), "0.0.7/2");
+    assert_eq!(nfts[0].metadata, b"hello");
+    assert_eq!(nfts[1].nft_id.serial, 1);
+    assert_eq!(nfts[1].spender, Some(AccountId::new(0, 0, 9)));
+}
+
+#[tokio::test]
+async fn account_transactions() {
+    let client = stub(HashMap::from([(
+        "/api/v1/transactions?account.id=0.0.8&limit=100",
+        r#"{
+            "transactions": [{
+                "charged_tx_fee": 7,
+                "consensus_timestamp": "1234567890.000000007",
+                "memo_base64": null,
+                "name": "CRYPTOTRANSFER",
+                "node": "0.0.3",
+                "nonce": 0,
+                "result": "SUCCESS",
+                "scheduled": false,
+                "transaction_hash": "AAEC",
+                "transaction_id": "0.0.8-1234567890-000000006",
+                "transfers": [{"account": "0.0.3", "amount": 2, "is_approval": false}, {"account": "0.0.8", "amount": -2, "is_approval": false}]
+            }],
+            "links": {"next": null}
+        }"#,
+    )]))
+    .await;
+
+    let transactions: Vec<_> =
+        client.account_transactions(AccountId::new(0, 0, 8)).try_collect().await.unwrap();
+
+    assert_eq!(transactions.len(), 1);
+
+    let transaction = &transactions[0];
+    assert_eq!(transaction.transaction_id.to_string(), "0.0.8@1234567890.6");
+    assert_eq!(transaction.result, Status::Success);
+    assert_eq!(transaction.charged_tx_fee, Hbar::from_tinybars(7));
+    assert_eq!(transaction.node, Some(AccountId::new(0, 0, 3)));
+    assert_eq!(transaction.transaction_hash, [0, 1, 2]);
+    assert_eq!(transaction.transfers.len(), 2);
+}
+
+#[tokio::test]
+async fn contract_logs() {
+    let client = stub(HashMap::from([(
+        "/api/v1/contracts/0.0.1002/results/logs?limit=100",
+        r#"{
+            "logs": [{
+                "address": "0x00000000000000000000000000000000000003ea",
+                "bloom": "0x0102",
+                "contract_id": "0.0.1002",
+                "data": "0x",
+                "index": 0,
+                "root_contract_id": "0.0.1002",
+                "timestamp": "1234567890.000000007",
+                "topics": ["0xddf252ad", "0x00"],
+                "transaction_hash": "0xabcdef"
+            }],
+            "links": {"next": null}
+        }"#,
+    )]))
+    .await;
+
+    let logs: Vec<_> =
+        client.contract_logs(ContractId::new(0, 0, 1002)).try_collect().await.unwrap();
+
+    assert_eq!(logs.len(), 1);
+
+    let log = &logs[0];
+    assert_eq!(log.log.contract_id, ContractId::new(0, 0, 1002));
+    assert_eq!(log.log.bloom, [1, 2]);
+    assert_eq!(log.log.topics, [vec![0xdd, 0xf2, 0x52, 0xad], vec![0]]);
+    assert!(log.log.data.is_empty());
+    assert_eq!(log.transaction_hash, [0xab, 0xcd, 0xef]);
+}
@@ -0,0 +1,149 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use time::OffsetDateTime;
+
+use super::{
+    parse,
+    Links,
+    Page,
+};
+use crate::{
+    AccountId,
+    Error,
+    Hbar,
+    Status,
+    TransactionId,
+    Transfer,
+};
+
+/// A transaction as reported by the mirror node.
+#[derive(Debug, Clone)]
+pub struct MirrorTransaction {
+    /// The transaction's ID.
+    pub transaction_id: TransactionId,
+
+    /// When the transaction reached consensus.
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// The type of transaction, for instance `CRYPTOTRANSFER`.
+    pub name: String,
+
+    /// The result of the transaction.
+    pub result: Status,
+
+    /// The fee charged for the transaction.
+    pub charged_tx_fee: Hbar,
+
+    /// The transaction's memo.
+    pub memo: Vec<u8>,
+
+    /// The node the transaction was submitted to.
+    pub node: Option<AccountId>,
+
+    /// The SHA-384 hash of the transaction.
+    pub transaction_hash: Vec<u8>,
+
+    /// The hbar transfers made by the transaction.
+    pub transfers: Vec<Transfer>,
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(super) struct TransactionJson {
+    #[serde(default)]
+    charged_tx_fee: i64,
+    consensus_timestamp: String,
+    memo_base64: Option<String>,
+    name: String,
+    node: Option<String>,
+    #[serde(default)]
+    nonce: i32,
+    result: String,
+    #[serde(default)]
+    scheduled: bool,
+    transaction_hash: String,
+    transaction_id: String,
+    #[serde(default)]
+    transfers: Vec<TransferJson>,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct TransferJson {
+    account: String,
+    amount: i64,
+}
+
+impl TryFrom<TransactionJson> for MirrorTransaction {
+    type Error = Error;
+
+    fn try_from(value: TransactionJson) -> Result<Self, Self::Error> {
+        let mut transaction_id: TransactionId = value.transaction_id.parse()?;
+        transaction_id.scheduled = value.scheduled;
+        transaction_id.nonce = (value.nonce != 0).then_some(value.nonce);
+
+        let transfers = value
+            .transfers
+            .into_iter()
+            .map(|it| {
+                Ok(Transfer {
+                    account_id: it.account.parse()?,
+                    amount: Hbar::from_tinybars(it.amount),
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self {
+            transaction_id,
+            consensus_timestamp: parse::timestamp(&value.consensus_timestamp)?,
+            name: value.name,
+            result: parse::status(&value.result)?,
+            charged_tx_fee: Hbar::from_tinybars(value.charged_tx_fee),
+            memo: value
+                .memo_base64
+                .as_deref()
+                .map(parse::base64_bytes)
+                .transpose()?
+                .unwrap_or_default(),
+            node: value.node.as_deref().map(str::parse).transpose()?,
+            transaction_hash: parse::base64_bytes(&value.transaction_hash)?,
+            transfers,
+        })
+    }
+}
+
+#[derive(serde_derive::Deserialize)]
+pub(super) struct TransactionsPage {
+    pub(super) transactions: Vec<TransactionJson>,
+    // `/transactions/{id}` doesn't paginate.
+    #[serde(default)]
+    links: Option<Links>,
+}
+
+impl Page for TransactionsPage {
+    type Item = MirrorTransaction;
+
+    fn next(&self) -> Option<&str> {
+        self.links.as_ref()?.next.as_deref()
+    }
+
+    fn into_items(self) -> Vec<crate::Result<Self::Item>> {
+        self.transactions.into_iter().map(TryInto::try_into).collect()
+    }
+}
@@ -39,6 +39,7 @@ use crate::error::{
     MnemonicParseError,
 };
 use crate::{
+    DerivationPath,
     Error,
     PrivateKey,
 };
@@ -205,6 +206,38 @@ impl Mnemonic {
         }
     }
 
+    /// Recover an ECDSA(secp256k1) [`PrivateKey`] from this `Mnemonic`, at the BIP-44 path `m/44'/60'/0'/0/{index}`.
+    ///
+    /// This is the key MetaMask and the other Hedera SDKs recover for the same mnemonic and index.
+    ///
+    /// # Errors
+    /// - [`MnemonicEntropyError::LegacyEcdsa`] if this is a v1 legacy mnemonic, which has no BIP-39 seed.
+    /// - [`Error::KeyDerive`] if `index` makes an invalid key, in which case the next index should be used.
+    pub fn to_standard_ecdsa_secp256k1_private_key(
+        &self,
+        passphrase: &str,
+        index: u32,
+    ) -> crate::Result<PrivateKey> {
+        self.to_ecdsa_private_key_with_path(passphrase, &DerivationPath::evm(index))
+    }
+
+    /// Recover an ECDSA(secp256k1) [`PrivateKey`] from this `Mnemonic` by following `path` from its BIP-32 master key.
+    ///
+    /// # Errors
+    /// - [`MnemonicEntropyError::LegacyEcdsa`] if this is a v1 legacy mnemonic, which has no BIP-39 seed.
+    /// - [`Error::KeyDerive`] if an index in `path` makes an invalid key.
+    pub fn to_ecdsa_private_key_with_path(
+        &self,
+        passphrase: &str,
+        path: &DerivationPath,
+    ) -> crate::Result<PrivateKey> {
+        if let MnemonicData::V1(_) = &self.0 {
+            return Err(Error::from(MnemonicEntropyError::LegacyEcdsa));
+        }
+
+        PrivateKey::from_seed_ecdsa(&self.to_seed(passphrase))?.derive_path(path)
+    }
+
     pub(crate) fn to_seed(&self, phrase: &str) -> [u8; 64] {
         let mut salt = String::from("mnemonic");
         salt.push_str(phrase);
@@ -476,7 +509,10 @@ mod tests {
     use hex_literal::hex;
 
     use super::Mnemonic;
-    use crate::error::MnemonicParseError;
+    use crate::error::{
+        MnemonicEntropyError,
+        MnemonicParseError,
+    };
     use crate::Error;
 
     const KNOWN_GOOD_MNEMONICS: &[&str] = &[
@@ -656,6 +692,62 @@ mod tests {
         .assert_debug_eq(key.debug_pretty());
     }
 
+    #[test]
+    fn to_standard_ecdsa_secp256k1_private_key() {
+        const MNEMONIC: &str = concat!(
+            "inmate flip alley wear offer often ",
+            "piece magnet surge toddler submit right ",
+            "radio absent pear floor belt raven ",
+            "price stove replace reduce plate home"
+        );
+
+        let mnemonic = Mnemonic::from_str(MNEMONIC).unwrap();
+
+        let key = mnemonic.to_standard_ecdsa_secp256k1_private_key("", 0).unwrap();
+
+        expect![[r#"
+            PrivateKeyData {
+                algorithm: Ecdsa,
+                key: "78f9545e40025cf7da9126a4d6a861ae34031d1c74c3404df06110c9fde371ad",
+                chain_code: Some(
+                    "58a9ee31eaf7499abc01952b44dbf0a2a5d6447512367f09d99381c9605bf9e8",
+                ),
+            }
+        "#]]
+        .assert_debug_eq(key.debug_pretty());
+
+        let key = mnemonic
+            .to_ecdsa_private_key_with_path("", &"m/44'/60'/0'/0/1".parse().unwrap())
+            .unwrap();
+
+        assert_eq!(
+            key.to_string_raw(),
+            "77ca263661ebdd5a8b33c224aeff5e7bf67eedacee68a1699d97ee8929d7b130"
+        );
+
+        let key = mnemonic.to_standard_ecdsa_secp256k1_private_key("some pass", 0).unwrap();
+
+        assert_eq!(
+            key.to_string_raw(),
+            "3df419786b76cd1022fbada72dfd3c5f4a17f92c22359c975af76cbd776f2ce4"
+        );
+    }
+
+    #[test]
+    fn legacy_mnemonic_to_ecdsa() {
+        const MNEMONIC: &str = concat!(
+            "jolly kidnap tom lawn drunk chick optic lust mutter mole bride ",
+            "galley dense member sage neural widow decide curb aboard margin manure"
+        );
+
+        let mnemonic = Mnemonic::from_str(MNEMONIC).unwrap();
+
+        assert_matches!(
+            mnemonic.to_standard_ecdsa_secp256k1_private_key("", 0),
+            Err(Error::MnemonicEntropy(MnemonicEntropyError::LegacyEcdsa))
+        );
+    }
+
     #[test]
     fn to_private_key() {
         const MNEMONIC: &str = concat!(
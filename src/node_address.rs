@@ -18,7 +18,10 @@
  * ‍
  */
 
-use std::net::SocketAddrV4;
+use std::net::{
+    Ipv6Addr,
+    SocketAddrV4,
+};
 
 use hedera_proto::services;
 
@@ -27,21 +30,24 @@ use crate::{
     AccountId,
     Error,
     FromProtobuf,
+    ServiceEndpoint,
 };
 
+fn parse_port(port: i32) -> crate::Result<u16> {
+    u16::try_from(port).map_err(|_| {
+        Error::from_protobuf(format!(
+            "expected 16 bit non-negative port number, but the port was actually `{port}`",
+        ))
+    })
+}
+
 fn parse_socket_addr_v4(ip: Vec<u8>, port: i32) -> crate::Result<SocketAddrV4> {
     let octets: Result<[u8; 4], _> = ip.try_into();
     let octets = octets.map_err(|v| {
         Error::from_protobuf(format!("expected 4 byte ip address, got `{}` bytes", v.len()))
     })?;
 
-    let port = u16::try_from(port).map_err(|_| {
-        Error::from_protobuf(format!(
-            "expected 16 bit non-negative port number, but the port was actually `{port}`",
-        ))
-    })?;
-
-    Ok(SocketAddrV4::new(octets.into(), port))
+    Ok(SocketAddrV4::new(octets.into(), parse_port(port)?))
 }
 
 /// The data about a node, including its service endpoints and the Hedera account to be paid for
@@ -67,10 +73,18 @@ pub struct NodeAddress {
     pub tls_certificate_hash: Vec<u8>,
 
     /// A node's service IP addresses and ports.
+    ///
+    /// Only has the endpoints with an IPv4 address, see `endpoints` for all of them.
     pub service_endpoints: Vec<SocketAddrV4>,
 
     /// A description of the node, up to 100 bytes.
     pub description: String,
+
+    /// Every service endpoint of the node, as published in the address book.
+    ///
+    /// Unlike `service_endpoints` this includes the endpoints published by domain name,
+    /// IPv6 addresses have no field of their own, so they're given as text in `domain_name`.
+    pub endpoints: Vec<ServiceEndpoint>,
 }
 
 impl FromProtobuf<services::NodeAddress> for NodeAddress {
@@ -80,15 +94,52 @@ impl FromProtobuf<services::NodeAddress> for NodeAddress {
     {
         // sometimes this will be oversized by 1, but that's fine.
         let mut addresses = Vec::with_capacity(pb.service_endpoint.len() + 1);
+        let mut endpoints = Vec::with_capacity(pb.service_endpoint.len() + 1);
 
         // `ip_address`/`portno` are deprecated, but lets handle them anyway.
         #[allow(deprecated)]
         if !pb.ip_address.is_empty() {
-            addresses.push(parse_socket_addr_v4(pb.ip_address, pb.portno)?);
+            let address = parse_socket_addr_v4(pb.ip_address, pb.portno)?;
+
+            addresses.push(address);
+            endpoints.push(ServiceEndpoint {
+                ip_address_v4: Some(*address.ip()),
+                port: i32::from(address.port()),
+                domain_name: String::new(),
+            });
         }
 
         for address in pb.service_endpoint {
-            addresses.push(parse_socket_addr_v4(address.ip_address_v4, address.port)?);
+            let port = i32::from(parse_port(address.port)?);
+
+            let endpoint = match address.ip_address_v4.len() {
+                // domain names are allowed to stand on their own.
+                0 if !address.domain_name.is_empty() => {
+                    ServiceEndpoint { ip_address_v4: None, port, domain_name: address.domain_name }
+                }
+                16 => {
+                    let octets: [u8; 16] = address.ip_address_v4.try_into().unwrap();
+
+                    ServiceEndpoint {
+                        ip_address_v4: None,
+                        port,
+                        domain_name: Ipv6Addr::from(octets).to_string(),
+                    }
+                }
+                _ => {
+                    let ip = parse_socket_addr_v4(address.ip_address_v4, address.port)?;
+
+                    addresses.push(ip);
+
+                    ServiceEndpoint {
+                        ip_address_v4: Some(*ip.ip()),
+                        port,
+                        domain_name: address.domain_name,
+                    }
+                }
+            };
+
+            endpoints.push(endpoint);
         }
 
         let node_account_id = AccountId::from_protobuf(pb_getf!(pb, node_account_id)?)?;
@@ -100,6 +151,7 @@ impl FromProtobuf<services::NodeAddress> for NodeAddress {
             service_endpoints: addresses,
             tls_certificate_hash: pb.node_cert_hash,
             node_account_id,
+            endpoints,
         })
     }
 }
@@ -116,6 +168,20 @@ impl ToProtobuf for NodeAddress {
                 port: i32::from(it.port()),
                 domain_name: it.to_string(),
             })
+            .chain(self.endpoints.iter().filter(|it| it.ip_address_v4.is_none()).map(|it| {
+                match it.domain_name.parse::<Ipv6Addr>() {
+                    Ok(ip) => services::ServiceEndpoint {
+                        ip_address_v4: ip.octets().to_vec(),
+                        port: it.port,
+                        domain_name: String::new(),
+                    },
+                    Err(_) => services::ServiceEndpoint {
+                        ip_address_v4: Vec::new(),
+                        port: it.port,
+                        domain_name: it.domain_name.clone(),
+                    },
+                }
+            }))
             .collect();
 
         services::NodeAddress {
@@ -131,3 +197,106 @@ impl ToProtobuf for NodeAddress {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::net::{
+        Ipv4Addr,
+        Ipv6Addr,
+        SocketAddrV4,
+    };
+
+    use hedera_proto::services;
+
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::{
+        AccountId,
+        NodeAddress,
+        ServiceEndpoint,
+    };
+
+    fn endpoint(ip: &[u8], port: i32, domain_name: &str) -> services::ServiceEndpoint {
+        services::ServiceEndpoint {
+            ip_address_v4: ip.to_vec(),
+            port,
+            domain_name: domain_name.to_owned(),
+        }
+    }
+
+    fn make_address(service_endpoint: Vec<services::ServiceEndpoint>) -> services::NodeAddress {
+        services::NodeAddress {
+            node_id: 1,
+            node_account_id: Some(AccountId::new(0, 0, 4).to_protobuf()),
+            service_endpoint,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn from_protobuf_endpoints() {
+        let address = NodeAddress::from_protobuf(make_address(vec![
+            endpoint(&[127, 0, 0, 1], 50211, ""),
+            endpoint(&[], 50212, "node1.solo.local"),
+            endpoint(&Ipv6Addr::LOCALHOST.octets(), 0, ""),
+        ]))
+        .unwrap();
+
+        assert_eq!(address.service_endpoints, [SocketAddrV4::new(Ipv4Addr::LOCALHOST, 50211)]);
+        assert_eq!(
+            address.endpoints,
+            [
+                ServiceEndpoint {
+                    ip_address_v4: Some(Ipv4Addr::LOCALHOST),
+                    port: 50211,
+                    domain_name: String::new(),
+                },
+                ServiceEndpoint {
+                    ip_address_v4: None,
+                    port: 50212,
+                    domain_name: "node1.solo.local".to_owned(),
+                },
+                ServiceEndpoint { ip_address_v4: None, port: 0, domain_name: "::1".to_owned() },
+            ]
+        );
+    }
+
+    #[test]
+    fn from_protobuf_deprecated_address() {
+        #[allow(deprecated)]
+        let pb = services::NodeAddress {
+            ip_address: vec![10, 0, 0, 1],
+            portno: 50211,
+            ..make_address(Vec::new())
+        };
+
+        let address = NodeAddress::from_protobuf(pb).unwrap();
+
+        assert_eq!(address.service_endpoints, [SocketAddrV4::new([10, 0, 0, 1].into(), 50211)]);
+        assert_eq!(address.endpoints.len(), 1);
+    }
+
+    #[test]
+    fn from_protobuf_endpoint_without_host() {
+        NodeAddress::from_protobuf(make_address(vec![endpoint(&[], 50211, "")])).unwrap_err();
+        NodeAddress::from_protobuf(make_address(vec![endpoint(&[1, 2, 3], 50211, "")]))
+            .unwrap_err();
+    }
+
+    #[test]
+    fn from_to_protobuf() {
+        let address = NodeAddress::from_protobuf(make_address(vec![
+            endpoint(&[127, 0, 0, 1], 50211, ""),
+            endpoint(&[], 50212, "node1.solo.local"),
+            endpoint(&Ipv6Addr::LOCALHOST.octets(), 50211, ""),
+        ]))
+        .unwrap();
+
+        let address2 = NodeAddress::from_protobuf(address.to_protobuf()).unwrap();
+
+        assert_eq!(address.service_endpoints, address2.service_endpoints);
+        assert_eq!(address.endpoints[1..], address2.endpoints[1..]);
+    }
+}
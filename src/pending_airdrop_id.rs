@@ -40,6 +40,8 @@ use crate::{
 /// that would modify that pending airdrop (such as a `claimAirdrop` or `cancelAirdrop`).
 ///
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct PendingAirdropId {
     /// A sending account.
     ///
@@ -18,6 +18,7 @@
  * ‍
  */
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
 
@@ -81,17 +82,17 @@ where
         None
     }
 
-    fn make_request(
-        &self,
-        _transaction_id: Option<&TransactionId>,
+    fn make_request<'a>(
+        &'a self,
+        _transaction_id: Option<&'a TransactionId>,
         _node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         let header = services::QueryHeader {
             response_type: services::ResponseType::CostAnswer as i32,
             payment: None,
         };
 
-        Ok((self.0.data.to_query_protobuf(header), ()))
+        Box::pin(std::future::ready(Ok((self.0.data.to_query_protobuf(header), ()))))
     }
 
     fn execute(
@@ -20,6 +20,7 @@
 
 use std::fmt::Debug;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
 
@@ -32,6 +33,7 @@ use crate::query::{
 use crate::{
     AccountId,
     BoxGrpcFuture,
+    ContractRevert,
     Error,
     FromProtobuf,
     Hbar,
@@ -124,20 +126,32 @@ where
         self.data.should_retry(response)
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
-        let payment = if self.data.is_payment_required() {
-            Some(self.payment.make_request(transaction_id, node_account_id)?.0)
-        } else {
-            None
-        };
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
+        Box::pin(async move {
+            let payment = if self.data.is_payment_required() {
+                let payment = self.payment.make_request(transaction_id, node_account_id).await?.0;
+
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    transaction_id = transaction_id.map(tracing::field::display),
+                    %node_account_id,
+                    amount = %self.payment.get_amount().unwrap_or_default(),
+                    "paying for query"
+                );
+
+                Some(payment)
+            } else {
+                None
+            };
 
-        let header = services::QueryHeader { response_type: 0, payment };
+            let header = services::QueryHeader { response_type: 0, payment };
 
-        Ok((self.data.to_query_protobuf(header), ()))
+            Ok((self.data.to_query_protobuf(header), ()))
+        })
     }
 
     fn execute(
@@ -162,8 +176,20 @@ where
         &self,
         status: crate::Status,
         transaction_id: Option<&TransactionId>,
-        _response: Self::GrpcResponse,
+        response: Self::GrpcResponse,
     ) -> crate::Error {
+        if let Some(revert) = ContractRevert::from_response(&response) {
+            return crate::Error::ContractReverted {
+                status,
+                transaction_id: self
+                    .data
+                    .transaction_id()
+                    .or(transaction_id.copied())
+                    .map(Box::new),
+                revert,
+            };
+        }
+
         if let Some(transaction_id) = self.data.transaction_id() {
             crate::Error::QueryPreCheckStatus { status, transaction_id: Box::new(transaction_id) }
         } else if let Some(transaction_id) = transaction_id {
@@ -25,6 +25,7 @@ use crate::execute::execute;
 use crate::query::cost::QueryCost;
 use crate::query::payment_transaction::PaymentTransaction;
 use crate::{
+    submission_journal,
     AccountId,
     Client,
     Error,
@@ -234,6 +235,14 @@ where
     }
 
     // eww long name
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "hedera.query",
+            skip_all,
+            fields(query = std::any::type_name::<D>(), cost = tracing::field::Empty)
+        )
+    )]
     pub(crate) async fn execute_with_optional_timeout(
         &mut self,
         client: &Client,
@@ -265,6 +274,9 @@ where
             // payment is required but none was specified, query the cost
             let cost = QueryCost::new(self).execute(client, None).await?;
 
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("cost", tracing::field::display(cost));
+
             if self.payment.get_max_amount().is_none() {
                 // N.B. This can still be `None`.
                 self.payment.max_amount(client.default_max_query_payment());
@@ -286,7 +298,14 @@ where
             self.payment.freeze_with(client)?;
         }
 
-        execute(client, self, timeout).await
+        let res = execute(client, self, timeout).await;
+
+        // once there's a receipt (or record), the transaction doesn't need recovering anymore.
+        if let Some(transaction_id) = self.data.transaction_id() {
+            submission_journal::complete_entry(client, &transaction_id, &res).await;
+        }
+
+        res
     }
 
     /// Execute this query against the provided client of the Hedera network.
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use futures_core::Future;
 use tokio::time::sleep;
 
@@ -26,6 +28,8 @@ impl Error {
 pub(crate) type Result<T> = std::result::Result<T, Error>;
 
 /// Durably retry some function according to the `backoff` until the backoff expires.
+///
+/// `f` is given how long was spent backing off before it was called.
 pub(crate) async fn retry<B, Fn, O, Fut>(
     mut backoff: B,
     max_attempts: Option<usize>,
@@ -33,16 +37,17 @@ pub(crate) async fn retry<B, Fn, O, Fut>(
 ) -> crate::Result<O>
 where
     B: backoff::backoff::Backoff + Send,
-    Fn: FnMut() -> Fut + Send,
+    Fn: FnMut(Duration) -> Fut + Send,
     Fut: Future<Output = Result<O>> + Send,
 {
     let mut last_error: Option<crate::Error> = None;
     let mut attempt_number = 0;
+    let mut delay = Duration::ZERO;
 
     while max_attempts.map_or(true, |it| attempt_number < it) {
         attempt_number += 1;
 
-        match f().await {
+        match f(delay).await {
             Ok(it) => return Ok(it),
             Err(Error::Transient(e)) => last_error = Some(e),
             Err(Error::EmptyTransient) => {}
@@ -57,6 +62,8 @@ where
             log::warn!("Backing off for {duration_ms}ms after failure of attempt {attempt_number}{err_suffix}");
             sleep(duration).await;
             log::warn!("Backed off for {duration_ms}ms after failure of attempt {attempt_number}{err_suffix}");
+
+            delay = duration;
         } else {
             let last_error = last_error.expect("timeout while network had no healthy nodes");
             return Err(crate::Error::TimedOut(last_error.into()));
@@ -108,6 +108,7 @@ impl ScheduleInfo {
                 operator: None,
                 is_frozen: true,
                 regenerate_transaction_id: Some(false),
+                idempotent: false,
             },
             Vec::new(),
         ))
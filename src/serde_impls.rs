@@ -0,0 +1,336 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! `serde` support for the SDK's model types.
+//!
+//! The representations are part of the public API, so they're kept stable and human readable:
+//! - entity IDs, transaction IDs, public keys, EVM addresses, and ledger IDs are strings,
+//!   in the same format as their `Display` impls (`"0.0.1001"`, `"0.0.1001@1700000000.5"`, ...).
+//! - [`Hbar`] is an integer number of tinybars.
+//! - [`Status`](crate::Status) is the name the status has in the protobufs (`"SUCCESS"`).
+//! - timestamps are `"<seconds>.<nanos>"` strings, like the ones used by mirror nodes.
+//! - durations are an integer number of seconds.
+//! - bytes are hex strings, without a `0x` prefix.
+//!
+//! Structs use `camelCase` field names.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use serde::de::{
+    self,
+    Visitor,
+};
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+use crate::{
+    AccountId,
+    ContractId,
+    DelegateContractId,
+    EntityId,
+    EvmAddress,
+    FileId,
+    Hbar,
+    LedgerId,
+    NftId,
+    PublicKey,
+    ScheduleId,
+    SemanticVersion,
+    TokenId,
+    TopicId,
+    TransactionId,
+};
+
+#[cfg(test)]
+mod tests;
+
+struct FromStrVisitor<T>(PhantomData<fn() -> T>);
+
+impl<T> Visitor<'_> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a string containing a `{}`", std::any::type_name::<T>())
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` for types with matching `Display` and `FromStr` impls.
+macro_rules! impl_serde_via_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.deserialize_str(FromStrVisitor(PhantomData))
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_via_str!(
+    AccountId,
+    ContractId,
+    DelegateContractId,
+    EntityId,
+    EvmAddress,
+    FileId,
+    LedgerId,
+    NftId,
+    PublicKey,
+    ScheduleId,
+    SemanticVersion,
+    TokenId,
+    TopicId,
+    TransactionId,
+);
+
+impl Serialize for Hbar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.to_tinybars())
+    }
+}
+
+impl<'de> Deserialize<'de> for Hbar {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i64::deserialize(deserializer).map(Self::from_tinybars)
+    }
+}
+
+/// Generates an `option` module next to a `serialize`/`deserialize` pair, for `Option<$ty>` fields.
+macro_rules! option {
+    ($ty:ty) => {
+        /// Like the parent module, for optional values.
+        pub(crate) mod option {
+            use serde::{
+                Deserialize,
+                Deserializer,
+                Serialize,
+                Serializer,
+            };
+
+            struct Ser<'a>(&'a $ty);
+
+            impl Serialize for Ser<'_> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    super::serialize(self.0, serializer)
+                }
+            }
+
+            struct De($ty);
+
+            impl<'de> Deserialize<'de> for De {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    super::deserialize(deserializer).map(Self)
+                }
+            }
+
+            pub(crate) fn serialize<S: Serializer>(
+                value: &Option<$ty>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                value.as_ref().map(Ser).serialize(serializer)
+            }
+
+            pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<$ty>, D::Error> {
+                Ok(Option::<De>::deserialize(deserializer)?.map(|it| it.0))
+            }
+        }
+    };
+}
+
+/// [`Status`](crate::Status) as its protobuf name, for instance `"SUCCESS"`.
+pub(crate) mod status {
+    use serde::de::Error as _;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    use crate::Status;
+
+    // `serde(with)` passes fields by reference.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn serialize<S: Serializer>(
+        status: &Status,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(status.as_str_name())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Status, D::Error> {
+        let name = String::deserialize(deserializer)?;
+
+        Status::from_str_name(&name)
+            .ok_or_else(|| D::Error::custom(format!("unknown status `{name}`")))
+    }
+}
+
+/// Timestamps as `"<seconds>.<nanos>"` strings, with exactly 9 digits of nanoseconds.
+pub(crate) mod timestamp {
+    use serde::de::Error as _;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+    use time::OffsetDateTime;
+
+    pub(crate) fn serialize<S: Serializer>(
+        timestamp: &OffsetDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!(
+            "{}.{:09}",
+            timestamp.unix_timestamp(),
+            timestamp.nanosecond()
+        ))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        parse(&s).ok_or_else(|| {
+            D::Error::custom(format!("expected a `<seconds>.<nanos>` timestamp, got `{s}`"))
+        })
+    }
+
+    fn parse(s: &str) -> Option<OffsetDateTime> {
+        let (seconds, nanos) = s.split_once('.')?;
+
+        if nanos.len() != 9 || !nanos.bytes().all(|it| it.is_ascii_digit()) {
+            return None;
+        }
+
+        let seconds: i64 = seconds.parse().ok()?;
+        let nanos: i64 = nanos.parse().ok()?;
+
+        OffsetDateTime::from_unix_timestamp(seconds)
+            .ok()?
+            .checked_add(time::Duration::nanoseconds(nanos))
+    }
+
+    option!(time::OffsetDateTime);
+}
+
+/// Durations as a whole number of seconds, the precision Hedera uses for them.
+pub(crate) mod duration {
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+    use time::Duration;
+
+    pub(crate) fn serialize<S: Serializer>(
+        duration: &Duration,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(duration.whole_seconds())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Duration, D::Error> {
+        i64::deserialize(deserializer).map(Duration::seconds)
+    }
+
+    option!(time::Duration);
+}
+
+/// Bytes as lowercase hex strings, without a `0x` prefix.
+pub(crate) mod bytes {
+    use serde::de::Error as _;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    pub(crate) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(bytes))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+
+        hex::decode(&s).map_err(D::Error::custom)
+    }
+
+    option!(Vec<u8>);
+
+    /// Like the parent module, for lists of byte strings.
+    pub(crate) mod vec {
+        use serde::{
+            Deserialize,
+            Deserializer,
+            Serializer,
+        };
+
+        pub(crate) fn serialize<S: Serializer>(
+            value: &[Vec<u8>],
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(value.iter().map(hex::encode))
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<Vec<u8>>, D::Error> {
+            struct De(Vec<u8>);
+
+            impl<'de> Deserialize<'de> for De {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    super::deserialize(deserializer).map(Self)
+                }
+            }
+
+            Ok(Vec::<De>::deserialize(deserializer)?.into_iter().map(|it| it.0).collect())
+        }
+    }
+}
@@ -0,0 +1,207 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt::Debug;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::transaction::test_helpers::TEST_TX_ID;
+use crate::transaction_receipt::make_receipt;
+use crate::transaction_record::make_record;
+use crate::{
+    AccountId,
+    AnyCustomFee,
+    ContractId,
+    FeeAssessmentMethod,
+    FractionalFee,
+    FractionalFeeData,
+    Hbar,
+    Key,
+    KeyList,
+    LedgerId,
+    NftId,
+    PrivateKey,
+    TokenId,
+    TransactionId,
+    TransactionReceipt,
+    TransactionRecord,
+};
+
+/// Asserts that `value` serializes to `expected`, and deserializes back to `value`.
+#[track_caller]
+fn assert_round_trip<T>(value: &T, expected: serde_json::Value)
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    assert_eq!(serde_json::to_value(value).unwrap(), expected);
+    assert_eq!(&serde_json::from_value::<T>(expected).unwrap(), value);
+}
+
+/// Round-trips a type without `PartialEq` by comparing its JSON before and after.
+#[track_caller]
+fn round_trip_json<T: Serialize + DeserializeOwned>(value: &T) -> serde_json::Value {
+    let json = serde_json::to_value(value).unwrap();
+    let value: T = serde_json::from_value(json.clone()).unwrap();
+
+    assert_eq!(serde_json::to_value(&value).unwrap(), json);
+
+    json
+}
+
+#[test]
+fn entity_ids_are_strings() {
+    assert_round_trip(&AccountId::new(0, 0, 1001), json!("0.0.1001"));
+    assert_round_trip(&TokenId::new(1, 2, 3), json!("1.2.3"));
+    assert_round_trip(&ContractId::new(0, 0, 5), json!("0.0.5"));
+    assert_round_trip(&NftId::from((TokenId::new(0, 0, 5), 7)), json!("0.0.5/7"));
+    assert_round_trip(&LedgerId::testnet(), json!("testnet"));
+}
+
+#[test]
+fn invalid_entity_id() {
+    let err = serde_json::from_value::<AccountId>(json!("0.0")).unwrap_err();
+    assert!(err.to_string().contains("expecting <shard>.<realm>.<num>"), "{err}");
+
+    serde_json::from_value::<AccountId>(json!(1001)).unwrap_err();
+}
+
+#[test]
+fn hbar_is_tinybars() {
+    assert_round_trip(&Hbar::new(2), json!(200_000_000));
+    assert_round_trip(&Hbar::from_tinybars(-5), json!(-5));
+}
+
+#[test]
+fn transaction_id() {
+    let transaction_id = TransactionId { nonce: Some(4), scheduled: true, ..TEST_TX_ID };
+
+    assert_round_trip(&transaction_id, json!("0.0.5006@1554158542.0?scheduled/4"));
+}
+
+#[test]
+fn key_list() {
+    let public_key = PrivateKey::from_str_ed25519(
+        "302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e10",
+    )
+    .unwrap()
+    .public_key();
+
+    let key = Key::KeyList(KeyList {
+        keys: Vec::from([Key::Single(public_key), Key::ContractId(ContractId::new(0, 0, 5))]),
+        threshold: Some(1),
+    });
+
+    assert_round_trip(
+        &key,
+        json!({
+            "keyList": {
+                "keys": [{ "single": public_key.to_string() }, { "contractId": "0.0.5" }],
+                "threshold": 1,
+            }
+        }),
+    );
+}
+
+#[test]
+fn custom_fee() {
+    let fee: AnyCustomFee = FractionalFee {
+        fee: FractionalFeeData {
+            denominator: 7,
+            numerator: 3,
+            minimum_amount: 3,
+            maximum_amount: 100,
+            assessment_method: FeeAssessmentMethod::Exclusive,
+        },
+        fee_collector_account_id: Some(AccountId::new(0, 0, 1001)),
+        all_collectors_are_exempt: false,
+    }
+    .into();
+
+    assert_round_trip(
+        &fee,
+        json!({
+            "fee": {
+                "fractional": {
+                    "denominator": 7,
+                    "numerator": 3,
+                    "minimumAmount": 3,
+                    "maximumAmount": 100,
+                    "assessmentMethod": "EXCLUSIVE",
+                }
+            },
+            "feeCollectorAccountId": "0.0.1001",
+            "allCollectorsAreExempt": false,
+        }),
+    );
+}
+
+#[test]
+fn transaction_receipt() {
+    let json = round_trip_json::<TransactionReceipt>(&make_receipt());
+
+    assert_eq!(json["status"], "SCHEDULE_ALREADY_DELETED");
+    assert_eq!(json["accountId"], "1.2.3");
+    assert_eq!(json["topicRunningHash"], hex::encode("how now brown cow"));
+    assert_eq!(json["exchangeRates"]["currentRate"]["expirationTime"], "1554158542.000000000");
+    assert_eq!(json["transactionId"], serde_json::Value::Null);
+}
+
+#[test]
+fn transaction_record() {
+    let json = round_trip_json::<TransactionRecord>(&make_record(Some(vec![1, 2, 3]), None));
+
+    assert_eq!(json["receipt"]["status"], "SCHEDULE_ALREADY_DELETED");
+    assert_eq!(json["transactionFee"], 3000);
+    assert_eq!(json["transfers"], json!([{ "accountId": "4.4.4", "amount": 500_000_000 }]));
+    assert_eq!(json["tokenTransfers"], json!({ "6.6.6": { "1.1.1": 4 } }));
+    assert_eq!(json["prngBytes"], "010203");
+}
+
+#[test]
+fn missing_optional_fields() {
+    let mut json = serde_json::to_value(make_receipt()).unwrap();
+    json.as_object_mut().unwrap().remove("topicRunningHash");
+
+    let receipt: TransactionReceipt = serde_json::from_value(json).unwrap();
+
+    assert_eq!(receipt.topic_running_hash, None);
+}
+
+#[test]
+fn invalid_timestamp() {
+    let mut json = serde_json::to_value(make_receipt()).unwrap();
+    json["exchangeRates"]["currentRate"]["expirationTime"] = json!("1554158542.5");
+
+    let err = serde_json::from_value::<TransactionReceipt>(json).unwrap_err();
+
+    assert!(err.to_string().contains("expected a `<seconds>.<nanos>` timestamp"), "{err}");
+}
+
+#[test]
+fn unknown_status() {
+    let mut json = serde_json::to_value(make_receipt()).unwrap();
+    json["status"] = json!("NOT_A_STATUS");
+
+    let err = serde_json::from_value::<TransactionReceipt>(json).unwrap_err();
+
+    assert!(err.to_string().contains("unknown status `NOT_A_STATUS`"), "{err}");
+}
@@ -19,7 +19,10 @@
  */
 
 use std::fmt;
+use std::future::Future;
 
+use futures_core::future::BoxFuture;
+use futures_util::FutureExt;
 use triomphe::Arc;
 use unsize::{
     CoerceUnsize,
@@ -27,10 +30,63 @@ use unsize::{
 };
 
 use crate::{
+    Error,
     PrivateKey,
     PublicKey,
 };
 
+/// Something that can sign transactions on behalf of a [`PublicKey`].
+///
+/// Unlike the closures accepted by [`Transaction::sign_with`](crate::Transaction::sign_with),
+/// signing is asynchronous, which allows keys that live in an HSM or a remote KMS to be used
+/// without blocking the executor.
+///
+/// Signatures are requested while a transaction is being executed, so a signer may be asked
+/// to sign several messages (one per node, per chunk, and again if the transaction ID gets regenerated).
+///
+/// # Examples
+///
+/// ```
+/// use futures_core::future::BoxFuture;
+/// use hedera::{PrivateKey, PublicKey, Signer};
+///
+/// struct RemoteKey(PrivateKey);
+///
+/// impl Signer for RemoteKey {
+///     fn public_key(&self) -> PublicKey {
+///         self.0.public_key()
+///     }
+///
+///     fn sign<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, hedera::Result<Vec<u8>>> {
+///         // a real implementation would make a network request here.
+///         Box::pin(async move { Ok(self.0.sign(message)) })
+///     }
+/// }
+/// ```
+pub trait Signer: Send + Sync {
+    /// Returns the public key that signatures from this signer can be verified with.
+    ///
+    /// This is called frequently (and before any signing happens), so it should be cheap.
+    fn public_key(&self) -> PublicKey;
+
+    /// Signs `message`, returning the raw signature bytes.
+    ///
+    /// # Errors
+    /// - Any error from the underlying signer, conventionally [`Error::Signature`].
+    ///   Failing to sign fails the request being executed.
+    fn sign<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, crate::Result<Vec<u8>>>;
+}
+
+impl Signer for PrivateKey {
+    fn public_key(&self) -> PublicKey {
+        PrivateKey::public_key(self)
+    }
+
+    fn sign<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, crate::Result<Vec<u8>>> {
+        Box::pin(std::future::ready(Ok(PrivateKey::sign(self, message))))
+    }
+}
+
 #[derive(Clone)]
 pub(crate) enum AnySigner {
     PrivateKey(PrivateKey),
@@ -46,6 +102,7 @@ pub(crate) enum AnySigner {
     // but we can't do that because trait aliases don't exist.
     #[allow(clippy::type_complexity)]
     Arbitrary(Box<PublicKey>, Arc<dyn Fn(&[u8]) -> Vec<u8> + Send + Sync>),
+    Signer(Arc<dyn Signer>),
 }
 
 impl AnySigner {
@@ -58,6 +115,10 @@ impl AnySigner {
             Arc::new(signer).unsize(Coercion!(to dyn Fn(&[u8]) -> Vec<u8> + Send + Sync)),
         )
     }
+
+    pub(crate) fn signer<S: Signer + 'static>(signer: S) -> Self {
+        Self::Signer(Arc::new(signer).unsize(Coercion!(to dyn Signer)))
+    }
 }
 
 impl fmt::Debug for AnySigner {
@@ -67,6 +128,7 @@ impl fmt::Debug for AnySigner {
             Self::Arbitrary(arg0, _) => {
                 f.debug_tuple("Arbitrary").field(arg0).field(&"Fn").finish()
             }
+            Self::Signer(it) => f.debug_tuple("Signer").field(&it.public_key()).finish(),
         }
     }
 }
@@ -77,17 +139,35 @@ impl AnySigner {
         match self {
             AnySigner::PrivateKey(it) => it.public_key(),
             AnySigner::Arbitrary(it, _) => **it,
+            AnySigner::Signer(it) => it.public_key(),
         }
     }
 
-    pub(crate) fn sign(&self, message: &[u8]) -> (PublicKey, Vec<u8>) {
+    pub(crate) async fn sign(&self, message: &[u8]) -> crate::Result<(PublicKey, Vec<u8>)> {
         match self {
-            AnySigner::PrivateKey(it) => (it.public_key(), it.sign(message)),
+            AnySigner::PrivateKey(it) => Ok((it.public_key(), it.sign(message))),
             AnySigner::Arbitrary(public, signer) => {
                 let bytes = signer(message);
 
-                (**public, bytes)
+                Ok((**public, bytes))
+            }
+            AnySigner::Signer(signer) => {
+                let bytes = signer.sign(message).await?;
+
+                Ok((signer.public_key(), bytes))
             }
         }
     }
 }
+
+/// Resolves a signing future in a synchronous context.
+///
+/// Private keys and closure based signers always complete immediately,
+/// a [`Signer`] that actually needs to wait can only be used while executing.
+pub(crate) fn sign_now<T>(future: impl Future<Output = crate::Result<T>>) -> crate::Result<T> {
+    future.now_or_never().unwrap_or_else(|| {
+        Err(Error::signature(
+            "signer did not complete synchronously; asynchronous signers are only supported when executing",
+        ))
+    })
+}
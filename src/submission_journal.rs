@@ -0,0 +1,635 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use std::{
+    fs,
+    io,
+};
+
+use hedera_proto::services;
+use parking_lot::Mutex;
+use prost::Message;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+use triomphe::Arc;
+
+use crate::transaction::DEFAULT_TRANSACTION_VALID_DURATION;
+use crate::{
+    AccountId,
+    AnyTransaction,
+    Client,
+    Error,
+    FromProtobuf,
+    TransactionHash,
+    TransactionId,
+    TransactionReceipt,
+    TransactionReceiptQuery,
+};
+
+/// Durable storage for transactions that have been sent to a node, but whose outcome isn't known yet.
+///
+/// A journal is set with [`Client::set_submission_journal`], after which every transaction the client
+/// submits is [`record`](Self::record)ed right before it's sent to a node.
+/// The entry is removed once the client gets a receipt or record for the transaction,
+/// or once a node rejected it at pre-check.
+///
+/// The methods are called on tokio's blocking thread pool, so they're free to do blocking IO.
+///
+/// If the process dies in between, [`Client::recover_submissions`] uses the entries that are
+/// left over to find out what happened to each transaction.
+///
+/// [`FileJournal`] and [`MemoryJournal`] are provided, anything else (such as a database)
+/// can be used by implementing this trait.
+pub trait SubmissionJournal: Send + Sync {
+    /// Durably stores `entry`, replacing any entry with the same transaction ID.
+    ///
+    /// If this fails, the transaction isn't sent.
+    ///
+    /// # Errors
+    /// - If the entry couldn't be stored.
+    fn record(&self, entry: &JournalEntry) -> io::Result<()>;
+
+    /// Removes the entry for `transaction_id`, if there is one.
+    ///
+    /// # Errors
+    /// - If the entry couldn't be removed.
+    fn remove(&self, transaction_id: &TransactionId) -> io::Result<()>;
+
+    /// Returns every entry in the journal.
+    ///
+    /// # Errors
+    /// - If the entries couldn't be read.
+    fn entries(&self) -> io::Result<Vec<JournalEntry>>;
+}
+
+/// A transaction that was sent to a node, as stored in a [`SubmissionJournal`].
+///
+/// Everything here can be recovered from [`transaction_bytes`](Self::transaction_bytes)
+/// with [`from_bytes`](Self::from_bytes), so a journal only needs to store those.
+#[derive(Debug, Clone)]
+pub struct JournalEntry {
+    /// The ID of the transaction.
+    pub transaction_id: TransactionId,
+
+    /// The node the transaction was sent to.
+    pub node_account_id: AccountId,
+
+    /// The hash of the transaction, as in its [`TransactionResponse`](crate::TransactionResponse).
+    pub transaction_hash: TransactionHash,
+
+    /// How long after its valid start the transaction can reach consensus.
+    pub valid_duration: Duration,
+
+    /// The protobuf encoded, signed, `Transaction` that was sent to the node.
+    pub transaction_bytes: Vec<u8>,
+}
+
+impl JournalEntry {
+    /// Parses an entry from the bytes of a signed transaction, as in [`transaction_bytes`](Self::transaction_bytes).
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if the bytes aren't a signed transaction for a single node.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let transaction = services::Transaction::decode(bytes).map_err(Error::from_protobuf)?;
+
+        Self::from_transaction(&transaction, bytes.to_vec())
+    }
+
+    fn from_transaction(
+        transaction: &services::Transaction,
+        transaction_bytes: Vec<u8>,
+    ) -> crate::Result<Self> {
+        if transaction.signed_transaction_bytes.is_empty() {
+            return Err(Error::from_protobuf("transaction has no signed transaction bytes"));
+        }
+
+        let signed = services::SignedTransaction::decode(&*transaction.signed_transaction_bytes)
+            .map_err(Error::from_protobuf)?;
+
+        let body =
+            services::TransactionBody::decode(&*signed.body_bytes).map_err(Error::from_protobuf)?;
+
+        Ok(Self {
+            transaction_id: TransactionId::from_protobuf(pb_getf!(body, transaction_id)?)?,
+            node_account_id: AccountId::from_protobuf(pb_getf!(body, node_account_id)?)?,
+            transaction_hash: TransactionHash::new(&transaction.signed_transaction_bytes),
+            valid_duration: body
+                .transaction_valid_duration
+                .map_or(DEFAULT_TRANSACTION_VALID_DURATION, Into::into),
+            transaction_bytes,
+        })
+    }
+
+    /// Returns the time after which the transaction can no longer reach consensus.
+    #[must_use]
+    pub fn expires_at(&self) -> OffsetDateTime {
+        self.transaction_id.valid_start + self.valid_duration
+    }
+}
+
+/// A [`SubmissionJournal`] that keeps its entries in memory.
+///
+/// This doesn't survive the process exiting, but it does survive the [`Client`] being dropped,
+/// clones share their entries.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryJournal(Arc<Mutex<HashMap<TransactionId, JournalEntry>>>);
+
+impl MemoryJournal {
+    /// Create a new, empty, `MemoryJournal`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl SubmissionJournal for MemoryJournal {
+    fn record(&self, entry: &JournalEntry) -> io::Result<()> {
+        self.0.lock().insert(entry.transaction_id, entry.clone());
+        Ok(())
+    }
+
+    fn remove(&self, transaction_id: &TransactionId) -> io::Result<()> {
+        self.0.lock().remove(transaction_id);
+        Ok(())
+    }
+
+    fn entries(&self) -> io::Result<Vec<JournalEntry>> {
+        Ok(self.0.lock().values().cloned().collect())
+    }
+}
+
+/// A [`SubmissionJournal`] that keeps each entry in its own file in a directory.
+///
+/// Entries are written to a temporary file that's synced to disk and then renamed into place,
+/// so an entry is either completely recorded or not at all.
+#[derive(Debug, Clone)]
+pub struct FileJournal {
+    dir: PathBuf,
+}
+
+impl FileJournal {
+    const EXTENSION: &'static str = "entry";
+
+    /// Opens the journal in `dir`, creating the directory if it doesn't exist.
+    ///
+    /// # Errors
+    /// - If the directory couldn't be created.
+    pub fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self { dir })
+    }
+
+    /// Returns the directory the entries are kept in.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, transaction_id: &TransactionId) -> PathBuf {
+        // `?` and `/` (from scheduled and nonce'd transaction IDs) aren't safe in file names.
+        let name = transaction_id.to_string().replace('?', "+").replace('/', "-");
+
+        self.dir.join(format!("{name}.{}", Self::EXTENSION))
+    }
+}
+
+impl SubmissionJournal for FileJournal {
+    fn record(&self, entry: &JournalEntry) -> io::Result<()> {
+        let path = self.path(&entry.transaction_id);
+        let tmp = path.with_extension("tmp");
+
+        {
+            let mut file = fs::File::create(&tmp)?;
+            io::Write::write_all(&mut file, &entry.transaction_bytes)?;
+            file.sync_all()?;
+        }
+
+        fs::rename(&tmp, &path)?;
+
+        // the rename itself only becomes durable once the directory is synced.
+        #[cfg(unix)]
+        fs::File::open(&self.dir)?.sync_all()?;
+
+        Ok(())
+    }
+
+    fn remove(&self, transaction_id: &TransactionId) -> io::Result<()> {
+        match fs::remove_file(self.path(transaction_id)) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            res => res,
+        }
+    }
+
+    fn entries(&self) -> io::Result<Vec<JournalEntry>> {
+        let mut entries = Vec::new();
+
+        for it in fs::read_dir(&self.dir)? {
+            let path = it?.path();
+
+            // leftover temporary files are from entries that were never recorded, so never sent.
+            if path.extension().is_none_or(|it| it != Self::EXTENSION) {
+                continue;
+            }
+
+            let entry = JournalEntry::from_bytes(&fs::read(&path)?)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            entries.push(entry);
+        }
+
+        Ok(entries)
+    }
+}
+
+/// What [`Client::recover_submissions`] found out about a [`JournalEntry`].
+#[derive(Debug)]
+pub struct RecoveredSubmission {
+    /// The entry that was recovered.
+    pub entry: JournalEntry,
+
+    /// Whether the transaction was sent to its node again, because it was still within its valid duration.
+    pub resubmitted: bool,
+
+    /// The receipt of the transaction, whatever its status.
+    ///
+    /// When this is an error the entry is left in the journal, for instance when a transaction
+    /// expired long enough ago that the network no longer has its receipt.
+    /// Such entries need to be resolved some other way (such as with a mirror node),
+    /// and then removed with [`SubmissionJournal::remove`].
+    pub receipt: crate::Result<TransactionReceipt>,
+}
+
+/// The journal set on a client.
+pub(crate) type Journal = Arc<dyn SubmissionJournal>;
+
+// journals are free to do blocking IO, which mustn't happen on the async workers.
+async fn blocking<T, F>(journal: &Journal, f: F) -> io::Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&dyn SubmissionJournal) -> io::Result<T> + Send + 'static,
+{
+    let journal = Arc::clone(journal);
+
+    tokio::task::spawn_blocking(move || f(&*journal)).await.map_err(io::Error::other)?
+}
+
+pub(crate) async fn remove_entry(journal: &Journal, transaction_id: &TransactionId) {
+    let id = *transaction_id;
+
+    // a leftover entry is harmless: recovering it will just find the receipt.
+    if let Err(e) = blocking(journal, move |journal| journal.remove(&id)).await {
+        log::warn!("Failed to remove {transaction_id} from the submission journal: {e}");
+    }
+}
+
+pub(crate) async fn record_entry(
+    journal: &Journal,
+    transaction: &services::Transaction,
+) -> crate::Result<TransactionId> {
+    let entry = JournalEntry::from_transaction(transaction, transaction.encode_to_vec())?;
+    let transaction_id = entry.transaction_id;
+
+    blocking(journal, move |journal| journal.record(&entry)).await.map_err(Error::Journal)?;
+
+    Ok(transaction_id)
+}
+
+/// Removes the entry for `transaction_id` if `res` is the outcome of a receipt or record query for it.
+pub(crate) async fn complete_entry<T>(
+    client: &Client,
+    transaction_id: &TransactionId,
+    res: &crate::Result<T>,
+) {
+    if !matches!(res, Ok(_) | Err(Error::ReceiptStatus { .. } | Error::ContractReverted { .. })) {
+        return;
+    }
+
+    if let Some(journal) = client.submission_journal() {
+        remove_entry(&journal, transaction_id).await;
+    }
+}
+
+pub(crate) async fn recover(
+    client: &Client,
+    journal: &Journal,
+) -> crate::Result<Vec<RecoveredSubmission>> {
+    let mut entries =
+        blocking(journal, |journal| journal.entries()).await.map_err(Error::Journal)?;
+
+    entries.sort_by_key(|it| it.transaction_id.valid_start);
+
+    let recovered = entries.into_iter().map(|entry| recover_entry(client, journal, entry));
+
+    Ok(futures_util::future::join_all(recovered).await)
+}
+
+async fn recover_entry(
+    client: &Client,
+    journal: &Journal,
+    entry: JournalEntry,
+) -> RecoveredSubmission {
+    let resubmitted = OffsetDateTime::now_utc() < entry.expires_at();
+
+    let resubmission = match resubmitted {
+        true => resubmit(client, journal, &entry).await,
+        false => Ok(()),
+    };
+
+    // even if resubmitting failed, the original submission might have gone through.
+    // getting the receipt removes the entry.
+    let receipt = TransactionReceiptQuery::new()
+        .transaction_id(entry.transaction_id)
+        .validate_status(false)
+        .execute(client)
+        .await;
+
+    let receipt = match (receipt, resubmission) {
+        (Ok(receipt), _) => Ok(receipt),
+
+        // why resubmitting failed says more than the receipt being missing does.
+        (Err(_), Err(e)) | (Err(e), Ok(())) => Err(e),
+    };
+
+    RecoveredSubmission { entry, resubmitted, receipt }
+}
+
+async fn resubmit(client: &Client, journal: &Journal, entry: &JournalEntry) -> crate::Result<()> {
+    let mut transaction = AnyTransaction::from_bytes(&entry.transaction_bytes)?;
+
+    // the node might already have it, in which case the receipt will tell us the rest.
+    let res = transaction.idempotent(true).execute(client).await.map(|_| ());
+
+    // being rejected now removes the entry, but that says nothing about the original submission.
+    if res.is_err() {
+        let restored = entry.clone();
+
+        if let Err(e) = blocking(journal, move |journal| journal.record(&restored)).await {
+            log::warn!("Failed to restore {} in the submission journal: {e}", entry.transaction_id);
+        }
+    }
+
+    res
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::{
+        FileJournal,
+        JournalEntry,
+        MemoryJournal,
+        SubmissionJournal,
+    };
+    use crate::testing::{
+        MockNetwork,
+        MockResponse,
+    };
+    use crate::{
+        AccountId,
+        Client,
+        FileAppendTransaction,
+        FileId,
+        Hbar,
+        PrivateKey,
+        Status,
+        TransactionId,
+        TransactionReceiptQuery,
+        TransferTransaction,
+    };
+
+    const TRANSFER: &str = "/proto.CryptoService/cryptoTransfer";
+
+    async fn client(network: &MockNetwork, journal: &MemoryJournal) -> Client {
+        let client = network.client().unwrap();
+        client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+        client.set_submission_journal(journal.clone());
+
+        for node in network.nodes() {
+            client.ping(node.account_id()).await.unwrap();
+        }
+
+        client
+    }
+
+    fn transfer() -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1))
+            .node_account_ids([AccountId::new(0, 0, 3)]);
+
+        tx
+    }
+
+    #[tokio::test]
+    async fn recorded_until_receipt() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let journal = MemoryJournal::new();
+        let client = client(&network, &journal).await;
+
+        let response = transfer().execute(&client).await.unwrap();
+
+        let entries = journal.entries().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].transaction_id, response.transaction_id);
+        assert_eq!(entries[0].node_account_id, response.node_account_id);
+        assert_eq!(entries[0].transaction_hash.0, response.transaction_hash.0);
+        assert_eq!(entries[0].valid_duration, Duration::seconds(120));
+
+        response.get_receipt(&client).await.unwrap();
+
+        assert!(journal.entries().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn receipt_query_removes() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let journal = MemoryJournal::new();
+        let client = client(&network, &journal).await;
+
+        let response = transfer().execute(&client).await.unwrap();
+        assert_eq!(journal.entries().unwrap().len(), 1);
+
+        TransactionReceiptQuery::new()
+            .transaction_id(response.transaction_id)
+            .execute(&client)
+            .await
+            .unwrap();
+
+        assert!(journal.entries().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn chunks_removed_by_execute_all() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let journal = MemoryJournal::new();
+        let client = client(&network, &journal).await;
+
+        let mut tx = FileAppendTransaction::new();
+        tx.file_id(FileId::new(0, 0, 1001))
+            .contents(vec![0; 25])
+            .chunk_size(10)
+            .node_account_ids([AccountId::new(0, 0, 3)]);
+
+        // every chunk waits for its receipt before the next one is sent.
+        let responses = tx.execute_all(&client).await.unwrap();
+
+        assert_eq!(responses.len(), 3);
+        assert!(journal.entries().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejected_is_removed() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let journal = MemoryJournal::new();
+        let client = client(&network, &journal).await;
+
+        network.nodes()[0].push_response(MockResponse::Precheck(Status::InsufficientPayerBalance));
+
+        transfer().execute(&client).await.unwrap_err();
+
+        assert!(journal.entries().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn rejected_after_unknown_delivery_is_kept() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let journal = MemoryJournal::new();
+        let client = client(&network, &journal).await;
+
+        network.nodes()[0]
+            .push_response(MockResponse::GrpcStatus(tonic::Status::unavailable("gone")))
+            .push_response(MockResponse::Precheck(Status::InsufficientPayerBalance));
+
+        transfer().execute(&client).await.unwrap_err();
+
+        assert_eq!(journal.entries().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn recover_resubmits_valid() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let journal = MemoryJournal::new();
+
+        let response = transfer().execute(&client(&network, &journal).await).await.unwrap();
+
+        // "restart"
+        let client = client(&network, &journal).await;
+
+        network.nodes()[0].push_response(MockResponse::Precheck(Status::DuplicateTransaction));
+
+        let recovered = client.recover_submissions().await.unwrap();
+
+        assert_eq!(recovered.len(), 1);
+        assert_eq!(recovered[0].entry.transaction_id, response.transaction_id);
+        assert!(recovered[0].resubmitted);
+        assert_eq!(recovered[0].receipt.as_ref().unwrap().status, Status::Success);
+
+        assert_eq!(network.nodes()[0].requests().iter().filter(|it| *it == TRANSFER).count(), 2);
+        assert!(journal.entries().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn recover_queries_expired() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let journal = MemoryJournal::new();
+        let client = client(&network, &journal).await;
+
+        let mut tx = transfer();
+        tx.transaction_id(TransactionId {
+            account_id: AccountId::new(0, 0, 2),
+            valid_start: OffsetDateTime::now_utc() - Duration::hours(1),
+            nonce: None,
+            scheduled: false,
+        });
+
+        tx.execute(&client).await.unwrap();
+
+        let recovered = client.recover_submissions().await.unwrap();
+
+        assert_eq!(recovered.len(), 1);
+        assert!(!recovered[0].resubmitted);
+        assert_eq!(recovered[0].receipt.as_ref().unwrap().status, Status::Success);
+
+        assert_eq!(network.nodes()[0].requests().iter().filter(|it| *it == TRANSFER).count(), 1);
+        assert!(journal.entries().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn file_journal() {
+        let network = MockNetwork::start(1).await.unwrap();
+        let memory = MemoryJournal::new();
+        let client = client(&network, &memory).await;
+
+        let mut tx = transfer();
+        tx.transaction_id(TransactionId {
+            scheduled: true,
+            nonce: Some(3),
+            ..TransactionId::generate(AccountId::new(0, 0, 2))
+        });
+
+        tx.execute(&client).await.unwrap();
+
+        let entry = memory.entries().unwrap().remove(0);
+
+        let dir = std::env::temp_dir().join(format!(
+            "hedera-file-journal-{}",
+            entry.transaction_id.valid_start.unix_timestamp_nanos()
+        ));
+
+        let journal = FileJournal::open(&dir).unwrap();
+
+        journal.record(&entry).unwrap();
+        // recording twice replaces the entry.
+        journal.record(&entry).unwrap();
+
+        // reopening sees the same entries.
+        let entries = FileJournal::open(&dir).unwrap().entries().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].transaction_id, entry.transaction_id);
+        assert_eq!(entries[0].transaction_bytes, entry.transaction_bytes);
+
+        journal.remove(&entry.transaction_id).unwrap();
+        journal.remove(&entry.transaction_id).unwrap();
+
+        assert!(journal.entries().unwrap().is_empty());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn from_bytes_invalid() {
+        assert!(JournalEntry::from_bytes(b"\xff\xff").is_err());
+        assert!(JournalEntry::from_bytes(&[]).is_err());
+    }
+}
@@ -0,0 +1,242 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! In-process mock consensus nodes, for testing code that talks to the network without a network.
+//!
+//! A [`MockNetwork`] runs a gRPC server per node on localhost that serves every consensus node service.
+//! Each node answers requests from a script of [`MockResponse`]s, which makes it possible to test
+//! how requests are retried, how errors are surfaced, and so on.
+//!
+//! When a node's script is empty it behaves like a healthy node:
+//! transactions pass pre-check, receipts and records are `SUCCESS`, cost queries are free,
+//! and any other query fails pre-check with `NOT_SUPPORTED`.
+//! Pings sent by the SDK are always answered successfully and never consume the script.
+//!
+//! # Examples
+//! ```
+//! # #[tokio::main]
+//! # async fn main() -> anyhow::Result<()> {
+//! use hedera::testing::{MockNetwork, MockResponse};
+//! use hedera::{AccountId, Hbar, PrivateKey, Status, TransferTransaction};
+//!
+//! let network = MockNetwork::start(1).await?;
+//! let client = network.client()?;
+//! client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+//!
+//! // the node is busy the first time it's asked, the SDK retries.
+//! network.nodes()[0].push_response(MockResponse::Precheck(Status::Busy));
+//!
+//! TransferTransaction::new()
+//!     .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+//!     .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1))
+//!     .execute(&client)
+//!     .await?;
+//!
+//! assert_eq!(network.nodes()[0].requests().len(), 2);
+//! # Ok(())
+//! # }
+//! ```
+
+mod service;
+#[cfg(test)]
+mod tests;
+
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+use std::net::SocketAddr;
+
+use parking_lot::Mutex;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+use tonic::transport::Server;
+use triomphe::Arc;
+
+use self::service::MockService;
+use crate::{
+    AccountId,
+    Client,
+    TransactionReceipt,
+    TransactionRecord,
+};
+
+/// A scripted response from a [`MockNode`].
+#[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
+pub enum MockResponse {
+    /// Fail pre-check with the given status (`OK` passes pre-check).
+    ///
+    /// Applies to both transactions and queries.
+    Precheck(crate::Status),
+
+    /// Fail the gRPC call itself, for instance with [`tonic::Code::Unavailable`].
+    GrpcStatus(tonic::Status),
+
+    /// Answer a [`TransactionReceiptQuery`](crate::TransactionReceiptQuery) with the given receipt.
+    Receipt(TransactionReceipt),
+
+    /// Answer a [`TransactionRecordQuery`](crate::TransactionRecordQuery) with the given record.
+    Record(TransactionRecord),
+}
+
+/// A set of [`MockNode`]s that a [`Client`] can be pointed at.
+pub struct MockNetwork {
+    nodes: Vec<MockNode>,
+}
+
+impl MockNetwork {
+    /// Start `node_count` nodes, with the account IDs `0.0.3`, `0.0.4`, and so on.
+    ///
+    /// The nodes are shut down when the network is dropped.
+    ///
+    /// # Errors
+    /// - If binding a node's socket fails.
+    pub async fn start(node_count: usize) -> std::io::Result<Self> {
+        let mut nodes = Vec::with_capacity(node_count);
+
+        for num in 3..(3 + node_count as u64) {
+            nodes.push(MockNode::start(AccountId::new(0, 0, num)).await?);
+        }
+
+        Ok(Self { nodes })
+    }
+
+    /// Returns the nodes in this network.
+    #[must_use]
+    pub fn nodes(&self) -> &[MockNode] {
+        &self.nodes
+    }
+
+    /// Returns the node with the given account ID, if there is one.
+    #[must_use]
+    pub fn node(&self, account_id: AccountId) -> Option<&MockNode> {
+        self.nodes.iter().find(|it| it.account_id == account_id)
+    }
+
+    /// Returns the network in the form expected by [`Client::for_network`].
+    #[must_use]
+    pub fn network(&self) -> HashMap<String, AccountId> {
+        self.nodes.iter().map(|it| (it.address.to_string(), it.account_id)).collect()
+    }
+
+    /// Create a client for this network.
+    ///
+    /// # Errors
+    /// - See [`Client::for_network`].
+    pub fn client(&self) -> crate::Result<Client> {
+        Client::for_network(self.network())
+    }
+}
+
+/// A single mock consensus node, see the [module documentation](self) for details.
+pub struct MockNode {
+    account_id: AccountId,
+    address: SocketAddr,
+    state: Arc<Mutex<NodeState>>,
+    // dropping the sender shuts the server down.
+    _shutdown: oneshot::Sender<()>,
+}
+
+impl MockNode {
+    /// Start a node with the given account ID on an unused localhost port.
+    ///
+    /// The node is shut down when it's dropped.
+    ///
+    /// # Errors
+    /// - If binding the node's socket fails.
+    pub async fn start(account_id: AccountId) -> std::io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let address = listener.local_addr()?;
+
+        let state = Arc::new(Mutex::new(NodeState {
+            account_id,
+            responses: VecDeque::new(),
+            requests: Vec::new(),
+        }));
+        let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+
+        let incoming = async_stream::stream! {
+            loop {
+                yield listener.accept().await.map(|(stream, _)| stream);
+            }
+        };
+
+        let router = Server::builder()
+            .add_service(MockService::<service::AddressBookService>::new(state.clone()))
+            .add_service(MockService::<service::ConsensusService>::new(state.clone()))
+            .add_service(MockService::<service::CryptoService>::new(state.clone()))
+            .add_service(MockService::<service::FileService>::new(state.clone()))
+            .add_service(MockService::<service::FreezeService>::new(state.clone()))
+            .add_service(MockService::<service::NetworkService>::new(state.clone()))
+            .add_service(MockService::<service::ScheduleService>::new(state.clone()))
+            .add_service(MockService::<service::SmartContractService>::new(state.clone()))
+            .add_service(MockService::<service::TokenService>::new(state.clone()))
+            .add_service(MockService::<service::UtilService>::new(state.clone()));
+
+        tokio::spawn(async move {
+            let shutdown = async {
+                // an error just means the sender was dropped, which is the signal.
+                let _ = shutdown_rx.await;
+            };
+
+            if let Err(e) = router.serve_with_incoming_shutdown(incoming, shutdown).await {
+                log::error!("mock node at {address} failed: {e}");
+            }
+        });
+
+        Ok(Self { account_id, address, state, _shutdown: shutdown })
+    }
+
+    /// Returns the account ID of this node.
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the address this node is listening on.
+    #[must_use]
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Add a response to the end of this node's script.
+    ///
+    /// Responses are used in order, one per request (cost queries excluded).
+    pub fn push_response(&self, response: MockResponse) -> &Self {
+        self.state.lock().responses.push_back(response);
+        self
+    }
+
+    /// Returns the gRPC paths of the requests this node has received, in order,
+    /// for instance `/proto.CryptoService/cryptoTransfer`.
+    ///
+    /// Pings (queries for the balance of the node's own account) aren't included.
+    #[must_use]
+    pub fn requests(&self) -> Vec<String> {
+        self.state.lock().requests.clone()
+    }
+}
+
+struct NodeState {
+    account_id: AccountId,
+    responses: VecDeque<MockResponse>,
+    requests: Vec<String>,
+}
@@ -0,0 +1,364 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::convert::Infallible;
+use std::future::{
+    ready,
+    Ready,
+};
+use std::marker::PhantomData;
+use std::task::{
+    Context,
+    Poll,
+};
+
+use hedera_proto::services;
+use hedera_proto::services::crypto_get_account_balance_query::BalanceSource;
+use parking_lot::Mutex;
+use time::OffsetDateTime;
+use tonic::body::BoxBody;
+use tonic::codec::ProstCodec;
+use tonic::codegen::{
+    empty_body,
+    http,
+    Body,
+    BoxFuture,
+    Service,
+    StdError,
+};
+use tonic::server::{
+    Grpc,
+    NamedService,
+    UnaryService,
+};
+use triomphe::Arc;
+
+use super::{
+    MockResponse,
+    NodeState,
+};
+use crate::protobuf::ToProtobuf;
+use crate::Status;
+
+/// A gRPC service name, the service itself is the same for all of them.
+pub(super) trait ServiceName: Send + Sync + 'static {
+    const NAME: &'static str;
+}
+
+macro_rules! service_names {
+    ($($ident:ident => $name:literal),* $(,)?) => {
+        $(
+            pub(super) enum $ident {}
+
+            impl ServiceName for $ident {
+                const NAME: &'static str = $name;
+            }
+        )*
+    };
+}
+
+service_names! {
+    AddressBookService => "proto.AddressBookService",
+    ConsensusService => "proto.ConsensusService",
+    CryptoService => "proto.CryptoService",
+    FileService => "proto.FileService",
+    FreezeService => "proto.FreezeService",
+    NetworkService => "proto.NetworkService",
+    ScheduleService => "proto.ScheduleService",
+    SmartContractService => "proto.SmartContractService",
+    TokenService => "proto.TokenService",
+    UtilService => "proto.UtilService",
+}
+
+/// Serves every method of the service `S` from a node's script.
+pub(super) struct MockService<S> {
+    state: Arc<Mutex<NodeState>>,
+    _service: PhantomData<fn() -> S>,
+}
+
+impl<S> MockService<S> {
+    pub(super) fn new(state: Arc<Mutex<NodeState>>) -> Self {
+        Self { state, _service: PhantomData }
+    }
+}
+
+// a derive would require `S: Clone`.
+impl<S> Clone for MockService<S> {
+    fn clone(&self) -> Self {
+        Self::new(self.state.clone())
+    }
+}
+
+impl<S: ServiceName> NamedService for MockService<S> {
+    const NAME: &'static str = S::NAME;
+}
+
+impl<S, B> Service<http::Request<B>> for MockService<S>
+where
+    S: ServiceName,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let path = request.uri().path().to_owned();
+        let state = self.state.clone();
+
+        let Some(method) = path.strip_prefix(&format!("/{}/", S::NAME)) else {
+            return Box::pin(async { Ok(unimplemented()) });
+        };
+
+        // the name of every query method contains `get`, except for `contractCallLocalMethod`.
+        let is_query =
+            method.to_ascii_lowercase().contains("get") || method == "contractCallLocalMethod";
+
+        Box::pin(async move {
+            let response = if is_query {
+                Grpc::new(ProstCodec::default()).unary(QueryHandler { state, path }, request).await
+            } else {
+                Grpc::new(ProstCodec::default())
+                    .unary(TransactionHandler { state, path }, request)
+                    .await
+            };
+
+            Ok(response)
+        })
+    }
+}
+
+fn unimplemented() -> http::Response<BoxBody> {
+    http::Response::builder()
+        .status(200)
+        .header("grpc-status", (tonic::Code::Unimplemented as i32).to_string())
+        .header("content-type", "application/grpc")
+        .body(empty_body())
+        .unwrap()
+}
+
+struct TransactionHandler {
+    state: Arc<Mutex<NodeState>>,
+    path: String,
+}
+
+impl UnaryService<services::Transaction> for TransactionHandler {
+    type Response = services::TransactionResponse;
+    type Future = Ready<Result<tonic::Response<Self::Response>, tonic::Status>>;
+
+    fn call(&mut self, _request: tonic::Request<services::Transaction>) -> Self::Future {
+        let mut state = self.state.lock();
+
+        state.requests.push(std::mem::take(&mut self.path));
+
+        let status = match state.responses.pop_front() {
+            None => Ok(Status::Ok),
+            Some(MockResponse::Precheck(status)) => Ok(status),
+            Some(MockResponse::GrpcStatus(status)) => Err(status),
+            Some(response @ (MockResponse::Receipt(_) | MockResponse::Record(_))) => {
+                Err(tonic::Status::internal(format!(
+                    "mock node: expected a transaction response to be scripted, found `{response:?}`"
+                )))
+            }
+        };
+
+        ready(status.map(|status| {
+            tonic::Response::new(services::TransactionResponse {
+                node_transaction_precheck_code: status as i32,
+                cost: 0,
+            })
+        }))
+    }
+}
+
+struct QueryHandler {
+    state: Arc<Mutex<NodeState>>,
+    path: String,
+}
+
+impl UnaryService<services::Query> for QueryHandler {
+    type Response = services::Response;
+    type Future = Ready<Result<tonic::Response<Self::Response>, tonic::Status>>;
+
+    fn call(&mut self, request: tonic::Request<services::Query>) -> Self::Future {
+        ready(self.answer(request.into_inner()).map(tonic::Response::new))
+    }
+}
+
+impl QueryHandler {
+    fn answer(&mut self, query: services::Query) -> Result<services::Response, tonic::Status> {
+        use services::query::Query;
+
+        let Some(query) = query.query else {
+            return Err(tonic::Status::invalid_argument("mock node: query is missing"));
+        };
+
+        let mut state = self.state.lock();
+
+        // the SDK pings a node by asking for its own balance before using it for the first time,
+        // answering those automatically keeps them out of the script.
+        if let Query::CryptogetAccountBalance(it) = &query {
+            if it.balance_source == Some(BalanceSource::AccountId(state.account_id.to_protobuf())) {
+                return Ok(services::Response {
+                    response: Some(precheck_response(&query, Status::Ok)),
+                });
+            }
+        }
+
+        state.requests.push(std::mem::take(&mut self.path));
+
+        let response_type = query_header(&query)
+            .map_or(services::ResponseType::AnswerOnly, services::QueryHeader::response_type);
+
+        // cost queries are answered without consuming the script, every query is free.
+        if response_type == services::ResponseType::CostAnswer {
+            return Ok(services::Response {
+                response: Some(precheck_response(&query, Status::Ok)),
+            });
+        }
+
+        let scripted = state.responses.pop_front();
+
+        let response = match (scripted, &query) {
+            (Some(MockResponse::GrpcStatus(status)), _) => return Err(status),
+
+            (Some(MockResponse::Precheck(status)), _) => precheck_response(&query, status),
+
+            (Some(MockResponse::Receipt(receipt)), Query::TransactionGetReceipt(_)) => {
+                receipt_response(receipt.to_protobuf())
+            }
+
+            (Some(MockResponse::Record(record)), Query::TransactionGetRecord(_)) => {
+                record_response(record.to_protobuf())
+            }
+
+            (Some(response), _) => {
+                return Err(tonic::Status::internal(format!(
+                    "mock node: `{response:?}` can't answer `{}`",
+                    query_name(&query)
+                )))
+            }
+
+            (None, Query::TransactionGetReceipt(_)) => receipt_response(success_receipt()),
+
+            (None, Query::TransactionGetRecord(it)) => {
+                record_response(services::TransactionRecord {
+                    receipt: Some(success_receipt()),
+                    consensus_timestamp: Some(OffsetDateTime::now_utc().into()),
+                    transaction_id: it.transaction_id.clone(),
+                    ..Default::default()
+                })
+            }
+
+            (None, _) => precheck_response(&query, Status::NotSupported),
+        };
+
+        Ok(services::Response { response: Some(response) })
+    }
+}
+
+fn success_receipt() -> services::TransactionReceipt {
+    services::TransactionReceipt { status: Status::Success as i32, ..Default::default() }
+}
+
+fn response_header(status: Status) -> Option<services::ResponseHeader> {
+    Some(services::ResponseHeader {
+        node_transaction_precheck_code: status as i32,
+        ..Default::default()
+    })
+}
+
+fn receipt_response(receipt: services::TransactionReceipt) -> services::response::Response {
+    services::response::Response::TransactionGetReceipt(services::TransactionGetReceiptResponse {
+        header: response_header(Status::Ok),
+        receipt: Some(receipt),
+        ..Default::default()
+    })
+}
+
+fn record_response(record: services::TransactionRecord) -> services::response::Response {
+    services::response::Response::TransactionGetRecord(services::TransactionGetRecordResponse {
+        header: response_header(Status::Ok),
+        transaction_record: Some(record),
+        ..Default::default()
+    })
+}
+
+/// Generates the per query type plumbing: reading the header of a query,
+/// and building an otherwise empty response of the matching type.
+macro_rules! queries {
+    ($($query:ident => $response:ident($response_ty:ident)),* $(,)?) => {
+        fn query_header(query: &services::query::Query) -> Option<&services::QueryHeader> {
+            match query {
+                $(services::query::Query::$query(it) => it.header.as_ref(),)*
+                _ => None,
+            }
+        }
+
+        fn query_name(query: &services::query::Query) -> &'static str {
+            match query {
+                $(services::query::Query::$query(_) => stringify!($query),)*
+                _ => "unknown query",
+            }
+        }
+
+        /// A response to `query` with nothing but a pre-check status.
+        ///
+        /// Unknown query types get a receipt response, which the SDK won't recognize.
+        fn precheck_response(query: &services::query::Query, status: Status) -> services::response::Response {
+            let header = response_header(status);
+
+            match query {
+                $(
+                    services::query::Query::$query(_) => services::response::Response::$response(
+                        services::$response_ty { header, ..Default::default() }
+                    ),
+                )*
+                _ => services::response::Response::TransactionGetReceipt(
+                    services::TransactionGetReceiptResponse { header, ..Default::default() }
+                ),
+            }
+        }
+    };
+}
+
+queries! {
+    ConsensusGetTopicInfo => ConsensusGetTopicInfo(ConsensusGetTopicInfoResponse),
+    ContractCallLocal => ContractCallLocal(ContractCallLocalResponse),
+    ContractGetBytecode => ContractGetBytecodeResponse(ContractGetBytecodeResponse),
+    ContractGetInfo => ContractGetInfo(ContractGetInfoResponse),
+    CryptoGetAccountRecords => CryptoGetAccountRecords(CryptoGetAccountRecordsResponse),
+    CryptoGetInfo => CryptoGetInfo(CryptoGetInfoResponse),
+    CryptoGetProxyStakers => CryptoGetProxyStakers(CryptoGetStakersResponse),
+    CryptogetAccountBalance => CryptogetAccountBalance(CryptoGetAccountBalanceResponse),
+    FileGetContents => FileGetContents(FileGetContentsResponse),
+    FileGetInfo => FileGetInfo(FileGetInfoResponse),
+    NetworkGetVersionInfo => NetworkGetVersionInfo(NetworkGetVersionInfoResponse),
+    ScheduleGetInfo => ScheduleGetInfo(ScheduleGetInfoResponse),
+    TokenGetInfo => TokenGetInfo(TokenGetInfoResponse),
+    TokenGetNftInfo => TokenGetNftInfo(TokenGetNftInfoResponse),
+    TransactionGetReceipt => TransactionGetReceipt(TransactionGetReceiptResponse),
+    TransactionGetRecord => TransactionGetRecord(TransactionGetRecordResponse),
+}
@@ -0,0 +1,182 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use assert_matches::assert_matches;
+use hedera_proto::services;
+
+use super::{
+    MockNetwork,
+    MockResponse,
+};
+use crate::protobuf::FromProtobuf;
+use crate::{
+    AccountBalanceQuery,
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    PrivateKey,
+    Status,
+    TransactionReceipt,
+    TransferTransaction,
+};
+
+const TRANSFER: &str = "/proto.CryptoService/cryptoTransfer";
+const GET_RECEIPT: &str = "/proto.CryptoService/getTransactionReceipts";
+
+fn client(network: &MockNetwork) -> Client {
+    let client = network.client().unwrap();
+    client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+
+    client
+}
+
+fn transfer() -> TransferTransaction {
+    let mut tx = TransferTransaction::new();
+    tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+        .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1));
+
+    tx
+}
+
+fn receipt(status: Status) -> TransactionReceipt {
+    TransactionReceipt::from_protobuf(services::TransactionReceipt {
+        status: status as i32,
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+#[tokio::test]
+async fn transaction_succeeds() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network);
+
+    let response = transfer().execute(&client).await.unwrap();
+    let receipt = response.get_receipt(&client).await.unwrap();
+
+    assert_eq!(response.node_account_id, AccountId::new(0, 0, 3));
+    assert_eq!(receipt.status, Status::Success);
+    assert_eq!(network.nodes()[0].requests(), [TRANSFER, GET_RECEIPT]);
+}
+
+#[tokio::test]
+async fn busy_is_retried() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network);
+
+    network.nodes()[0]
+        .push_response(MockResponse::Precheck(Status::Busy))
+        .push_response(MockResponse::Precheck(Status::PlatformNotActive));
+
+    transfer().execute(&client).await.unwrap();
+
+    assert_eq!(network.nodes()[0].requests(), [TRANSFER, TRANSFER, TRANSFER]);
+}
+
+#[tokio::test]
+async fn expired_transaction_is_regenerated() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network);
+
+    network.nodes()[0].push_response(MockResponse::Precheck(Status::TransactionExpired));
+
+    transfer().execute(&client).await.unwrap();
+
+    assert_eq!(network.nodes()[0].requests(), [TRANSFER, TRANSFER]);
+}
+
+#[tokio::test]
+async fn precheck_failure_is_returned() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network);
+
+    network.nodes()[0].push_response(MockResponse::Precheck(Status::InsufficientPayerBalance));
+
+    let res = transfer().execute(&client).await;
+
+    assert_matches!(
+        res,
+        Err(Error::TransactionPreCheckStatus { status: Status::InsufficientPayerBalance, .. })
+    );
+    assert_eq!(network.nodes()[0].requests(), [TRANSFER]);
+}
+
+#[tokio::test]
+async fn unavailable_node_is_skipped() {
+    let network = MockNetwork::start(2).await.unwrap();
+    let client = client(&network);
+
+    let unavailable = &network.nodes()[0];
+    unavailable.push_response(MockResponse::GrpcStatus(tonic::Status::unavailable("down")));
+
+    let response = transfer()
+        .node_account_ids([unavailable.account_id(), network.nodes()[1].account_id()])
+        .execute(&client)
+        .await
+        .unwrap();
+
+    // whichever node is tried first, the unavailable node can't be the one that succeeds.
+    assert_eq!(response.node_account_id, network.nodes()[1].account_id());
+    assert!(unavailable.requests().len() <= 1);
+}
+
+#[tokio::test]
+async fn receipt_is_polled_until_known() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network);
+
+    let response = transfer().execute(&client).await.unwrap();
+
+    network.nodes()[0]
+        .push_response(MockResponse::Receipt(receipt(Status::Unknown)))
+        .push_response(MockResponse::Precheck(Status::ReceiptNotFound))
+        .push_response(MockResponse::Receipt(receipt(Status::Success)));
+
+    let receipt = response.get_receipt(&client).await.unwrap();
+
+    assert_eq!(receipt.status, Status::Success);
+    assert_eq!(network.nodes()[0].requests(), [TRANSFER, GET_RECEIPT, GET_RECEIPT, GET_RECEIPT]);
+}
+
+#[tokio::test]
+async fn failed_receipt_is_an_error() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network);
+
+    let response = transfer().execute(&client).await.unwrap();
+
+    network.nodes()[0].push_response(MockResponse::Receipt(receipt(Status::InvalidSignature)));
+
+    let res = response.get_receipt(&client).await;
+
+    assert_matches!(res, Err(Error::ReceiptStatus { status: Status::InvalidSignature, .. }));
+}
+
+#[tokio::test]
+async fn unsupported_query() {
+    let network = MockNetwork::start(1).await.unwrap();
+    let client = client(&network);
+
+    let res =
+        AccountBalanceQuery::new().account_id(AccountId::new(0, 0, 1001)).execute(&client).await;
+
+    assert_matches!(res, Err(Error::QueryNoPaymentPreCheckStatus { status: Status::NotSupported }));
+}
@@ -56,6 +56,8 @@ pub type RoyaltyFee = CustomFee<RoyaltyFeeData>;
 /// fee is attached. A custom fee may be either fixed or fractional, and must specify a fee collector
 /// account to receive the assessed fees. Only positive fees may be assessed.
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct CustomFee<Fee> {
     /// The fee to be charged
     pub fee: Fee,
@@ -153,6 +155,8 @@ impl From<RoyaltyFee> for AnyCustomFee {
 
 /// Represents the possible fee types.
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum Fee {
     /// A fee using a fixed amount.
     Fixed(FixedFeeData),
@@ -213,6 +217,8 @@ impl From<RoyaltyFeeData> for Fee {
 /// A fixed number of units (hbar or token) to assess as a fee during a `CryptoTransfer` that transfers
 /// units of the token to which this fixed fee is attached.
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FixedFeeData {
     /// The number of units to assess as a fee
     pub amount: i64,
@@ -260,6 +266,8 @@ impl ToProtobuf for FixedFeeData {
 /// be less than the given `minimum_amount`, and never greater than the given `maximum_amount`.  The
 /// denomination is always units of the token to which this fractional fee is attached.
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FractionalFeeData {
     /// The denominator of the fraction of transferred units to assess as a fee
     pub denominator: u64,
@@ -318,6 +326,8 @@ impl ToProtobuf for FractionalFeeData {
 /// any fungible value, the ledger will assess the fallback fee, if present, to the new NFT owner.
 /// Royalty fees can only be added to tokens of type type `NON_FUNGIBLE_UNIQUE`.
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct RoyaltyFeeData {
     /// The denominator of the fraction of fungible value exchanged for an NFT to collect as royalty
     pub denominator: u64,
@@ -360,6 +370,8 @@ impl ToProtobuf for RoyaltyFeeData {
 ///
 /// The terminology here (exclusive vs inclusive) is borrowed from tax assessment.
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 pub enum FeeAssessmentMethod {
     /// The recipient recieves the transfer amount, minus the fee.
     ///
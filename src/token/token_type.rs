@@ -34,6 +34,8 @@ use crate::{
 /// may be added in the future.
 ///
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "SCREAMING_SNAKE_CASE"))]
 #[repr(C)]
 pub enum TokenType {
     /// Interchangeable value with one another, where any quantity of them has the same value as
@@ -36,6 +36,8 @@ use crate::{
 /// Response from [`TopicInfoQuery`][crate::TopicInfoQuery].
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TopicInfo {
     /// The ID of the topic for which information is requested.
     pub topic_id: TopicId,
@@ -44,12 +46,14 @@ pub struct TopicInfo {
     pub topic_memo: String,
 
     /// SHA-384 running hash of (previousRunningHash, topicId, consensusTimestamp, sequenceNumber, message).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::bytes"))]
     pub running_hash: Vec<u8>,
 
     /// Sequence number (starting at 1 for the first submitMessage) of messages on the topic.
     pub sequence_number: u64,
 
     /// Effective consensus timestamp at (and after) which submitMessage calls will no longer succeed on the topic.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_impls::timestamp::option"))]
     pub expiration_time: Option<OffsetDateTime>,
 
     /// Access control for update/delete of the topic.
@@ -63,6 +67,7 @@ pub struct TopicInfo {
     pub auto_renew_account_id: Option<AccountId>,
 
     /// The interval at which the auto-renew account will be charged to extend the topic's expiry.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_impls::duration::option"))]
     pub auto_renew_period: Option<Duration>,
 
     /// The ledger ID the response was returned from
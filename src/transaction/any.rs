@@ -882,6 +882,7 @@ impl AnyTransaction {
                 operator: None,
                 is_frozen: true,
                 regenerate_transaction_id: Some(false),
+                idempotent: false,
             },
             signers: Vec::new(),
             sources: None,
@@ -1147,6 +1148,7 @@ macro_rules! impl_cast_any {
                             operator: transaction.body.operator,
                             is_frozen: transaction.body.is_frozen,
                             regenerate_transaction_id: transaction.body.regenerate_transaction_id,
+                            idempotent: transaction.body.idempotent,
                         },
                         signers: transaction.signers,
                         sources: transaction.sources,
@@ -1,6 +1,7 @@
 use std::cmp;
 use std::num::NonZeroUsize;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use tonic::transport::Channel;
 
@@ -91,6 +92,22 @@ pub struct ChunkInfo {
 }
 
 impl ChunkInfo {
+    /// The transaction ID of chunk `chunk` of an idempotent transaction, derived from the initial one,
+    /// so that every execution (and every retry) of it submits each chunk with the same ID.
+    #[must_use]
+    // taking `transaction_id` by reference and then dereferencing it to copy it unconditionally... Feels weird.
+    #[allow(clippy::large_types_passed_by_value)]
+    pub(crate) fn idempotent_transaction_id(
+        initial_transaction_id: TransactionId,
+        chunk: usize,
+    ) -> TransactionId {
+        TransactionId {
+            valid_start: initial_transaction_id.valid_start
+                + time::Duration::nanoseconds(chunk as i64),
+            ..initial_transaction_id
+        }
+    }
+
     #[must_use]
     pub(crate) fn assert_single_transaction(&self) -> (TransactionId, AccountId) {
         assert!(self.current == 0 && self.total == 1);
@@ -155,22 +172,30 @@ where
         self.transaction.regenerate_transaction_id()
     }
 
+    fn is_idempotent(&self) -> bool {
+        self.transaction.body.idempotent
+    }
+
     fn operator_account_id(&self) -> Option<&AccountId> {
         self.transaction.operator_account_id()
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn make_request<'b>(
+        &'b self,
+        transaction_id: Option<&'b TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'b, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo::initial(
-            self.total_chunks,
-            *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-            node_account_id,
-        )))
+        Box::pin(async move {
+            self.transaction
+                .make_request_inner(&ChunkInfo::initial(
+                    self.total_chunks,
+                    *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
+                    node_account_id,
+                ))
+                .await
+        })
     }
 
     fn execute(
@@ -214,6 +239,10 @@ where
     fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32> {
         Ok(response.node_transaction_precheck_code)
     }
+
+    fn submitted_transaction(request: &Self::GrpcRequest) -> Option<&services::Transaction> {
+        Some(request)
+    }
 }
 
 impl<'a, D: ValidateChecksums> ValidateChecksums for FirstChunkView<'a, D> {
@@ -246,7 +275,9 @@ where
     }
 
     fn transaction_id(&self) -> Option<TransactionId> {
-        None
+        self.transaction.body.idempotent.then(|| {
+            ChunkInfo::idempotent_transaction_id(self.initial_transaction_id, self.current_chunk)
+        })
     }
 
     fn operator_account_id(&self) -> Option<&AccountId> {
@@ -261,20 +292,29 @@ where
         self.transaction.regenerate_transaction_id()
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn is_idempotent(&self) -> bool {
+        self.transaction.body.idempotent
+    }
+
+    fn make_request<'b>(
+        &'b self,
+        transaction_id: Option<&'b TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'b, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.transaction.is_frozen());
 
-        Ok(self.transaction.make_request_inner(&ChunkInfo {
-            total: self.total_chunks,
-            current: self.current_chunk,
-            initial_transaction_id: self.initial_transaction_id,
-            node_account_id,
-            current_transaction_id: *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-        }))
+        Box::pin(async move {
+            self.transaction
+                .make_request_inner(&ChunkInfo {
+                    total: self.total_chunks,
+                    current: self.current_chunk,
+                    initial_transaction_id: self.initial_transaction_id,
+                    node_account_id,
+                    current_transaction_id: *transaction_id
+                        .ok_or(Error::NoPayerAccountOrTransactionId)?,
+                })
+                .await
+        })
     }
 
     fn execute(
@@ -318,6 +358,10 @@ where
     fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32> {
         Ok(response.node_transaction_precheck_code)
     }
+
+    fn submitted_transaction(request: &Self::GrpcRequest) -> Option<&services::Transaction> {
+        Some(request)
+    }
 }
 
 impl<'a, D: ValidateChecksums> ValidateChecksums for ChunkView<'a, D> {
@@ -37,6 +37,7 @@ impl<D: Clone> CostTransaction<D> {
                 operator: transaction.body.operator,
                 is_frozen: transaction.body.is_frozen,
                 regenerate_transaction_id: transaction.body.regenerate_transaction_id,
+                idempotent: transaction.body.idempotent,
             },
             // cost transactions have no signers
             signers: Vec::new(),
@@ -21,6 +21,7 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+use futures_core::future::BoxFuture;
 use hedera_proto::services;
 use prost::Message;
 use tonic::transport::Channel;
@@ -83,12 +84,12 @@ impl From<(PublicKey, Vec<u8>)> for SignaturePair {
 
 impl<D> Transaction<D>
 where
-    D: TransactionData + ToTransactionDataProtobuf,
+    D: TransactionExecute,
 {
-    pub(crate) fn make_request_inner(
+    pub(crate) async fn make_request_inner(
         &self,
         chunk_info: &ChunkInfo,
-    ) -> (services::Transaction, TransactionHash) {
+    ) -> crate::Result<(services::Transaction, TransactionHash)> {
         assert!(self.is_frozen());
 
         let transaction_body = self.to_transaction_body_protobuf(chunk_info);
@@ -98,7 +99,7 @@ where
         let mut signatures = Vec::with_capacity(1 + self.signers.len());
 
         if let Some(operator) = &self.body.operator {
-            let operator_signature = operator.sign(&body_bytes);
+            let operator_signature = operator.sign(&body_bytes).await?;
 
             signatures.push(SignaturePair::from(operator_signature).into_protobuf());
         }
@@ -106,7 +107,7 @@ where
         for signer in &self.signers {
             let public_key = signer.public_key().to_bytes();
             if !signatures.iter().any(|it| public_key.starts_with(&it.pub_key_prefix)) {
-                let signature = signer.sign(&body_bytes);
+                let signature = signer.sign(&body_bytes).await?;
                 signatures.push(SignaturePair::from(signature).into_protobuf());
             }
         }
@@ -123,7 +124,7 @@ where
         let transaction =
             services::Transaction { signed_transaction_bytes, ..services::Transaction::default() };
 
-        (transaction, transaction_hash)
+        Ok((transaction, transaction_hash))
     }
 }
 
@@ -156,7 +157,7 @@ pub trait TransactionData: Clone + Into<AnyTransactionData> {
 }
 
 pub trait TransactionExecute:
-    ToTransactionDataProtobuf + TransactionData + ValidateChecksums
+    Send + Sync + ToTransactionDataProtobuf + TransactionData + ValidateChecksums
 {
     fn execute(
         &self,
@@ -197,17 +198,24 @@ where
         self.body.regenerate_transaction_id
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn is_idempotent(&self) -> bool {
+        self.body.idempotent
+    }
+
+    fn make_request<'a>(
+        &'a self,
+        transaction_id: Option<&'a TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'a, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         assert!(self.is_frozen());
 
-        Ok(self.make_request_inner(&ChunkInfo::single(
-            *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
-            node_account_id,
-        )))
+        Box::pin(async move {
+            self.make_request_inner(&ChunkInfo::single(
+                *transaction_id.ok_or(Error::NoPayerAccountOrTransactionId)?,
+                node_account_id,
+            ))
+            .await
+        })
     }
 
     fn execute(
@@ -251,6 +259,10 @@ where
     fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32> {
         Ok(response.node_transaction_precheck_code)
     }
+
+    fn submitted_transaction(request: &Self::GrpcRequest) -> Option<&services::Transaction> {
+        Some(request)
+    }
 }
 
 /// Marker trait for transactions that support Chunking.
@@ -309,22 +321,22 @@ pub(crate) struct SourceTransaction<'a, D> {
     sources: Cow<'a, TransactionSources>,
 }
 
-impl<'a, D> SourceTransaction<'a, D> {
-    pub(crate) fn new(transaction: &'a Transaction<D>, sources: &'a TransactionSources) -> Self {
+impl<'a, D: TransactionExecute> SourceTransaction<'a, D> {
+    pub(crate) async fn new(
+        transaction: &'a Transaction<D>,
+        sources: &'a TransactionSources,
+    ) -> crate::Result<Self> {
         // fixme: be way more lazy.
-        let sources = sources.sign_with(&transaction.signers);
+        let sources = sources.sign_with(&transaction.signers).await?;
 
-        Self { inner: transaction, sources }
+        Ok(Self { inner: transaction, sources })
     }
 
     pub(crate) async fn execute(
         &self,
         client: &Client,
         timeout: Option<std::time::Duration>,
-    ) -> crate::Result<TransactionResponse>
-    where
-        D: TransactionExecute,
-    {
+    ) -> crate::Result<TransactionResponse> {
         Ok(self.execute_all(client, timeout).await?.swap_remove(0))
     }
 
@@ -332,10 +344,7 @@ impl<'a, D> SourceTransaction<'a, D> {
         &self,
         client: &Client,
         timeout_per_chunk: Option<std::time::Duration>,
-    ) -> crate::Result<Vec<TransactionResponse>>
-    where
-        D: TransactionExecute,
-    {
+    ) -> crate::Result<Vec<TransactionResponse>> {
         let mut responses = Vec::with_capacity(self.sources.chunks_len());
         for chunk in self.sources.chunks() {
             let response = crate::execute::execute(
@@ -406,15 +415,22 @@ impl<'a, D: TransactionExecute> Execute for SourceTransactionExecuteView<'a, D>
         Some(false)
     }
 
-    fn make_request(
-        &self,
-        transaction_id: Option<&TransactionId>,
+    fn is_idempotent(&self) -> bool {
+        self.transaction.body.idempotent
+    }
+
+    fn make_request<'b>(
+        &'b self,
+        transaction_id: Option<&'b TransactionId>,
         node_account_id: AccountId,
-    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+    ) -> BoxFuture<'b, crate::Result<(Self::GrpcRequest, Self::Context)>> {
         debug_assert_eq!(transaction_id, self.transaction_id().as_ref());
 
         let index = *self.indecies_by_node_id.get(&node_account_id).unwrap();
-        Ok((self.chunk.transactions()[index].clone(), self.chunk.transaction_hashes()[index]))
+        Box::pin(std::future::ready(Ok((
+            self.chunk.transactions()[index].clone(),
+            self.chunk.transaction_hashes()[index],
+        ))))
     }
 
     fn execute(
@@ -447,4 +463,8 @@ impl<'a, D: TransactionExecute> Execute for SourceTransactionExecuteView<'a, D>
     fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32> {
         Transaction::<D>::response_pre_check_status(response)
     }
+
+    fn submitted_transaction(request: &Self::GrpcRequest) -> Option<&services::Transaction> {
+        Some(request)
+    }
 }
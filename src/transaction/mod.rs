@@ -34,16 +34,22 @@ use triomphe::Arc;
 
 use crate::downcast::DowncastOwned;
 use crate::execute::execute;
-use crate::signer::AnySigner;
+use crate::signer::{
+    sign_now,
+    AnySigner,
+};
 use crate::{
     AccountId,
     Client,
     Error,
     Hbar,
+    Key,
+    KeyRequirement,
     Operator,
     PrivateKey,
     PublicKey,
     ScheduleCreateTransaction,
+    Signer,
     TransactionHash,
     TransactionId,
     TransactionResponse,
@@ -78,7 +84,13 @@ pub(crate) use protobuf::{
 };
 pub(crate) use source::TransactionSources;
 
-const DEFAULT_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(120);
+pub(crate) const DEFAULT_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(120);
+
+/// The signatures on a frozen [`Transaction`], by node account ID, then transaction ID, then public key.
+///
+/// Every chunk of a chunked transaction has its own transaction ID.
+pub type TransactionSignatures =
+    HashMap<AccountId, HashMap<TransactionId, HashMap<PublicKey, Vec<u8>>>>;
 
 /// A transaction that can be executed on the Hedera network.
 #[derive(Clone)]
@@ -109,6 +121,8 @@ pub(crate) struct TransactionBody<D> {
     pub(crate) is_frozen: bool,
 
     pub(crate) regenerate_transaction_id: Option<bool>,
+
+    pub(crate) idempotent: bool,
 }
 
 impl<D> Default for Transaction<D>
@@ -127,6 +141,7 @@ where
                 operator: None,
                 is_frozen: false,
                 regenerate_transaction_id: None,
+                idempotent: false,
             },
             signers: Vec::new(),
             sources: None,
@@ -174,8 +189,8 @@ impl<D> Transaction<D> {
         self.sources.as_ref()
     }
 
-    fn signed_sources(&self) -> Option<Cow<'_, TransactionSources>> {
-        self.sources().map(|it| it.sign_with(&self.signers))
+    fn signed_sources(&self) -> crate::Result<Option<Cow<'_, TransactionSources>>> {
+        self.sources().map(|it| sign_now(it.sign_with(&self.signers))).transpose()
     }
 
     /// # Panics
@@ -293,6 +308,36 @@ impl<D> Transaction<D> {
         self
     }
 
+    /// Returns whether this transaction is executed idempotently, see [`idempotent`](Self::idempotent).
+    #[must_use]
+    pub fn get_idempotent(&self) -> bool {
+        self.body.idempotent
+    }
+
+    /// Sets whether this transaction is executed idempotently.
+    ///
+    /// An idempotent transaction keeps its transaction ID across every node and retry,
+    /// and a [`Status::DuplicateTransaction`](crate::Status::DuplicateTransaction) pre-check
+    /// is taken to mean that an earlier submission of it already got through.
+    /// In that case `execute` succeeds, and the receipt of the [`TransactionResponse`] is
+    /// the receipt of that earlier submission.
+    /// Only its transaction ID and receipt are authoritative though: its `node_account_id` and `transaction_hash`
+    /// are those of the submission that got the duplicate pre-check, which aren't necessarily those of the one that got through.
+    ///
+    /// This makes it safe to `execute` the same transaction again when it's unknown whether it was submitted,
+    /// for instance after a gRPC timeout.
+    ///
+    /// If no transaction ID was set, the one generated the first time the transaction is executed is kept.
+    /// Every later chunk of a chunked transaction gets the transaction ID whose valid start is
+    /// that many nanoseconds after the first chunk's.
+    ///
+    /// Unlike most setters, this can be called on a frozen transaction, since it doesn't change what's signed.
+    pub fn idempotent(&mut self, idempotent: bool) -> &mut Self {
+        self.body.idempotent = idempotent;
+
+        self
+    }
+
     /// Sign the transaction.
     pub fn sign(&mut self, private_key: PrivateKey) -> &mut Self {
         self.sign_signer(AnySigner::PrivateKey(private_key))
@@ -307,6 +352,17 @@ impl<D> Transaction<D> {
         self.sign_signer(AnySigner::arbitrary(Box::new(public_key), signer))
     }
 
+    /// Sign the transaction with a [`Signer`].
+    ///
+    /// The signer is awaited while the transaction is executed, making this suitable for HSM or KMS backed keys.
+    ///
+    /// Note: Synchronous functions that need signatures, such as [`to_bytes`](Self::to_bytes) or
+    /// [`get_transaction_hash`](Self::get_transaction_hash), fail with [`Error::Signature`]
+    /// if the signer doesn't complete immediately.
+    pub fn sign_with_signer<S: Signer + 'static>(&mut self, signer: S) -> &mut Self {
+        self.sign_signer(AnySigner::signer(signer))
+    }
+
     pub(crate) fn sign_signer(&mut self, signer: AnySigner) -> &mut Self {
         // We're _supposed_ to require frozen here, but really there's no reason I can think of to do that.
 
@@ -497,6 +553,9 @@ impl<D: TransactionExecute> Transaction<D> {
         for chunk in 0..used_chunks {
             let current_transaction_id = match chunk {
                 0 => initial_transaction_id,
+                _ if self.body.idempotent => {
+                    ChunkInfo::idempotent_transaction_id(initial_transaction_id, chunk)
+                }
                 _ => operator()?.generate_transaction_id(),
             };
 
@@ -509,7 +568,7 @@ impl<D: TransactionExecute> Transaction<D> {
                     node_account_id,
                 };
 
-                transaction_list.push(self.make_request_inner(&chunk_info).0);
+                transaction_list.push(sign_now(self.make_request_inner(&chunk_info))?.0);
             }
         }
 
@@ -519,7 +578,7 @@ impl<D: TransactionExecute> Transaction<D> {
     pub(crate) fn make_sources(&self) -> crate::Result<Cow<'_, TransactionSources>> {
         assert!(self.is_frozen());
 
-        if let Some(sources) = self.signed_sources() {
+        if let Some(sources) = self.signed_sources()? {
             return Ok(sources);
         }
 
@@ -536,9 +595,10 @@ impl<D: TransactionExecute> Transaction<D> {
     pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
         assert!(self.is_frozen(), "Transaction must be frozen to call `to_bytes`");
 
-        let transaction_list = self
-            .signed_sources()
-            .map_or_else(|| self.make_transaction_list(), |it| Ok(it.transactions().to_vec()))?;
+        let transaction_list = match self.signed_sources()? {
+            Some(sources) => sources.transactions().to_vec(),
+            None => self.make_transaction_list()?,
+        };
 
         Ok(hedera_proto::sdk::TransactionList { transaction_list }.encode_to_vec())
     }
@@ -568,10 +628,10 @@ impl<D: TransactionExecute> Transaction<D> {
         // this is the only check that is for correctness rather than debugability.
         assert!(sources.transactions().len() == 1);
 
-        let sources = sources.sign_with(std::slice::from_ref(signer));
+        let sources = sign_now(sources.sign_with(std::slice::from_ref(signer))).unwrap();
 
         // hack: I don't care about perf here.
-        let ret = signer.sign(&sources.signed_transactions()[0].body_bytes);
+        let ret = sign_now(signer.sign(&sources.signed_transactions()[0].body_bytes)).unwrap();
 
         // if we have a `Cow::Borrowed` that'd mean there was no modification
         if let Cow::Owned(sources) = sources {
@@ -585,6 +645,7 @@ impl<D: TransactionExecute> Transaction<D> {
     /// Adds a signature directly to `self`.
     ///
     /// Only use this as a last resort.
+    /// For transactions with multiple nodes or chunks, use [`add_signatures`](Self::add_signatures) instead.
     ///
     /// This forcibly disables transaction ID regeneration.
     pub fn add_signature(&mut self, pk: PublicKey, signature: Vec<u8>) -> &mut Self {
@@ -593,6 +654,152 @@ impl<D: TransactionExecute> Transaction<D> {
         self
     }
 
+    /// Adds externally produced signatures to `self`, for every node and chunk.
+    ///
+    /// `signatures` is usually what [`get_signatures`](Self::get_signatures) returned for a copy of `self`
+    /// that was signed elsewhere, every key in it must have signed every node and chunk of `self`.
+    ///
+    /// Keys that have already signed `self` are skipped,
+    /// use [`remove_signature`](Self::remove_signature) first to replace their signatures.
+    ///
+    /// This forcibly disables transaction ID regeneration.
+    ///
+    /// # Errors
+    /// - [`Error::Signature`] if a key in `signatures` is missing a signature for any node or chunk of `self`.
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn add_signatures(
+        &mut self,
+        signatures: &TransactionSignatures,
+    ) -> crate::Result<&mut Self> {
+        assert!(self.is_frozen(), "Transaction must be frozen to call `add_signatures`");
+
+        let sources = self.make_sources()?;
+
+        let mut signers: Vec<AnySigner> = Vec::new();
+
+        for public_key in signatures.values().flat_map(HashMap::values).flat_map(HashMap::keys) {
+            if signers.iter().any(|it| it.public_key() == *public_key) {
+                continue;
+            }
+
+            // the signer gets handed body bytes, so that's what we look the signatures up by.
+            let mut by_body = HashMap::new();
+
+            for chunk in sources.chunks() {
+                let transaction_id = chunk.transaction_id();
+
+                for (node_id, tx) in chunk.node_ids().iter().zip(chunk.signed_transactions()) {
+                    let signature = signatures
+                        .get(node_id)
+                        .and_then(|it| it.get(&transaction_id))
+                        .and_then(|it| it.get(public_key))
+                        .ok_or_else(|| {
+                            Error::signature(format!(
+                                "missing signature by `{public_key}` for node `{node_id}` and transaction `{transaction_id}`"
+                            ))
+                        })?;
+
+                    by_body.insert(tx.body_bytes.clone(), signature.clone());
+                }
+            }
+
+            signers.push(AnySigner::arbitrary(Box::new(*public_key), move |body| {
+                by_body[body].clone()
+            }));
+        }
+
+        let sources = sign_now(sources.sign_with(&signers))?;
+
+        // if we have a `Cow::Borrowed` that'd mean there was no modification
+        if let Cow::Owned(sources) = sources {
+            self.sources = Some(sources);
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the signatures on `self`, by node account ID, then transaction ID, then public key.
+    ///
+    /// Keys given to [`sign`](Self::sign) and friends are included, signing now if they haven't yet.
+    ///
+    /// Note: Calling this function _disables_ transaction ID regeneration.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    /// - [`Error::Signature`] if a signer fails or doesn't complete immediately.
+    /// - [`Error::KeyParse`] if a signature only has a prefix of its public key.
+    /// - [`Error::FromProtobuf`] if a signature is neither Ed25519 nor ECDSA(secp256k1).
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn get_signatures(&mut self) -> crate::Result<TransactionSignatures> {
+        assert!(self.is_frozen(), "Transaction must be frozen to call `get_signatures`");
+
+        let sources = match self.make_sources()? {
+            Cow::Borrowed(it) => it,
+            Cow::Owned(it) => &*self.sources.insert(it),
+        };
+
+        sources.signatures()
+    }
+
+    /// Removes every signature by `public_key` from `self`,
+    /// returning the removed signatures by node account ID, then transaction ID.
+    ///
+    /// The key is also removed from the keys given to [`sign`](Self::sign) and friends.
+    ///
+    /// Note: Calling this function _disables_ transaction ID regeneration.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    /// - [`Error::Signature`] if a signer fails or doesn't complete immediately.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn remove_signature(
+        &mut self,
+        public_key: &PublicKey,
+    ) -> crate::Result<HashMap<AccountId, HashMap<TransactionId, Vec<u8>>>> {
+        assert!(self.is_frozen(), "Transaction must be frozen to call `remove_signature`");
+
+        let mut sources = self.make_sources()?.into_owned();
+
+        self.signers.retain(|it| it.public_key() != *public_key);
+
+        let removed = sources.remove_signatures(public_key);
+
+        self.sources = Some(sources);
+
+        Ok(removed)
+    }
+
+    /// Checks each of `keys` against the signatures on `self`.
+    ///
+    /// Each returned [`KeyRequirement`] says whether its key is satisfied, which public keys are still missing, and why.
+    ///
+    /// Keys given to [`sign`](Self::sign) and friends count, signing now if they haven't yet.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    /// - [`Error::Signature`] if a signer fails or doesn't complete immediately.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn check_key_requirements(&self, keys: &[Key]) -> crate::Result<Vec<KeyRequirement>> {
+        assert!(self.is_frozen(), "Transaction must be frozen to call `check_key_requirements`");
+
+        let sources = self.make_sources()?;
+
+        Ok(keys.iter().map(|it| KeyRequirement::check(it, &sources)).collect())
+    }
+
     /// # Panics
     /// panics if the transaction is not schedulable, a transaction can be non-schedulable due to:
     /// - if `self.is_frozen`
@@ -729,9 +936,11 @@ where
     ) -> crate::Result<TransactionResponse> {
         // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
         self.freeze_with(Some(client))?;
+        self.pin_idempotent_transaction_id()?;
 
         if let Some(sources) = self.sources() {
             return self::execute::SourceTransaction::new(self, sources)
+                .await?
                 .execute(client, timeout)
                 .await;
         }
@@ -749,6 +958,22 @@ where
         execute(client, self, timeout).await
     }
 
+    // an idempotent transaction has to keep its transaction ID across executions,
+    // so, rather than generating a new one for every execution, generate it once and keep it.
+    // the later chunks' IDs are derived from it, so they're reserved along with it.
+    fn pin_idempotent_transaction_id(&mut self) -> crate::Result<()> {
+        if self.body.idempotent && self.body.transaction_id.is_none() {
+            let operator =
+                self.body.operator.as_ref().ok_or(Error::NoPayerAccountOrTransactionId)?;
+
+            let used_chunks = self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks);
+
+            self.body.transaction_id = Some(operator.reserve_transaction_ids(used_chunks));
+        }
+
+        Ok(())
+    }
+
     // this is in *this* impl block rather than the `: TransactionExecuteChunked` impl block
     //because there's the off chance that someone calls `execute` on a Transaction that wants `execute_all`...
     async fn execute_all_inner(
@@ -848,10 +1073,12 @@ where
     ) -> crate::Result<Vec<TransactionResponse>> {
         // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
         self.freeze_with(Some(client))?;
+        self.pin_idempotent_transaction_id()?;
 
         // fixme: dedup this with `execute_with_optional_timeout`
         if let Some(sources) = self.sources() {
             return self::execute::SourceTransaction::new(self, sources)
+                .await?
                 .execute_all(client, timeout_per_chunk)
                 .await;
         }
@@ -1055,6 +1282,7 @@ where
             operator,
             is_frozen,
             regenerate_transaction_id,
+            idempotent,
         } = body;
 
         // not a `map().map_err()` because ownership.
@@ -1070,6 +1298,7 @@ where
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
+                    idempotent,
                 },
                 signers,
                 sources,
@@ -1086,6 +1315,7 @@ where
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
+                    idempotent,
                 },
                 signers,
                 sources,
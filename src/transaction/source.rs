@@ -19,6 +19,7 @@
  */
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::ops::Range;
 
 use hedera_proto::services;
@@ -27,9 +28,11 @@ use prost::Message;
 
 use crate::protobuf::FromProtobuf;
 use crate::signer::AnySigner;
+use crate::transaction::TransactionSignatures;
 use crate::{
     AccountId,
     Error,
+    PublicKey,
     TransactionHash,
     TransactionId,
 };
@@ -227,9 +230,9 @@ impl TransactionSources {
         })
     }
 
-    pub(crate) fn sign_with(&self, signers: &[AnySigner]) -> Cow<'_, Self> {
+    pub(crate) async fn sign_with(&self, signers: &[AnySigner]) -> crate::Result<Cow<'_, Self>> {
         if signers.is_empty() {
-            return Cow::Borrowed(self);
+            return Ok(Cow::Borrowed(self));
         }
 
         let mut signed_transactions = Cow::Borrowed(&self.signed_transactions);
@@ -250,13 +253,14 @@ impl TransactionSources {
             for tx in signed_transactions.to_mut().iter_mut() {
                 let sig_map = tx.sig_map.get_or_insert_with(services::SignatureMap::default);
                 // todo: reuse `pk_bytes` instead of re-serializing them.
-                let sig_pair = super::execute::SignaturePair::from(signer.sign(&tx.body_bytes));
+                let sig_pair =
+                    super::execute::SignaturePair::from(signer.sign(&tx.body_bytes).await?);
 
                 sig_map.sig_pair.push(sig_pair.into_protobuf());
             }
         }
 
-        match signed_transactions {
+        let sources = match signed_transactions {
             // if it's still borrowed then no signatures have been added (all signers are duplicates).
             Cow::Borrowed(_) => Cow::Borrowed(self),
             Cow::Owned(signed_transactions) => Cow::Owned(Self {
@@ -267,7 +271,91 @@ impl TransactionSources {
                 node_ids: self.node_ids.clone(),
                 transaction_hashes: self.transaction_hashes.clone(),
             }),
+        };
+
+        Ok(sources)
+    }
+
+    /// Returns every signature in `self`, by node account ID, then transaction ID, then public key.
+    pub(super) fn signatures(&self) -> crate::Result<TransactionSignatures> {
+        use services::signature_pair::Signature;
+
+        let mut signatures = TransactionSignatures::new();
+
+        for chunk in self.chunks() {
+            let transaction_id = chunk.transaction_id();
+
+            for (node_id, tx) in chunk.node_ids().iter().zip(chunk.signed_transactions()) {
+                let by_key =
+                    signatures.entry(*node_id).or_default().entry(transaction_id).or_default();
+
+                for sig_pair in tx.sig_map.as_ref().map_or_else(|| [].as_slice(), |it| &it.sig_pair)
+                {
+                    let (public_key, signature) = match &sig_pair.signature {
+                        Some(Signature::Ed25519(sig)) => {
+                            (PublicKey::from_bytes_ed25519(&sig_pair.pub_key_prefix)?, sig)
+                        }
+                        Some(Signature::EcdsaSecp256k1(sig)) => {
+                            (PublicKey::from_bytes_ecdsa(&sig_pair.pub_key_prefix)?, sig)
+                        }
+                        _ => {
+                            return Err(Error::from_protobuf(
+                                "unsupported transaction signature type",
+                            ))
+                        }
+                    };
+
+                    by_key.insert(public_key, signature.clone());
+                }
+            }
         }
+
+        Ok(signatures)
+    }
+
+    /// Removes every signature made by `public_key`, returning them by node account ID, then transaction ID.
+    pub(super) fn remove_signatures(
+        &mut self,
+        public_key: &PublicKey,
+    ) -> HashMap<AccountId, HashMap<TransactionId, Vec<u8>>> {
+        use services::signature_pair::Signature;
+
+        let pk = public_key.to_bytes_raw();
+
+        let mut removed: HashMap<_, HashMap<_, _>> = HashMap::new();
+
+        for (chunk, transaction_id) in self.chunks.iter().zip(&self.transaction_ids) {
+            let signed_transactions = &mut self.signed_transactions[chunk.clone()];
+
+            for (node_id, tx) in self.node_ids.iter().zip(signed_transactions) {
+                let Some(sig_map) = &mut tx.sig_map else {
+                    continue;
+                };
+
+                sig_map.sig_pair.retain(|it| {
+                    let sig = match &it.signature {
+                        Some(Signature::Ed25519(sig)) if public_key.is_ed25519() => sig,
+                        Some(Signature::EcdsaSecp256k1(sig)) if public_key.is_ecdsa() => sig,
+                        _ => return true,
+                    };
+
+                    // unlike when skipping signers, a prefix isn't enough here:
+                    // it might just as well be the prefix of someone else's key.
+                    if it.pub_key_prefix != pk {
+                        return true;
+                    }
+
+                    removed.entry(*node_id).or_default().insert(*transaction_id, sig.clone());
+
+                    false
+                });
+            }
+        }
+
+        // the encoded transactions might be stale now.
+        self.transactions = OnceCell::new();
+
+        removed
     }
 
     pub(crate) fn transactions(&self) -> &[services::Transaction] {
@@ -1,15 +1,21 @@
 use std::collections::HashSet;
 
 use assert_matches::assert_matches;
+use futures_core::future::BoxFuture;
+use hedera_proto::services;
 use hex_literal::hex;
+use prost::Message;
 use time::OffsetDateTime;
 
 use crate::transaction::AnyTransactionData;
 use crate::{
+    AccountId,
     AnyTransaction,
     Client,
     Hbar,
     PrivateKey,
+    PublicKey,
+    Signer,
     TopicMessageSubmitTransaction,
     TransactionId,
     TransferTransaction,
@@ -119,3 +125,269 @@ async fn chunked_to_from_bytes() -> crate::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn sign_with_signer() -> crate::Result<()> {
+    struct KmsKey(PrivateKey);
+
+    impl Signer for KmsKey {
+        fn public_key(&self) -> PublicKey {
+            self.0.public_key()
+        }
+
+        fn sign<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, crate::Result<Vec<u8>>> {
+            Box::pin(async move { Ok(self.0.sign(message)) })
+        }
+    }
+
+    let key = PrivateKey::generate_ed25519();
+    let public_key = key.public_key();
+
+    let mut tx = TransferTransaction::new();
+
+    let bytes = tx
+        .hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into(), 7.into()])
+        .freeze()?
+        .sign_with_signer(KmsKey(key))
+        .to_bytes()?;
+
+    let mut tx2 = AnyTransaction::from_bytes(&bytes)?;
+
+    public_key.verify_transaction(&mut tx2)?;
+
+    Ok(())
+}
+
+#[test]
+fn pending_signer_fails_synchronously() -> crate::Result<()> {
+    struct PendingKey(PublicKey);
+
+    impl Signer for PendingKey {
+        fn public_key(&self) -> PublicKey {
+            self.0
+        }
+
+        fn sign<'a>(&'a self, _message: &'a [u8]) -> BoxFuture<'a, crate::Result<Vec<u8>>> {
+            Box::pin(std::future::pending())
+        }
+    }
+
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into()])
+        .freeze()?
+        .sign_with_signer(PendingKey(PrivateKey::generate_ed25519().public_key()));
+
+    assert_matches!(tx.to_bytes(), Err(crate::Error::Signature(_)));
+
+    Ok(())
+}
+
+fn multi_node_transfer_bytes() -> crate::Result<Vec<u8>> {
+    TransferTransaction::new()
+        .hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into(), 7.into()])
+        .freeze()?
+        .to_bytes()
+}
+
+#[test]
+fn add_signatures_multi_node() -> crate::Result<()> {
+    let bytes = multi_node_transfer_bytes()?;
+
+    let key = PrivateKey::generate_ed25519();
+
+    let signatures = AnyTransaction::from_bytes(&bytes)?.sign(key.clone()).get_signatures()?;
+
+    assert_eq!(signatures.len(), 2);
+
+    for by_transaction in signatures.values() {
+        assert_eq!(by_transaction.len(), 1);
+        let by_key = by_transaction.values().next().unwrap();
+        assert_eq!(by_key.keys().collect::<Vec<_>>(), [&key.public_key()]);
+    }
+
+    let mut tx = AnyTransaction::from_bytes(&bytes)?;
+
+    tx.add_signatures(&signatures)?;
+
+    assert_eq!(tx.get_signatures()?, signatures);
+
+    key.public_key().verify_transaction(&mut AnyTransaction::from_bytes(&tx.to_bytes()?)?)?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn add_signatures_chunked() -> crate::Result<()> {
+    let client = Client::for_testnet();
+    client.set_operator(0.into(), PrivateKey::generate_ed25519());
+
+    let bytes = TopicMessageSubmitTransaction::new()
+        .topic_id(314)
+        .message(b"Hello, world!".to_vec())
+        .chunk_size(8)
+        .max_chunks(2)
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into(), 7.into()])
+        .freeze_with(&client)?
+        .to_bytes()?;
+
+    let key = PrivateKey::generate_ecdsa();
+
+    let signatures = AnyTransaction::from_bytes(&bytes)?.sign(key.clone()).get_signatures()?;
+
+    // 2 nodes, with 2 chunks each.
+    assert_eq!(signatures.len(), 2);
+    assert!(signatures.values().all(|it| it.len() == 2));
+
+    let mut tx = AnyTransaction::from_bytes(&bytes)?;
+
+    tx.add_signatures(&signatures)?;
+
+    key.public_key().verify_transaction(&mut AnyTransaction::from_bytes(&tx.to_bytes()?)?)?;
+
+    Ok(())
+}
+
+#[test]
+fn add_signatures_missing_node() -> crate::Result<()> {
+    let bytes = multi_node_transfer_bytes()?;
+
+    let mut signatures = AnyTransaction::from_bytes(&bytes)?
+        .sign(PrivateKey::generate_ed25519())
+        .get_signatures()?;
+
+    signatures.remove(&AccountId::from(7));
+
+    assert_matches!(
+        AnyTransaction::from_bytes(&bytes)?.add_signatures(&signatures),
+        Err(crate::Error::Signature(_))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn remove_signature() -> crate::Result<()> {
+    let bytes = multi_node_transfer_bytes()?;
+
+    let key1 = PrivateKey::generate_ed25519();
+    let key2 = PrivateKey::generate_ecdsa();
+
+    let mut tx = AnyTransaction::from_bytes(&bytes)?;
+    tx.sign(key1.clone()).sign(key2.clone());
+
+    let removed = tx.remove_signature(&key1.public_key())?;
+
+    assert_eq!(removed.len(), 2);
+    assert!(removed.values().all(|it| it.len() == 1));
+
+    for by_transaction in tx.get_signatures()?.values() {
+        for by_key in by_transaction.values() {
+            assert_eq!(by_key.keys().collect::<Vec<_>>(), [&key2.public_key()]);
+        }
+    }
+
+    let mut tx2 = AnyTransaction::from_bytes(&tx.to_bytes()?)?;
+
+    key2.public_key().verify_transaction(&mut tx2)?;
+    assert_matches!(
+        key1.public_key().verify_transaction(&mut tx2),
+        Err(crate::Error::SignatureVerify(_))
+    );
+
+    // nothing left to remove.
+    assert!(tx.remove_signature(&key1.public_key())?.is_empty());
+
+    Ok(())
+}
+
+/// Adds `sig_pairs` to every signed transaction in `bytes`, without checking them.
+fn with_sig_pairs(bytes: &[u8], sig_pairs: &[services::SignaturePair]) -> Vec<u8> {
+    let mut list = hedera_proto::sdk::TransactionList::decode(bytes).unwrap();
+
+    for tx in &mut list.transaction_list {
+        let mut signed =
+            services::SignedTransaction::decode(&*tx.signed_transaction_bytes).unwrap();
+        signed.sig_map.get_or_insert_with(Default::default).sig_pair.extend_from_slice(sig_pairs);
+        tx.signed_transaction_bytes = signed.encode_to_vec();
+    }
+
+    list.encode_to_vec()
+}
+
+#[test]
+fn remove_signature_needs_whole_key() -> crate::Result<()> {
+    use services::signature_pair::Signature;
+
+    let key = PrivateKey::generate_ed25519();
+    let pk = key.public_key().to_bytes_raw();
+
+    // none of these are signatures by `key`, even though the key starts with each prefix.
+    let others = [
+        services::SignaturePair {
+            pub_key_prefix: Vec::new(),
+            signature: Some(Signature::Ed25519(vec![1; 64])),
+        },
+        services::SignaturePair {
+            pub_key_prefix: pk[..4].to_vec(),
+            signature: Some(Signature::Ed25519(vec![2; 64])),
+        },
+        services::SignaturePair {
+            pub_key_prefix: pk,
+            signature: Some(Signature::EcdsaSecp256k1(vec![3; 64])),
+        },
+    ];
+
+    let bytes = multi_node_transfer_bytes()?;
+
+    let mut tx = AnyTransaction::from_bytes(&with_sig_pairs(&bytes, &others))?;
+    assert!(tx.remove_signature(&key.public_key())?.is_empty());
+
+    let signed = AnyTransaction::from_bytes(&bytes)?.sign(key.clone()).to_bytes()?;
+    let mut tx = AnyTransaction::from_bytes(&with_sig_pairs(&signed, &others))?;
+
+    let removed = tx.remove_signature(&key.public_key())?;
+
+    assert_eq!(removed.len(), 2);
+    assert!(removed.values().all(|it| it.len() == 1));
+
+    // only the real signature got removed.
+    let list = hedera_proto::sdk::TransactionList::decode(&*tx.to_bytes()?).unwrap();
+
+    for tx in list.transaction_list {
+        let signed = services::SignedTransaction::decode(&*tx.signed_transaction_bytes).unwrap();
+        assert_eq!(signed.sig_map.unwrap().sig_pair, others);
+    }
+
+    Ok(())
+}
@@ -43,6 +43,8 @@ use crate::{
 /// Response from [`TransactionReceiptQuery`][crate::TransactionReceiptQuery].
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TransactionReceipt {
     // fixme(sr): better doc comment.
     /// The ID of the transaction that this is a receipt for.
@@ -50,6 +52,7 @@ pub struct TransactionReceipt {
 
     /// The consensus status of the transaction; is UNKNOWN if consensus has not been reached, or if
     /// the associated transaction did not have a valid payer signature.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::status"))]
     pub status: Status,
 
     /// In the receipt for an `AccountCreateTransaction`, the id of the newly created account.
@@ -74,6 +77,7 @@ pub struct TransactionReceipt {
     // TODO: use a hash type (for display/debug/serialize purposes)
     /// In the receipt for a `TopicMessageSubmitTransaction`, the new running hash of the
     /// topic that received the message.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_impls::bytes::option"))]
     pub topic_running_hash: Option<Vec<u8>>,
 
     /// In the receipt of a `TopicMessageSubmitTransaction`, the version of the SHA-384
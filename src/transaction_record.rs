@@ -21,6 +21,8 @@
 use std::collections::HashMap;
 
 use hedera_proto::services;
+#[cfg(test)]
+pub(super) use tests::make_record;
 use time::OffsetDateTime;
 
 use crate::protobuf::ToProtobuf;
@@ -46,6 +48,8 @@ use crate::{
 /// The complete record for a transaction on Hedera that has reached consensus.
 /// Response from [`TransactionRecordQuery`][crate::TransactionRecordQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TransactionRecord {
     /// The status (reach consensus, or failed, or is unknown) and the ID of
     /// any new account/file/instance created.
@@ -53,9 +57,11 @@ pub struct TransactionRecord {
 
     /// The hash of the Transaction that executed (not the hash of any Transaction that failed for
     /// having a duplicate TransactionID).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::bytes"))]
     pub transaction_hash: Vec<u8>,
 
     /// The consensus timestamp.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::timestamp"))]
     pub consensus_timestamp: OffsetDateTime,
 
     /// Record of the value returned by the smart contract function or constructor.
@@ -93,6 +99,7 @@ pub struct TransactionRecord {
 
     /// In the record of an internal transaction, the consensus timestamp of the user
     /// transaction that spawned it.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_impls::timestamp::option"))]
     pub parent_consensus_timestamp: Option<OffsetDateTime>,
 
     /// In the record of an internal CryptoCreate transaction triggered by a user
@@ -109,9 +116,11 @@ pub struct TransactionRecord {
 
     /// The keccak256 hash of the ethereumData. This field will only be populated for
     /// `EthereumTransaction`.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::bytes"))]
     pub ethereum_hash: Vec<u8>,
 
     /// In the record of a PRNG transaction with no output range, a pseudorandom 384-bit string.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_impls::bytes::option"))]
     pub prng_bytes: Option<Vec<u8>>,
 
     /// In the record of a PRNG transaction with an output range, the output of a PRNG
@@ -360,7 +369,10 @@ mod tests {
         Transfer,
     };
 
-    fn make_record(prng_bytes: Option<Vec<u8>>, prng_number: Option<u32>) -> TransactionRecord {
+    pub(crate) fn make_record(
+        prng_bytes: Option<Vec<u8>>,
+        prng_number: Option<u32>,
+    ) -> TransactionRecord {
         TransactionRecord {
             receipt: crate::transaction_receipt::make_receipt(),
             transaction_hash: b"hello".to_vec(),
@@ -31,6 +31,7 @@ use crate::query::{
 };
 use crate::{
     BoxGrpcFuture,
+    ContractFunctionResult,
     Error,
     FromProtobuf,
     Query,
@@ -152,9 +153,16 @@ impl QueryExecute for TransactionRecordQueryData {
         let record = TransactionRecord::from_protobuf(response)?;
 
         if self.validate_status && record.receipt.status != Status::Success {
-            return Err(Error::ReceiptStatus {
-                transaction_id: self.transaction_id.map(Box::new),
-                status: record.receipt.status,
+            let transaction_id = self.transaction_id.map(Box::new);
+            let status = record.receipt.status;
+            let revert =
+                record.contract_function_result.as_ref().and_then(ContractFunctionResult::revert);
+
+            return Err(match revert {
+                Some(revert) => {
+                    Error::ContractReverted { status, transaction_id, revert: Box::new(revert) }
+                }
+                None => Error::ReceiptStatus { status, transaction_id },
             });
         }
 
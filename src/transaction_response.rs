@@ -115,7 +115,8 @@ impl TransactionResponse {
     ///
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
-    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record,
+    ///   or [`Error::ContractReverted`](crate::Error::ContractReverted) if it's a failed contract call that says what it reverted with.
     pub async fn get_record(&self, client: &Client) -> crate::Result<TransactionRecord> {
         self.get_record_query().execute(client).await
     }
@@ -125,7 +126,8 @@ impl TransactionResponse {
     ///
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
-    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record,
+    ///   or [`Error::ContractReverted`](crate::Error::ContractReverted) if it's a failed contract call that says what it reverted with.
     pub async fn get_record_with_timeout(
         &self,
         client: &Client,